@@ -14,6 +14,7 @@ pub struct AddCommentInput {
     pub account_id: FizzyId,
     pub user_id: FizzyId,
     pub card_number: i64,
+    /// Comment body as ActionText HTML
     pub content: String,
 }
 
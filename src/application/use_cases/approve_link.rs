@@ -0,0 +1,70 @@
+use std::sync::Arc;
+use chrono::Utc;
+use crate::domain::entities::{LinkRequest, User};
+use crate::domain::ports::{LinkRequestRepository, UserRepository};
+use crate::domain::value_objects::FizzyId;
+use crate::application::errors::ApplicationError;
+
+pub struct ApproveLinkUseCase {
+    user_repository: Arc<dyn UserRepository>,
+    link_request_repository: Arc<dyn LinkRequestRepository>,
+}
+
+pub struct ApproveLinkInput {
+    pub account_id: FizzyId,
+    pub approver_id: FizzyId,
+    pub code: String,
+    pub approve: bool,
+}
+
+pub struct ApproveLinkOutput {
+    pub request: LinkRequest,
+    pub user: User,
+    pub approved: bool,
+}
+
+impl ApproveLinkUseCase {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        link_request_repository: Arc<dyn LinkRequestRepository>,
+    ) -> Self {
+        Self { user_repository, link_request_repository }
+    }
+
+    /// Approve or deny a pending link request. Only account admins may decide.
+    pub async fn execute(&self, input: ApproveLinkInput) -> Result<ApproveLinkOutput, ApplicationError> {
+        let approver = self.user_repository
+            .find_by_id(&input.account_id, &input.approver_id)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        if !approver.as_ref().is_some_and(|u| u.is_active() && u.is_admin()) {
+            return Err(ApplicationError::Unauthorized("Only account admins can approve links".to_string()));
+        }
+
+        let request = self.link_request_repository
+            .find_by_code(&input.code)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .ok_or_else(|| ApplicationError::NotFound("Link request not found or already handled".to_string()))?;
+
+        // The code is single-use whatever the outcome
+        self.link_request_repository
+            .delete(&request.code)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        if request.is_expired(Utc::now()) {
+            return Err(ApplicationError::InvalidInput("Link request has expired".to_string()));
+        }
+
+        let user = self.user_repository
+            .find_by_id(&input.account_id, &request.user_id)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .filter(User::is_active)
+            .ok_or_else(|| ApplicationError::NotFound("Requested user no longer exists".to_string()))?;
+
+        Ok(ApproveLinkOutput { request, user, approved: input.approve })
+    }
+}
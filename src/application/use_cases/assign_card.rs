@@ -0,0 +1,247 @@
+use std::sync::Arc;
+use crate::domain::entities::{Card, User};
+use crate::domain::ports::{
+    AssignmentRepository, BoardRepository, CardRepository, CreateEventInput, EventRepository, UserRepository,
+    event_actions,
+};
+use crate::domain::value_objects::FizzyId;
+use crate::application::errors::ApplicationError;
+
+/// Minimum score for a name to count as a match at all
+const MIN_MATCH_SCORE: u32 = 20;
+
+pub struct AssignCardUseCase {
+    card_repository: Arc<dyn CardRepository>,
+    board_repository: Arc<dyn BoardRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    assignment_repository: Arc<dyn AssignmentRepository>,
+    event_repository: Arc<dyn EventRepository>,
+}
+
+/// Who to assign: a name typed by the user, or an ID picked from a keyboard
+#[derive(Debug, Clone)]
+pub enum AssigneeRef {
+    Name(String),
+    Id(FizzyId),
+}
+
+pub struct AssignCardInput {
+    pub account_id: FizzyId,
+    pub user_id: FizzyId,
+    pub card_number: i64,
+    pub assignee: AssigneeRef,
+}
+
+pub enum AssignCardOutput {
+    Assigned { card: Card, assignee: User },
+    AlreadyAssigned { card: Card, assignee: User },
+    /// The name matched several people (or nobody); let the user pick
+    Ambiguous { card: Card, candidates: Vec<User> },
+}
+
+impl AssignCardUseCase {
+    pub fn new(
+        card_repository: Arc<dyn CardRepository>,
+        board_repository: Arc<dyn BoardRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        assignment_repository: Arc<dyn AssignmentRepository>,
+        event_repository: Arc<dyn EventRepository>,
+    ) -> Self {
+        Self { card_repository, board_repository, user_repository, assignment_repository, event_repository }
+    }
+
+    pub async fn execute(&self, input: AssignCardInput) -> Result<AssignCardOutput, ApplicationError> {
+        let card = self.card_repository
+            .find_by_number(&input.account_id, input.card_number)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Card #{} not found", input.card_number)))?;
+
+        let has_access = self.board_repository
+            .user_has_access(&input.account_id, &card.board_id, &input.user_id)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        if !has_access {
+            return Err(ApplicationError::Unauthorized("No access to this board".to_string()));
+        }
+
+        // Only people who can see the board can be assigned to its cards
+        let users = self.user_repository
+            .list_board_members(&input.account_id, &card.board_id)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        let assignee = match input.assignee {
+            AssigneeRef::Id(id) => users
+                .into_iter()
+                .find(|u| u.id == id)
+                .ok_or_else(|| ApplicationError::NotFound("User not found on this board".to_string()))?,
+            AssigneeRef::Name(name) if name.trim().eq_ignore_ascii_case("me") => users
+                .into_iter()
+                .find(|u| u.id == input.user_id)
+                .ok_or_else(|| ApplicationError::NotFound("User not found".to_string()))?,
+            AssigneeRef::Name(name) => {
+                let matches = match_users(&name, &users);
+                match matches.as_slice() {
+                    [single] => (*single).clone(),
+                    // Nobody matches: offer every board member
+                    [] => return Ok(AssignCardOutput::Ambiguous { card, candidates: users }),
+                    _ => {
+                        let candidates = matches.into_iter().cloned().collect();
+                        return Ok(AssignCardOutput::Ambiguous { card, candidates });
+                    }
+                }
+            }
+        };
+
+        let assigned = self.assignment_repository
+            .assign(&input.account_id, &card.id, &assignee.id, &input.user_id)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        if !assigned {
+            return Ok(AssignCardOutput::AlreadyAssigned { card, assignee });
+        }
+
+        // Create event
+        let _ = self.event_repository
+            .create_event(&input.account_id, CreateEventInput {
+                board_id: card.board_id.clone(),
+                eventable_id: card.id.clone(),
+                eventable_type: "Card".to_string(),
+                creator_id: input.user_id,
+                action: event_actions::CARD_ASSIGNED.to_string(),
+                particulars: serde_json::json!({
+                    "assignee_ids": [assignee.id.as_str()]
+                }),
+            })
+            .await;
+
+        let card = self.card_repository
+            .find_by_id(&input.account_id, &card.id)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .unwrap_or(card);
+
+        Ok(AssignCardOutput::Assigned { card, assignee })
+    }
+}
+
+/// Find the users whose name best matches `query`.
+/// Returns every user tied for the best score, so one result means a clear match.
+pub(crate) fn match_users<'a>(query: &str, users: &'a [User]) -> Vec<&'a User> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let scored: Vec<(u32, &User)> = users
+        .iter()
+        .map(|user| (name_score(&query, user), user))
+        .filter(|(score, _)| *score >= MIN_MATCH_SCORE)
+        .collect();
+
+    let best = scored.iter().map(|(score, _)| *score).max().unwrap_or(0);
+    scored
+        .into_iter()
+        .filter(|(score, _)| *score == best)
+        .map(|(_, user)| user)
+        .collect()
+}
+
+/// Score how well a lowercase query matches a user's name or email
+fn name_score(query: &str, user: &User) -> u32 {
+    let name = user.name.to_lowercase();
+    let words: Vec<&str> = name.split_whitespace().collect();
+    let email_name = user
+        .email
+        .as_deref()
+        .and_then(|email| email.split('@').next())
+        .map(str::to_lowercase);
+
+    if name == query {
+        100
+    } else if email_name.as_deref() == Some(query) {
+        90
+    } else if words.contains(&query) {
+        80
+    } else if name.starts_with(query) {
+        60
+    } else if words.iter().any(|w| w.starts_with(query)) {
+        50
+    } else if name.contains(query) {
+        30
+    } else if query.chars().count() >= 4 && words.iter().any(|w| edit_distance(w, query) <= 1) {
+        // Tolerate a single typo in longer names
+        20
+    } else {
+        0
+    }
+}
+
+/// Levenshtein distance between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            current.push((previous[j] + cost).min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::UserRole;
+    use chrono::Utc;
+
+    fn user(name: &str, email: &str) -> User {
+        User {
+            id: FizzyId::generate(),
+            account_id: FizzyId::from("acc"),
+            identity_id: None,
+            name: name.to_string(),
+            role: UserRole::Member,
+            active: true,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            email: Some(email.to_string()),
+        }
+    }
+
+    fn names<'a>(matches: &[&'a User]) -> Vec<&'a str> {
+        matches.iter().map(|u| u.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_match_users_prefers_whole_words() {
+        let users = vec![
+            user("Ana Lopez", "ana@example.com"),
+            user("Anabel Smith", "anabel@example.com"),
+        ];
+
+        assert_eq!(names(&match_users("ana", &users)), vec!["Ana Lopez"]);
+        assert_eq!(names(&match_users("smi", &users)), vec!["Anabel Smith"]);
+    }
+
+    #[test]
+    fn test_match_users_reports_ties_and_typos() {
+        let users = vec![
+            user("Sam Green", "sam.g@example.com"),
+            user("Sam Brown", "sbrown@example.com"),
+        ];
+
+        assert_eq!(match_users("sam", &users).len(), 2);
+        assert_eq!(names(&match_users("sbrown", &users)), vec!["Sam Brown"]);
+        assert_eq!(names(&match_users("gren", &users)), vec!["Sam Green"]);
+        assert!(match_users("zed", &users).is_empty());
+    }
+}
@@ -0,0 +1,119 @@
+use std::sync::Arc;
+use crate::domain::entities::{Attachment, Card};
+use crate::domain::ports::{
+    AttachmentRepository, AttachmentTarget, BoardRepository, CardRepository, CreateEventInput,
+    EventRepository, NewAttachment, event_actions,
+};
+use crate::domain::value_objects::FizzyId;
+use crate::application::errors::ApplicationError;
+
+pub struct AttachFileUseCase {
+    card_repository: Arc<dyn CardRepository>,
+    board_repository: Arc<dyn BoardRepository>,
+    attachment_repository: Arc<dyn AttachmentRepository>,
+    event_repository: Arc<dyn EventRepository>,
+}
+
+pub struct AttachFileInput {
+    pub account_id: FizzyId,
+    pub user_id: FizzyId,
+    pub card_number: i64,
+    pub filename: String,
+    pub content_type: Option<String>,
+    pub data: Vec<u8>,
+    /// Text to post with the file as ActionText HTML. With text the file
+    /// goes on a new comment; without, it is added to the description.
+    pub comment: Option<String>,
+}
+
+pub struct AttachFileOutput {
+    pub card: Card,
+    pub attachment: Attachment,
+}
+
+impl AttachFileUseCase {
+    pub fn new(
+        card_repository: Arc<dyn CardRepository>,
+        board_repository: Arc<dyn BoardRepository>,
+        attachment_repository: Arc<dyn AttachmentRepository>,
+        event_repository: Arc<dyn EventRepository>,
+    ) -> Self {
+        Self {
+            card_repository,
+            board_repository,
+            attachment_repository,
+            event_repository,
+        }
+    }
+
+    pub async fn execute(&self, input: AttachFileInput) -> Result<AttachFileOutput, ApplicationError> {
+        if input.data.is_empty() {
+            return Err(ApplicationError::InvalidInput("The file is empty".to_string()));
+        }
+
+        let card = self.card_repository
+            .find_by_number(&input.account_id, input.card_number)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Card #{} not found", input.card_number)))?;
+
+        let has_access = self.board_repository
+            .user_has_access(&input.account_id, &card.board_id, &input.user_id)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        if !has_access {
+            return Err(ApplicationError::Unauthorized("No access to this board".to_string()));
+        }
+
+        let file = NewAttachment {
+            filename: input.filename,
+            content_type: input.content_type,
+            data: input.data,
+        };
+
+        let target = match input.comment.filter(|text| !text.trim().is_empty()) {
+            Some(body) => AttachmentTarget::NewComment {
+                card_id: card.id.clone(),
+                creator_id: input.user_id.clone(),
+                body,
+            },
+            None => AttachmentTarget::CardDescription(card.id.clone()),
+        };
+
+        let attachment = self.attachment_repository
+            .create(&input.account_id, &target, file)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        let event = match &attachment.comment_id {
+            Some(comment_id) => CreateEventInput {
+                board_id: card.board_id.clone(),
+                eventable_id: comment_id.clone(),
+                eventable_type: "Comment".to_string(),
+                creator_id: input.user_id,
+                action: event_actions::COMMENT_CREATED.to_string(),
+                particulars: serde_json::json!({
+                    "card_id": card.id.as_str()
+                }),
+            },
+            None => CreateEventInput {
+                board_id: card.board_id.clone(),
+                eventable_id: card.id.clone(),
+                eventable_type: "Card".to_string(),
+                creator_id: input.user_id,
+                action: event_actions::CARD_UPDATED.to_string(),
+                particulars: serde_json::json!({
+                    "attachment": attachment.filename
+                }),
+            },
+        };
+
+        // Create event
+        let _ = self.event_repository
+            .create_event(&input.account_id, event)
+            .await;
+
+        Ok(AttachFileOutput { card, attachment })
+    }
+}
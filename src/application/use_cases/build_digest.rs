@@ -0,0 +1,187 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use chrono::{DateTime, Days, NaiveDate, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use crate::domain::entities::Card;
+use crate::domain::ports::{BoardRepository, CardFilters, CardRepository, EventRepository, event_actions};
+use crate::domain::value_objects::{CardStatus, FizzyId};
+use crate::application::errors::ApplicationError;
+
+pub struct BuildDigestUseCase {
+    card_repository: Arc<dyn CardRepository>,
+    board_repository: Arc<dyn BoardRepository>,
+    event_repository: Arc<dyn EventRepository>,
+}
+
+pub struct BuildDigestInput {
+    pub account_id: FizzyId,
+    pub user_id: FizzyId,
+    /// The local date the digest is for
+    pub today: NaiveDate,
+    /// Start and end of the local "yesterday", in UTC
+    pub yesterday: (DateTime<Utc>, DateTime<Utc>),
+}
+
+impl BuildDigestInput {
+    /// Input for the digest of `today` as seen in `timezone`
+    pub fn for_day(account_id: FizzyId, user_id: FizzyId, today: NaiveDate, timezone: Tz) -> Self {
+        let yesterday = today.checked_sub_days(Days::new(1)).unwrap_or(today);
+        Self {
+            account_id,
+            user_id,
+            today,
+            yesterday: (start_of_day(yesterday, timezone), start_of_day(today, timezone)),
+        }
+    }
+}
+
+/// Local midnight of `date` in UTC (or the first instant of the day when DST skips midnight)
+fn start_of_day(date: NaiveDate, timezone: Tz) -> DateTime<Utc> {
+    let midnight = date.and_time(NaiveTime::MIN);
+    timezone
+        .from_local_datetime(&midnight)
+        .earliest()
+        .map(|start| start.with_timezone(&Utc))
+        .unwrap_or_else(|| midnight.and_utc())
+}
+
+/// Daily summary across the boards a user can access
+pub struct Digest {
+    pub today: NaiveDate,
+    pub overdue: Vec<Card>,
+    pub due_today: Vec<Card>,
+    pub golden: Vec<Card>,
+    pub created_yesterday: Vec<Card>,
+    pub closed_yesterday: Vec<Card>,
+    pub awaiting_triage: Vec<Card>,
+}
+
+impl Digest {
+    /// Check if there is nothing to report
+    pub fn is_empty(&self) -> bool {
+        self.overdue.is_empty()
+            && self.due_today.is_empty()
+            && self.golden.is_empty()
+            && self.created_yesterday.is_empty()
+            && self.closed_yesterday.is_empty()
+            && self.awaiting_triage.is_empty()
+    }
+}
+
+impl BuildDigestUseCase {
+    pub fn new(
+        card_repository: Arc<dyn CardRepository>,
+        board_repository: Arc<dyn BoardRepository>,
+        event_repository: Arc<dyn EventRepository>,
+    ) -> Self {
+        Self { card_repository, board_repository, event_repository }
+    }
+
+    pub async fn execute(&self, input: BuildDigestInput) -> Result<Digest, ApplicationError> {
+        let board_ids: Vec<FizzyId> = self.board_repository
+            .list_accessible(&input.account_id, &input.user_id)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .into_iter()
+            .map(|board| board.id)
+            .collect();
+
+        let open = || CardFilters {
+            exclude_status: Some(vec![CardStatus::Closed, CardStatus::NotNow]),
+            exclude_closed: Some(true),
+            board_ids: Some(board_ids.clone()),
+            ..Default::default()
+        };
+
+        let (overdue, due_today) = self
+            .list(&input.account_id, CardFilters {
+                due_on_or_before: Some(input.today),
+                ..open()
+            })
+            .await?
+            .into_iter()
+            .partition(|card| card.due_on < Some(input.today));
+
+        let golden = self
+            .list(&input.account_id, CardFilters {
+                is_golden: Some(true),
+                ..open()
+            })
+            .await?;
+
+        let awaiting_triage = self
+            .list(&input.account_id, CardFilters {
+                awaiting_triage: Some(true),
+                ..open()
+            })
+            .await?;
+
+        let (since, until) = input.yesterday;
+        let created_yesterday = self
+            .cards_from_events(
+                &input.account_id,
+                &board_ids,
+                since,
+                until,
+                &[event_actions::CARD_CREATED, event_actions::CARD_PUBLISHED],
+            )
+            .await?;
+        let closed_yesterday = self
+            .cards_from_events(&input.account_id, &board_ids, since, until, &[event_actions::CARD_CLOSED])
+            .await?;
+
+        Ok(Digest {
+            today: input.today,
+            overdue,
+            due_today,
+            golden,
+            created_yesterday,
+            closed_yesterday,
+            awaiting_triage,
+        })
+    }
+
+    /// List cards matching the filters
+    async fn list(&self, account_id: &FizzyId, filters: CardFilters) -> Result<Vec<Card>, ApplicationError> {
+        self.card_repository
+            .list(account_id, filters)
+            .await
+            .map_err(ApplicationError::DomainError)
+    }
+
+    /// Load the cards that had one of the given events in a time range
+    async fn cards_from_events(
+        &self,
+        account_id: &FizzyId,
+        board_ids: &[FizzyId],
+        since: DateTime<Utc>,
+        until: DateTime<Utc>,
+        actions: &[&str],
+    ) -> Result<Vec<Card>, ApplicationError> {
+        let events = self.event_repository
+            .list_between(account_id, since, until, actions)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        let mut seen = HashSet::new();
+        let mut cards = Vec::new();
+        for event in events {
+            let Some(card_id) = event.card_id else {
+                continue;
+            };
+            if !board_ids.contains(&event.board_id) || !seen.insert(card_id.clone()) {
+                continue;
+            }
+
+            if let Some(card) = self.card_repository
+                .find_by_id(account_id, &card_id)
+                .await
+                .map_err(ApplicationError::DomainError)?
+            {
+                cards.push(card);
+            }
+        }
+
+        Ok(cards)
+    }
+}
@@ -0,0 +1,74 @@
+use std::sync::Arc;
+use crate::domain::entities::{Card, Comment};
+use crate::domain::ports::{BoardRepository, CardRepository, CommentRepository, UserRepository};
+use crate::domain::value_objects::FizzyId;
+use crate::application::errors::ApplicationError;
+
+/// Who may change a comment, shared by editing and deleting so both apply the same rules
+pub(crate) struct CommentAccess {
+    comment_repository: Arc<dyn CommentRepository>,
+    card_repository: Arc<dyn CardRepository>,
+    board_repository: Arc<dyn BoardRepository>,
+    user_repository: Arc<dyn UserRepository>,
+}
+
+impl CommentAccess {
+    pub(crate) fn new(
+        comment_repository: Arc<dyn CommentRepository>,
+        card_repository: Arc<dyn CardRepository>,
+        board_repository: Arc<dyn BoardRepository>,
+        user_repository: Arc<dyn UserRepository>,
+    ) -> Self {
+        Self { comment_repository, card_repository, board_repository, user_repository }
+    }
+
+    /// Look up a comment the user may change: on a board they can access, and
+    /// their own unless they are an admin. Returns it with its card. `verb`
+    /// names the change in the error, e.g. "edit".
+    pub(crate) async fn find_changeable(
+        &self,
+        account_id: &FizzyId,
+        user_id: &FizzyId,
+        comment_id: &FizzyId,
+        verb: &str,
+    ) -> Result<(Comment, Card), ApplicationError> {
+        let comment = self.comment_repository
+            .find_by_id(account_id, comment_id)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .ok_or_else(|| ApplicationError::NotFound("Comment not found".to_string()))?;
+
+        let card = self.card_repository
+            .find_by_id(account_id, &comment.card_id)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .ok_or_else(|| ApplicationError::NotFound("Card not found".to_string()))?;
+
+        let has_access = self.board_repository
+            .user_has_access(account_id, &card.board_id, user_id)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        if !has_access {
+            return Err(ApplicationError::Unauthorized("No access to this board".to_string()));
+        }
+
+        // Only the author may change a comment, unless the user is an admin
+        if &comment.creator_id != user_id {
+            let is_admin = self.user_repository
+                .find_by_id(account_id, user_id)
+                .await
+                .map_err(ApplicationError::DomainError)?
+                .is_some_and(|u| u.is_admin());
+
+            if !is_admin {
+                return Err(ApplicationError::Unauthorized(format!(
+                    "You can only {} your own comments",
+                    verb
+                )));
+            }
+        }
+
+        Ok((comment, card))
+    }
+}
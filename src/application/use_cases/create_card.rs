@@ -18,6 +18,7 @@ pub struct CreateCardUseCaseInput {
     pub user_id: FizzyId,
     pub board_id: FizzyId,
     pub title: String,
+    /// Description as ActionText HTML
     pub description: Option<String>,
 }
 
@@ -0,0 +1,78 @@
+use std::sync::Arc;
+use crate::domain::entities::{Card, Comment};
+use crate::domain::ports::{
+    BoardRepository, CardRepository, CommentRepository, CreateEventInput, EventRepository,
+    UserRepository, event_actions,
+};
+use crate::domain::value_objects::FizzyId;
+use crate::application::errors::ApplicationError;
+use super::comment_access::CommentAccess;
+
+pub struct DeleteCommentUseCase {
+    comment_repository: Arc<dyn CommentRepository>,
+    access: CommentAccess,
+    event_repository: Arc<dyn EventRepository>,
+}
+
+pub struct DeleteCommentInput {
+    pub account_id: FizzyId,
+    pub user_id: FizzyId,
+    pub comment_id: FizzyId,
+}
+
+impl DeleteCommentUseCase {
+    pub fn new(
+        comment_repository: Arc<dyn CommentRepository>,
+        card_repository: Arc<dyn CardRepository>,
+        board_repository: Arc<dyn BoardRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        event_repository: Arc<dyn EventRepository>,
+    ) -> Self {
+        let access = CommentAccess::new(
+            comment_repository.clone(),
+            card_repository,
+            board_repository,
+            user_repository,
+        );
+
+        Self { comment_repository, access, event_repository }
+    }
+
+    /// Look up a comment the user may delete: on a board they can access, and
+    /// their own unless they are an admin. Returns it with its card.
+    pub async fn find_deletable(
+        &self,
+        account_id: &FizzyId,
+        user_id: &FizzyId,
+        comment_id: &FizzyId,
+    ) -> Result<(Comment, Card), ApplicationError> {
+        self.access.find_changeable(account_id, user_id, comment_id, "delete").await
+    }
+
+    pub async fn execute(&self, input: DeleteCommentInput) -> Result<(), ApplicationError> {
+        let (comment, card) = self
+            .find_deletable(&input.account_id, &input.user_id, &input.comment_id)
+            .await?;
+
+        self.comment_repository
+            .delete(&input.account_id, &comment.id)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        // The comment is gone, so the event hangs off the card
+        let _ = self.event_repository
+            .create_event(&input.account_id, CreateEventInput {
+                board_id: card.board_id.clone(),
+                eventable_id: card.id.clone(),
+                eventable_type: "Card".to_string(),
+                creator_id: input.user_id,
+                action: event_actions::COMMENT_DELETED.to_string(),
+                particulars: serde_json::json!({
+                    "comment_id": comment.id.as_str()
+                }),
+            })
+            .await;
+
+        Ok(())
+    }
+}
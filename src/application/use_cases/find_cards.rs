@@ -0,0 +1,110 @@
+use std::sync::Arc;
+use crate::domain::entities::Card;
+use crate::domain::ports::{BoardRepository, CardFilters, CardRepository};
+use crate::domain::value_objects::FizzyId;
+use crate::application::errors::ApplicationError;
+
+/// Quick card lookup by number or title, as typed into a Telegram inline query
+pub struct FindCardsUseCase {
+    card_repository: Arc<dyn CardRepository>,
+    board_repository: Arc<dyn BoardRepository>,
+}
+
+pub struct FindCardsInput {
+    pub account_id: FizzyId,
+    pub user_id: FizzyId,
+    /// "123", "#123" or the start of a title (or of a word in it); empty lists recent cards
+    pub query: String,
+    pub limit: usize,
+}
+
+impl FindCardsUseCase {
+    pub fn new(
+        card_repository: Arc<dyn CardRepository>,
+        board_repository: Arc<dyn BoardRepository>,
+    ) -> Self {
+        Self { card_repository, board_repository }
+    }
+
+    /// Find open cards on boards the user can see: an exact number match
+    /// first, then titles starting with the query, then titles with a word
+    /// starting with it, each most recently active first
+    pub async fn execute(&self, input: FindCardsInput) -> Result<Vec<Card>, ApplicationError> {
+        let board_ids: Vec<FizzyId> = self.board_repository
+            .list_accessible(&input.account_id, &input.user_id)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .into_iter()
+            .map(|board| board.id)
+            .collect();
+
+        let query = input.query.trim();
+        let mut cards = Vec::new();
+
+        if let Some(number) = parse_number(query)
+            && let Some(card) = self.card_repository
+                .find_by_number(&input.account_id, number)
+                .await
+                .map_err(ApplicationError::DomainError)?
+            && board_ids.contains(&card.board_id)
+        {
+            cards.push(card);
+        }
+
+        let groups = if query.is_empty() {
+            vec![CardFilters::default()]
+        } else {
+            vec![
+                CardFilters { title_prefix: Some(query.to_string()), ..Default::default() },
+                CardFilters { title_word_prefix: Some(query.to_string()), ..Default::default() },
+            ]
+        };
+
+        for group in groups {
+            if cards.len() >= input.limit {
+                break;
+            }
+
+            // Cards already found may match again, so leave room for them
+            let filters = CardFilters {
+                board_ids: Some(board_ids.clone()),
+                exclude_closed: Some(true),
+                limit: Some(input.limit as i64),
+                ..group
+            };
+
+            let matches = self.card_repository
+                .list(&input.account_id, filters)
+                .await
+                .map_err(ApplicationError::DomainError)?;
+
+            for card in matches {
+                if !cards.iter().any(|c: &Card| c.id == card.id) {
+                    cards.push(card);
+                }
+            }
+        }
+
+        cards.truncate(input.limit);
+
+        Ok(cards)
+    }
+}
+
+/// Parse "123" or "#123" as a card number
+fn parse_number(query: &str) -> Option<i64> {
+    query.strip_prefix('#').unwrap_or(query).parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_number() {
+        assert_eq!(parse_number("42"), Some(42));
+        assert_eq!(parse_number("#42"), Some(42));
+        assert_eq!(parse_number("#"), None);
+        assert_eq!(parse_number("42 login"), None);
+    }
+}
@@ -0,0 +1,113 @@
+use std::sync::Arc;
+use crate::domain::entities::{Board, Card, Column};
+use crate::domain::ports::{BoardRepository, CardRepository};
+use crate::domain::value_objects::FizzyId;
+use crate::application::errors::ApplicationError;
+use super::{BoardRef, BoardSection};
+
+pub struct GetBoardOverviewUseCase {
+    card_repository: Arc<dyn CardRepository>,
+    board_repository: Arc<dyn BoardRepository>,
+}
+
+pub struct GetBoardOverviewInput {
+    pub account_id: FizzyId,
+    pub user_id: FizzyId,
+    pub board: BoardRef,
+    /// Sections with more cards than this only report their count
+    pub max_cards_per_section: usize,
+}
+
+/// One section of the board view
+pub struct SectionOverview {
+    pub section: BoardSection,
+    /// The column, for `BoardSection::Column`
+    pub column: Option<Column>,
+    pub count: i64,
+    /// The section's cards, most recently active first; empty when collapsed
+    pub cards: Vec<Card>,
+}
+
+impl SectionOverview {
+    pub fn is_collapsed(&self) -> bool {
+        self.count > 0 && self.cards.is_empty()
+    }
+}
+
+pub struct GetBoardOverviewOutput {
+    pub board: Board,
+    /// Awaiting triage, the columns in position order, then Not now and Done
+    pub sections: Vec<SectionOverview>,
+}
+
+impl GetBoardOverviewUseCase {
+    pub fn new(
+        card_repository: Arc<dyn CardRepository>,
+        board_repository: Arc<dyn BoardRepository>,
+    ) -> Self {
+        Self { card_repository, board_repository }
+    }
+
+    pub async fn execute(&self, input: GetBoardOverviewInput) -> Result<GetBoardOverviewOutput, ApplicationError> {
+        let board = match input.board {
+            BoardRef::Name(name) => self.board_repository
+                .find_by_name(&input.account_id, &name)
+                .await
+                .map_err(ApplicationError::DomainError)?
+                .ok_or_else(|| ApplicationError::NotFound(format!("Board '{}' not found", name)))?,
+            BoardRef::Id(id) => self.board_repository
+                .find_by_id(&input.account_id, &id)
+                .await
+                .map_err(ApplicationError::DomainError)?
+                .ok_or_else(|| ApplicationError::NotFound("Board not found".to_string()))?,
+        };
+
+        let has_access = self.board_repository
+            .user_has_access(&input.account_id, &board.id, &input.user_id)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        if !has_access {
+            return Err(ApplicationError::Unauthorized("No access to this board".to_string()));
+        }
+
+        let columns = self.board_repository
+            .get_columns(&input.account_id, &board.id)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        let mut layout = vec![(BoardSection::AwaitingTriage, None)];
+        layout.extend(
+            columns
+                .into_iter()
+                .map(|column| (BoardSection::Column(column.id.as_str().to_string()), Some(column))),
+        );
+        // Postponed and finished cards are rarely what you look for; only count them
+        layout.push((BoardSection::NotNow, None));
+        layout.push((BoardSection::Done, None));
+
+        let mut sections = Vec::with_capacity(layout.len());
+        for (section, column) in layout {
+            let filters = section.filters(&board.id, column.as_ref());
+
+            let count = self.card_repository
+                .count(&input.account_id, filters.clone())
+                .await
+                .map_err(ApplicationError::DomainError)?;
+
+            let listed = !matches!(section, BoardSection::NotNow | BoardSection::Done);
+            let cards = if listed && count > 0 && count <= input.max_cards_per_section as i64 {
+                self.card_repository
+                    .list(&input.account_id, filters)
+                    .await
+                    .map_err(ApplicationError::DomainError)?
+            } else {
+                Vec::new()
+            };
+
+            sections.push(SectionOverview { section, column, count, cards });
+        }
+
+        Ok(GetBoardOverviewOutput { board, sections })
+    }
+}
@@ -0,0 +1,298 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use chrono::NaiveDate;
+use crate::domain::entities::{Card, Event};
+use crate::domain::ports::{
+    event_actions, BoardRepository, CardRepository, CommentRepository, EventRepository,
+    UserRepository,
+};
+use crate::domain::value_objects::FizzyId;
+use crate::application::errors::ApplicationError;
+
+/// What happened in a timeline entry, with the IDs in its particulars resolved to names
+#[derive(Debug, Clone, PartialEq)]
+pub enum Activity {
+    Created,
+    /// Moved into a column; `None` if the column no longer exists
+    Moved { column: Option<String> },
+    SentBackToTriage,
+    BoardChanged { board: Option<String> },
+    Closed,
+    Reopened,
+    Postponed,
+    Resumed,
+    AutoPostponed,
+    /// Comment HTML; `None` if the comment was deleted
+    Commented { content: Option<String> },
+    CommentEdited,
+    CommentDeleted,
+    Assigned(Vec<String>),
+    Unassigned(Vec<String>),
+    /// New due date; `None` if it was removed
+    DueDateChanged(Option<NaiveDate>),
+    TitleChanged { title: Option<String> },
+    Updated,
+    Gilded,
+    Ungilded,
+    /// Tag titles added or removed by the bot
+    Tagged(Vec<String>),
+    Untagged(Vec<String>),
+    /// An action the bot doesn't know how to describe
+    Other(String),
+}
+
+pub struct HistoryEntry {
+    pub event: Event,
+    pub activity: Activity,
+}
+
+pub struct GetCardHistoryUseCase {
+    card_repository: Arc<dyn CardRepository>,
+    board_repository: Arc<dyn BoardRepository>,
+    event_repository: Arc<dyn EventRepository>,
+    comment_repository: Arc<dyn CommentRepository>,
+    user_repository: Arc<dyn UserRepository>,
+}
+
+pub struct GetCardHistoryInput {
+    pub account_id: FizzyId,
+    pub user_id: FizzyId,
+    pub card_number: i64,
+    pub page: usize,
+    pub per_page: usize,
+}
+
+pub struct GetCardHistoryOutput {
+    pub card: Card,
+    /// Oldest first
+    pub entries: Vec<HistoryEntry>,
+    pub page: usize,
+    pub has_more: bool,
+}
+
+/// Names referenced by a page of events, keyed by the ID strings in their particulars
+#[derive(Default)]
+struct Names {
+    columns: HashMap<String, String>,
+    users: HashMap<String, String>,
+    comments: HashMap<String, String>,
+}
+
+impl GetCardHistoryUseCase {
+    pub fn new(
+        card_repository: Arc<dyn CardRepository>,
+        board_repository: Arc<dyn BoardRepository>,
+        event_repository: Arc<dyn EventRepository>,
+        comment_repository: Arc<dyn CommentRepository>,
+        user_repository: Arc<dyn UserRepository>,
+    ) -> Self {
+        Self { card_repository, board_repository, event_repository, comment_repository, user_repository }
+    }
+
+    pub async fn execute(&self, input: GetCardHistoryInput) -> Result<GetCardHistoryOutput, ApplicationError> {
+        let card = self.card_repository
+            .find_by_number(&input.account_id, input.card_number)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Card #{} not found", input.card_number)))?;
+
+        let has_access = self.board_repository
+            .user_has_access(&input.account_id, &card.board_id, &input.user_id)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        if !has_access {
+            return Err(ApplicationError::Unauthorized("No access to this board".to_string()));
+        }
+
+        // Fetch one extra event to find out whether there is a next page
+        let mut events = self.event_repository
+            .list_for_card(
+                &input.account_id,
+                &card.id,
+                input.per_page as i64 + 1,
+                (input.page * input.per_page) as i64,
+            )
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        let has_more = events.len() > input.per_page;
+        events.truncate(input.per_page);
+
+        let names = self.resolve_names(&input.account_id, &card, &events).await?;
+        let entries = events
+            .into_iter()
+            .map(|event| HistoryEntry {
+                activity: decode(&event, &names),
+                event,
+            })
+            .collect();
+
+        Ok(GetCardHistoryOutput { card, entries, page: input.page, has_more })
+    }
+
+    async fn resolve_names(
+        &self,
+        account_id: &FizzyId,
+        card: &Card,
+        events: &[Event],
+    ) -> Result<Names, ApplicationError> {
+        let mut names = Names::default();
+
+        // Columns of other boards (before a board change) stay unresolved
+        let columns = self.board_repository
+            .get_columns(account_id, &card.board_id)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+        for column in columns {
+            names.columns.insert(column.id.as_str().to_string(), column.name);
+        }
+
+        for event in events {
+            for user_id in string_array(event.particular("assignee_ids")) {
+                if names.users.contains_key(user_id) {
+                    continue;
+                }
+                let user = self.user_repository
+                    .find_by_id(account_id, &FizzyId::new(user_id.to_string()))
+                    .await
+                    .map_err(ApplicationError::DomainError)?;
+                if let Some(user) = user {
+                    names.users.insert(user_id.to_string(), user.name);
+                }
+            }
+
+            if event.eventable_type == "Comment" {
+                let comment = self.comment_repository
+                    .find_by_id(account_id, &event.eventable_id)
+                    .await
+                    .map_err(ApplicationError::DomainError)?;
+                if let Some(comment) = comment {
+                    names.comments.insert(event.eventable_id.as_str().to_string(), comment.content);
+                }
+            }
+        }
+
+        Ok(names)
+    }
+}
+
+/// Decode an event's action and particulars
+fn decode(event: &Event, names: &Names) -> Activity {
+    let text = |key: &str| event.particular(key).and_then(|v| v.as_str());
+    let user_names = || {
+        string_array(event.particular("assignee_ids"))
+            .filter_map(|id| names.users.get(id).cloned())
+            .collect()
+    };
+    let tag_titles = || {
+        string_array(event.particular("tag_titles"))
+            .map(|title| format!("#{}", title))
+            .collect()
+    };
+
+    match event.action.as_str() {
+        event_actions::CARD_CREATED | event_actions::CARD_PUBLISHED => Activity::Created,
+        event_actions::CARD_COLUMN_CHANGED | event_actions::CARD_TRIAGED => {
+            // The bot records the column's ID, the web app its name
+            let column = text("column_id")
+                .and_then(|id| names.columns.get(id).cloned())
+                .or_else(|| text("column").map(str::to_string));
+            Activity::Moved { column }
+        }
+        event_actions::CARD_SENT_BACK_TO_TRIAGE => Activity::SentBackToTriage,
+        event_actions::CARD_BOARD_CHANGED => Activity::BoardChanged {
+            board: text("new_board").map(str::to_string),
+        },
+        event_actions::CARD_CLOSED => Activity::Closed,
+        event_actions::CARD_REOPENED => Activity::Reopened,
+        event_actions::CARD_POSTPONED => Activity::Postponed,
+        event_actions::CARD_RESUMED => Activity::Resumed,
+        event_actions::CARD_AUTO_POSTPONED => Activity::AutoPostponed,
+        event_actions::COMMENT_CREATED => Activity::Commented {
+            content: names.comments.get(event.eventable_id.as_str()).cloned(),
+        },
+        event_actions::COMMENT_UPDATED => Activity::CommentEdited,
+        event_actions::COMMENT_DELETED => Activity::CommentDeleted,
+        event_actions::CARD_ASSIGNED => Activity::Assigned(user_names()),
+        event_actions::CARD_UNASSIGNED => Activity::Unassigned(user_names()),
+        event_actions::CARD_DUE_DATE_CHANGED => {
+            // Dates may come with a time attached
+            let due_on = text("due_on")
+                .or_else(|| text("due_date"))
+                .and_then(|date| date.get(..10))
+                .and_then(|date| date.parse().ok());
+            Activity::DueDateChanged(due_on)
+        }
+        event_actions::CARD_TITLE_CHANGED => Activity::TitleChanged {
+            title: text("new_title").map(str::to_string),
+        },
+        event_actions::CARD_UPDATED => Activity::Updated,
+        event_actions::CARD_GILDED => Activity::Gilded,
+        event_actions::CARD_UNGILDED => Activity::Ungilded,
+        event_actions::CARD_TAGGED => Activity::Tagged(tag_titles()),
+        event_actions::CARD_UNTAGGED => Activity::Untagged(tag_titles()),
+        other => Activity::Other(other.to_string()),
+    }
+}
+
+fn string_array(value: Option<&serde_json::Value>) -> impl Iterator<Item = &str> {
+    value
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|v| v.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn event(action: &str, particulars: serde_json::Value) -> Event {
+        Event {
+            id: FizzyId::generate(),
+            account_id: FizzyId::generate(),
+            board_id: FizzyId::generate(),
+            eventable_id: FizzyId::new("0".repeat(25)),
+            eventable_type: "Card".to_string(),
+            creator_id: FizzyId::generate(),
+            action: action.to_string(),
+            particulars,
+            created_at: Utc::now(),
+            card_id: None,
+            creator_name: None,
+        }
+    }
+
+    #[test]
+    fn test_decode_resolves_particulars() {
+        let mut names = Names::default();
+        names.columns.insert("col1".to_string(), "Doing".to_string());
+        names.users.insert("user1".to_string(), "Alice".to_string());
+
+        let moved = event("card_column_changed", serde_json::json!({ "column_id": "col1" }));
+        assert_eq!(decode(&moved, &names), Activity::Moved { column: Some("Doing".to_string()) });
+
+        let gone = event("card_column_changed", serde_json::json!({ "column_id": "col2" }));
+        assert_eq!(decode(&gone, &names), Activity::Moved { column: None });
+
+        let triaged = event("card_triaged", serde_json::json!({ "particulars": { "column": "Done" } }));
+        assert_eq!(decode(&triaged, &names), Activity::Moved { column: Some("Done".to_string()) });
+
+        let assigned = event("card_assigned", serde_json::json!({ "assignee_ids": ["user1", "user2"] }));
+        assert_eq!(decode(&assigned, &names), Activity::Assigned(vec!["Alice".to_string()]));
+
+        let due = event("card_due_date_changed", serde_json::json!({ "due_on": "2026-11-02" }));
+        assert_eq!(decode(&due, &names), Activity::DueDateChanged(NaiveDate::from_ymd_opt(2026, 11, 2)));
+
+        let cleared = event("card_due_date_changed", serde_json::json!({ "due_on": null }));
+        assert_eq!(decode(&cleared, &names), Activity::DueDateChanged(None));
+
+        let tagged = event("card_tagged", serde_json::json!({ "tag_titles": ["bug", "ux"] }));
+        assert_eq!(decode(&tagged, &names), Activity::Tagged(vec!["#bug".to_string(), "#ux".to_string()]));
+
+        let unknown = event("card_boosted", serde_json::json!({}));
+        assert_eq!(decode(&unknown, &names), Activity::Other("card_boosted".to_string()));
+    }
+}
@@ -0,0 +1,97 @@
+use std::sync::Arc;
+use crate::domain::entities::{Board, Card, Column};
+use crate::domain::ports::{BoardRepository, CardFilters, CardRepository};
+use crate::domain::value_objects::{CardStatus, FizzyId};
+use crate::application::errors::ApplicationError;
+
+/// Which board to triage: by name from a command, or by ID when continuing from a button
+pub enum BoardRef {
+    Name(String),
+    Id(FizzyId),
+}
+
+pub struct GetTriageCardUseCase {
+    card_repository: Arc<dyn CardRepository>,
+    board_repository: Arc<dyn BoardRepository>,
+}
+
+pub struct GetTriageCardInput {
+    pub account_id: FizzyId,
+    pub user_id: FizzyId,
+    pub board: BoardRef,
+    /// Number of cards skipped so far in this triage session
+    pub skip: usize,
+}
+
+pub struct GetTriageCardOutput {
+    pub board: Board,
+    /// Next card to triage, or `None` when the queue (minus skipped cards) is exhausted
+    pub card: Option<Card>,
+    /// Columns the card can be placed in
+    pub columns: Vec<Column>,
+    /// Total number of cards awaiting triage on the board
+    pub total: usize,
+}
+
+impl GetTriageCardUseCase {
+    pub fn new(
+        card_repository: Arc<dyn CardRepository>,
+        board_repository: Arc<dyn BoardRepository>,
+    ) -> Self {
+        Self { card_repository, board_repository }
+    }
+
+    pub async fn execute(&self, input: GetTriageCardInput) -> Result<GetTriageCardOutput, ApplicationError> {
+        let board = match input.board {
+            BoardRef::Name(name) => self.board_repository
+                .find_by_name(&input.account_id, &name)
+                .await
+                .map_err(ApplicationError::DomainError)?
+                .ok_or_else(|| ApplicationError::NotFound(format!("Board '{}' not found", name)))?,
+            BoardRef::Id(id) => self.board_repository
+                .find_by_id(&input.account_id, &id)
+                .await
+                .map_err(ApplicationError::DomainError)?
+                .ok_or_else(|| ApplicationError::NotFound("Board not found".to_string()))?,
+        };
+
+        let has_access = self.board_repository
+            .user_has_access(&input.account_id, &board.id, &input.user_id)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        if !has_access {
+            return Err(ApplicationError::Unauthorized("No access to this board".to_string()));
+        }
+
+        let filters = CardFilters {
+            board_id: Some(board.id.clone()),
+            awaiting_triage: Some(true),
+            exclude_status: Some(vec![CardStatus::Closed, CardStatus::NotNow]),
+            ..Default::default()
+        };
+
+        let total = self.card_repository
+            .count(&input.account_id, filters.clone())
+            .await
+            .map_err(ApplicationError::DomainError)? as usize;
+
+        let card = self.card_repository
+            .list(&input.account_id, CardFilters {
+                limit: Some(1),
+                offset: Some(input.skip as i64),
+                ..filters
+            })
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .into_iter()
+            .next();
+
+        let columns = self.board_repository
+            .get_columns(&input.account_id, &board.id)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        Ok(GetTriageCardOutput { board, card, columns, total })
+    }
+}
@@ -0,0 +1,75 @@
+use std::sync::Arc;
+use crate::domain::entities::Card;
+use crate::domain::ports::{BoardRepository, CardRepository, EventRepository, CreateEventInput, event_actions};
+use crate::domain::value_objects::FizzyId;
+use crate::application::errors::ApplicationError;
+
+pub struct GildCardUseCase {
+    card_repository: Arc<dyn CardRepository>,
+    board_repository: Arc<dyn BoardRepository>,
+    event_repository: Arc<dyn EventRepository>,
+}
+
+pub struct GildCardInput {
+    pub account_id: FizzyId,
+    pub user_id: FizzyId,
+    pub card_number: i64,
+    /// True to gild, false to ungild
+    pub golden: bool,
+}
+
+impl GildCardUseCase {
+    pub fn new(
+        card_repository: Arc<dyn CardRepository>,
+        board_repository: Arc<dyn BoardRepository>,
+        event_repository: Arc<dyn EventRepository>,
+    ) -> Self {
+        Self { card_repository, board_repository, event_repository }
+    }
+
+    pub async fn execute(&self, input: GildCardInput) -> Result<Card, ApplicationError> {
+        let card = self.card_repository
+            .find_by_number(&input.account_id, input.card_number)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Card #{} not found", input.card_number)))?;
+
+        let has_access = self.board_repository
+            .user_has_access(&input.account_id, &card.board_id, &input.user_id)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        if !has_access {
+            return Err(ApplicationError::Unauthorized("No access to this board".to_string()));
+        }
+
+        let changed = self.card_repository
+            .set_golden(&input.account_id, &card.id, input.golden)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        if !changed {
+            return Ok(card);
+        }
+
+        let action = if input.golden {
+            event_actions::CARD_GILDED
+        } else {
+            event_actions::CARD_UNGILDED
+        };
+
+        // Create event
+        let _ = self.event_repository
+            .create_event(&input.account_id, CreateEventInput {
+                board_id: card.board_id.clone(),
+                eventable_id: card.id.clone(),
+                eventable_type: "Card".to_string(),
+                creator_id: input.user_id,
+                action: action.to_string(),
+                particulars: serde_json::json!({}),
+            })
+            .await;
+
+        Ok(Card { is_golden: input.golden, ..card })
+    }
+}
@@ -1,8 +1,59 @@
 use std::sync::Arc;
-use crate::domain::entities::Card;
-use crate::domain::ports::{CardRepository, CardFilters, BoardRepository};
-use crate::domain::value_objects::{FizzyId, CardStatus};
+use crate::domain::entities::{Board, Column};
+use crate::domain::ports::{CardRepository, CardFilters, CardListPage, CardPage, BoardRepository};
+use crate::domain::value_objects::FizzyId;
 use crate::application::errors::ApplicationError;
+use super::BoardRef;
+
+/// A slice of a board's cards, as laid out in Fizzy's board view
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BoardSection {
+    /// Every active card, whatever its column
+    Open,
+    /// Active cards not yet placed in a column
+    AwaitingTriage,
+    /// Active cards in one column, given by its ID or the end of it. The
+    /// end of an ID is random, so a few characters tell a board's columns
+    /// apart where there is no room for the whole ID.
+    Column(String),
+    NotNow,
+    Done,
+}
+
+impl BoardSection {
+    /// Filters selecting this section's cards; `column` is the resolved column for `Column`
+    pub fn filters(&self, board_id: &FizzyId, column: Option<&Column>) -> CardFilters {
+        let active = CardFilters {
+            board_id: Some(board_id.clone()),
+            exclude_closed: Some(true),
+            exclude_not_now: Some(true),
+            ..Default::default()
+        };
+
+        match self {
+            BoardSection::Open => active,
+            BoardSection::AwaitingTriage => CardFilters {
+                awaiting_triage: Some(true),
+                ..active
+            },
+            BoardSection::Column(_) => CardFilters {
+                column_id: column.map(|c| c.id.clone()),
+                ..active
+            },
+            BoardSection::NotNow => CardFilters {
+                board_id: Some(board_id.clone()),
+                exclude_closed: Some(true),
+                only_not_now: Some(true),
+                ..Default::default()
+            },
+            BoardSection::Done => CardFilters {
+                board_id: Some(board_id.clone()),
+                only_closed: Some(true),
+                ..Default::default()
+            },
+        }
+    }
+}
 
 pub struct ListBoardCardsUseCase {
     card_repository: Arc<dyn CardRepository>,
@@ -12,13 +63,17 @@ pub struct ListBoardCardsUseCase {
 pub struct ListBoardCardsInput {
     pub account_id: FizzyId,
     pub user_id: FizzyId,
-    pub board_name: String,
-    pub limit: Option<i64>,
+    pub board: BoardRef,
+    pub section: BoardSection,
+    pub page: CardPage,
+    pub per_page: usize,
 }
 
 pub struct ListBoardCardsOutput {
-    pub cards: Vec<Card>,
-    pub board_name: String,
+    pub board: Board,
+    /// The listed column, for `BoardSection::Column`
+    pub column: Option<Column>,
+    pub page: CardListPage,
 }
 
 impl ListBoardCardsUseCase {
@@ -30,11 +85,18 @@ impl ListBoardCardsUseCase {
     }
 
     pub async fn execute(&self, input: ListBoardCardsInput) -> Result<ListBoardCardsOutput, ApplicationError> {
-        let board = self.board_repository
-            .find_by_name(&input.account_id, &input.board_name)
-            .await
-            .map_err(ApplicationError::DomainError)?
-            .ok_or_else(|| ApplicationError::NotFound(format!("Board '{}' not found", input.board_name)))?;
+        let board = match input.board {
+            BoardRef::Name(name) => self.board_repository
+                .find_by_name(&input.account_id, &name)
+                .await
+                .map_err(ApplicationError::DomainError)?
+                .ok_or_else(|| ApplicationError::NotFound(format!("Board '{}' not found", name)))?,
+            BoardRef::Id(id) => self.board_repository
+                .find_by_id(&input.account_id, &id)
+                .await
+                .map_err(ApplicationError::DomainError)?
+                .ok_or_else(|| ApplicationError::NotFound("Board not found".to_string()))?,
+        };
 
         let has_access = self.board_repository
             .user_has_access(&input.account_id, &board.id, &input.user_id)
@@ -45,21 +107,34 @@ impl ListBoardCardsUseCase {
             return Err(ApplicationError::Unauthorized("No access to this board".to_string()));
         }
 
-        let filters = CardFilters {
-            board_id: Some(board.id.clone()),
-            exclude_status: Some(vec![CardStatus::Closed, CardStatus::NotNow]),
-            limit: input.limit.or(Some(20)),
-            ..Default::default()
+        let column = match &input.section {
+            BoardSection::Column(key) => {
+                let columns = self.board_repository
+                    .get_columns(&input.account_id, &board.id)
+                    .await
+                    .map_err(ApplicationError::DomainError)?;
+                let column = columns
+                    .into_iter()
+                    .find(|column| !key.is_empty() && column.id.as_str().ends_with(key.as_str()))
+                    .ok_or_else(|| ApplicationError::NotFound("Column not found".to_string()))?;
+                Some(column)
+            }
+            _ => None,
         };
 
+        let filters = input.section
+            .filters(&board.id, column.as_ref())
+            .page(input.page, input.per_page);
+
         let cards = self.card_repository
             .list(&input.account_id, filters)
             .await
             .map_err(ApplicationError::DomainError)?;
 
         Ok(ListBoardCardsOutput {
-            cards,
-            board_name: board.name,
+            board,
+            column,
+            page: CardListPage::new(cards, input.page, input.per_page),
         })
     }
 }
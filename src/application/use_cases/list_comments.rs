@@ -0,0 +1,83 @@
+use std::sync::Arc;
+use crate::domain::entities::{Card, Comment};
+use crate::domain::ports::{BoardRepository, CardRepository, CommentRepository, UserRepository};
+use crate::domain::value_objects::FizzyId;
+use crate::application::errors::ApplicationError;
+
+pub struct ListCommentsUseCase {
+    card_repository: Arc<dyn CardRepository>,
+    board_repository: Arc<dyn BoardRepository>,
+    comment_repository: Arc<dyn CommentRepository>,
+    user_repository: Arc<dyn UserRepository>,
+}
+
+pub struct ListCommentsInput {
+    pub account_id: FizzyId,
+    pub user_id: FizzyId,
+    pub card_number: i64,
+    /// Page 0 holds the most recent comments
+    pub page: usize,
+    pub per_page: usize,
+}
+
+pub struct ListCommentsOutput {
+    pub card: Card,
+    /// Oldest first within the page
+    pub comments: Vec<Comment>,
+    pub page: usize,
+    /// Whether there are older comments
+    pub has_older: bool,
+    /// Whether the viewer may edit and delete other people's comments
+    pub viewer_is_admin: bool,
+}
+
+impl ListCommentsUseCase {
+    pub fn new(
+        card_repository: Arc<dyn CardRepository>,
+        board_repository: Arc<dyn BoardRepository>,
+        comment_repository: Arc<dyn CommentRepository>,
+        user_repository: Arc<dyn UserRepository>,
+    ) -> Self {
+        Self { card_repository, board_repository, comment_repository, user_repository }
+    }
+
+    pub async fn execute(&self, input: ListCommentsInput) -> Result<ListCommentsOutput, ApplicationError> {
+        let card = self.card_repository
+            .find_by_number(&input.account_id, input.card_number)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Card #{} not found", input.card_number)))?;
+
+        let has_access = self.board_repository
+            .user_has_access(&input.account_id, &card.board_id, &input.user_id)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        if !has_access {
+            return Err(ApplicationError::Unauthorized("No access to this board".to_string()));
+        }
+
+        // Fetch one extra comment to find out whether there are older ones
+        let mut comments = self.comment_repository
+            .list_for_card(
+                &input.account_id,
+                &card.id,
+                input.per_page as i64 + 1,
+                (input.page * input.per_page) as i64,
+            )
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        let has_older = comments.len() > input.per_page;
+        comments.truncate(input.per_page);
+        comments.reverse();
+
+        let viewer_is_admin = self.user_repository
+            .find_by_id(&input.account_id, &input.user_id)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .is_some_and(|u| u.is_admin());
+
+        Ok(ListCommentsOutput { card, comments, page: input.page, has_older, viewer_is_admin })
+    }
+}
@@ -0,0 +1,66 @@
+use std::sync::Arc;
+use chrono::NaiveDate;
+use crate::domain::entities::Card;
+use crate::domain::ports::{AssignmentRepository, CardFilters, CardRepository};
+use crate::domain::value_objects::{CardStatus, FizzyId};
+use crate::application::errors::ApplicationError;
+
+pub struct ListDueCardsUseCase {
+    card_repository: Arc<dyn CardRepository>,
+    assignment_repository: Arc<dyn AssignmentRepository>,
+}
+
+pub struct ListDueCardsInput {
+    pub account_id: FizzyId,
+    pub due_on_or_before: NaiveDate,
+}
+
+/// An open card with a due date, and who should hear about it
+pub struct DueCard {
+    pub card: Card,
+    /// The card's assignees, or its creator when nobody is assigned
+    pub recipient_ids: Vec<FizzyId>,
+}
+
+pub struct ListDueCardsOutput {
+    pub cards: Vec<DueCard>,
+}
+
+impl ListDueCardsUseCase {
+    pub fn new(
+        card_repository: Arc<dyn CardRepository>,
+        assignment_repository: Arc<dyn AssignmentRepository>,
+    ) -> Self {
+        Self { card_repository, assignment_repository }
+    }
+
+    pub async fn execute(&self, input: ListDueCardsInput) -> Result<ListDueCardsOutput, ApplicationError> {
+        let filters = CardFilters {
+            due_on_or_before: Some(input.due_on_or_before),
+            exclude_status: Some(vec![CardStatus::Closed, CardStatus::NotNow]),
+            exclude_closed: Some(true),
+            ..Default::default()
+        };
+
+        let cards = self.card_repository
+            .list(&input.account_id, filters)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        let mut due_cards = Vec::with_capacity(cards.len());
+        for card in cards {
+            let mut recipient_ids = self.assignment_repository
+                .list_assignee_ids(&input.account_id, &card.id)
+                .await
+                .map_err(ApplicationError::DomainError)?;
+
+            if recipient_ids.is_empty() {
+                recipient_ids.push(card.creator_id.clone());
+            }
+
+            due_cards.push(DueCard { card, recipient_ids });
+        }
+
+        Ok(ListDueCardsOutput { cards: due_cards })
+    }
+}
@@ -0,0 +1,56 @@
+use std::sync::Arc;
+use crate::domain::ports::{BoardRepository, CardFilters, CardListPage, CardPage, CardRepository};
+use crate::domain::value_objects::FizzyId;
+use crate::application::errors::ApplicationError;
+
+pub struct ListGoldenCardsUseCase {
+    card_repository: Arc<dyn CardRepository>,
+    board_repository: Arc<dyn BoardRepository>,
+}
+
+pub struct ListGoldenCardsInput {
+    pub account_id: FizzyId,
+    pub user_id: FizzyId,
+    pub page: CardPage,
+    pub per_page: usize,
+}
+
+pub struct ListGoldenCardsOutput {
+    pub page: CardListPage,
+}
+
+impl ListGoldenCardsUseCase {
+    pub fn new(
+        card_repository: Arc<dyn CardRepository>,
+        board_repository: Arc<dyn BoardRepository>,
+    ) -> Self {
+        Self { card_repository, board_repository }
+    }
+
+    pub async fn execute(&self, input: ListGoldenCardsInput) -> Result<ListGoldenCardsOutput, ApplicationError> {
+        let board_ids: Vec<FizzyId> = self.board_repository
+            .list_accessible(&input.account_id, &input.user_id)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .into_iter()
+            .map(|board| board.id)
+            .collect();
+
+        // Only show golden cards on boards the user can see
+        let filters = CardFilters {
+            is_golden: Some(true),
+            exclude_closed: Some(true),
+            board_ids: Some(board_ids),
+            ..CardFilters::default().page(input.page, input.per_page)
+        };
+
+        let cards = self.card_repository
+            .list(&input.account_id, filters)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        Ok(ListGoldenCardsOutput {
+            page: CardListPage::new(cards, input.page, input.per_page),
+        })
+    }
+}
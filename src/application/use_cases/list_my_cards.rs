@@ -1,6 +1,5 @@
 use crate::application::errors::ApplicationError;
-use crate::domain::entities::Card;
-use crate::domain::ports::{CardFilters, CardRepository};
+use crate::domain::ports::{CardFilters, CardListPage, CardPage, CardRepository};
 use crate::domain::value_objects::FizzyId;
 use std::sync::Arc;
 
@@ -13,11 +12,12 @@ pub struct ListMyCardsInput {
     pub account_id: FizzyId,
     pub user_id: FizzyId,
     pub include_closed: bool,
-    pub limit: Option<i64>,
+    pub page: CardPage,
+    pub per_page: usize,
 }
 
 pub struct ListMyCardsOutput {
-    pub cards: Vec<Card>,
+    pub page: CardListPage,
 }
 
 impl ListMyCardsUseCase {
@@ -36,9 +36,9 @@ impl ListMyCardsUseCase {
             } else {
                 Some(true)
             },
-            limit: input.limit.or(Some(20)),
             ..Default::default()
-        };
+        }
+        .page(input.page, input.per_page);
 
         let cards = self
             .card_repository
@@ -46,6 +46,8 @@ impl ListMyCardsUseCase {
             .await
             .map_err(ApplicationError::DomainError)?;
 
-        Ok(ListMyCardsOutput { cards })
+        Ok(ListMyCardsOutput {
+            page: CardListPage::new(cards, input.page, input.per_page),
+        })
     }
 }
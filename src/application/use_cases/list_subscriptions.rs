@@ -0,0 +1,58 @@
+use std::sync::Arc;
+use crate::domain::entities::SubscriptionTarget;
+use crate::domain::ports::{BoardRepository, CardRepository, SubscriptionRepository};
+use crate::domain::value_objects::FizzyId;
+use crate::application::errors::ApplicationError;
+use super::SubscribedTarget;
+
+pub struct ListSubscriptionsUseCase {
+    subscription_repository: Arc<dyn SubscriptionRepository>,
+    board_repository: Arc<dyn BoardRepository>,
+    card_repository: Arc<dyn CardRepository>,
+}
+
+pub struct ListSubscriptionsInput {
+    pub account_id: FizzyId,
+    pub chat_id: i64,
+}
+
+pub struct ListSubscriptionsOutput {
+    /// Subscribed boards and cards; ones deleted in Fizzy are left out
+    pub targets: Vec<SubscribedTarget>,
+}
+
+impl ListSubscriptionsUseCase {
+    pub fn new(
+        subscription_repository: Arc<dyn SubscriptionRepository>,
+        board_repository: Arc<dyn BoardRepository>,
+        card_repository: Arc<dyn CardRepository>,
+    ) -> Self {
+        Self { subscription_repository, board_repository, card_repository }
+    }
+
+    pub async fn execute(&self, input: ListSubscriptionsInput) -> Result<ListSubscriptionsOutput, ApplicationError> {
+        let subscriptions = self.subscription_repository
+            .list_for_chat(input.chat_id)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        let mut targets = Vec::with_capacity(subscriptions.len());
+        for subscription in subscriptions {
+            let target = match subscription.target {
+                SubscriptionTarget::Board(id) => self.board_repository
+                    .find_by_id(&input.account_id, &id)
+                    .await
+                    .map_err(ApplicationError::DomainError)?
+                    .map(SubscribedTarget::Board),
+                SubscriptionTarget::Card(id) => self.card_repository
+                    .find_by_id(&input.account_id, &id)
+                    .await
+                    .map_err(ApplicationError::DomainError)?
+                    .map(|card| SubscribedTarget::Card(Box::new(card))),
+            };
+            targets.extend(target);
+        }
+
+        Ok(ListSubscriptionsOutput { targets })
+    }
+}
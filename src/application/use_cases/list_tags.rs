@@ -0,0 +1,58 @@
+use std::sync::Arc;
+use crate::domain::entities::{Card, Tag};
+use crate::domain::ports::{CardRepository, TagRepository};
+use crate::domain::value_objects::FizzyId;
+use crate::application::errors::ApplicationError;
+
+pub struct ListTagsUseCase {
+    card_repository: Arc<dyn CardRepository>,
+    tag_repository: Arc<dyn TagRepository>,
+}
+
+pub struct ListTagsInput {
+    pub account_id: FizzyId,
+    /// When set, also report which tags are on this card
+    pub card_number: Option<i64>,
+}
+
+pub struct ListTagsOutput {
+    pub tags: Vec<Tag>,
+    pub card: Option<Card>,
+    pub card_tag_ids: Vec<FizzyId>,
+}
+
+impl ListTagsUseCase {
+    pub fn new(
+        card_repository: Arc<dyn CardRepository>,
+        tag_repository: Arc<dyn TagRepository>,
+    ) -> Self {
+        Self { card_repository, tag_repository }
+    }
+
+    pub async fn execute(&self, input: ListTagsInput) -> Result<ListTagsOutput, ApplicationError> {
+        let tags = self.tag_repository
+            .list(&input.account_id)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        let Some(card_number) = input.card_number else {
+            return Ok(ListTagsOutput { tags, card: None, card_tag_ids: Vec::new() });
+        };
+
+        let card = self.card_repository
+            .find_by_number(&input.account_id, card_number)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Card #{} not found", card_number)))?;
+
+        let card_tag_ids = self.tag_repository
+            .list_for_card(&input.account_id, &card.id)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .into_iter()
+            .map(|t| t.id)
+            .collect();
+
+        Ok(ListTagsOutput { tags, card: Some(card), card_tag_ids })
+    }
+}
@@ -8,14 +8,71 @@ mod move_card;
 mod close_card;
 mod reopen_card;
 mod add_comment;
+mod search_cards;
+mod request_link;
+mod approve_link;
+mod verify_link;
+mod assign_card;
+mod unassign_card;
+mod tag_card;
+mod untag_card;
+mod toggle_card_tag;
+mod list_tags;
+mod set_due_date;
+mod gild_card;
+mod list_golden_cards;
+mod postpone_card;
+mod get_triage_card;
+mod list_due_cards;
+mod subscribe;
+mod list_subscriptions;
+mod poll_events;
+mod build_digest;
+mod get_card_history;
+mod list_comments;
+mod update_comment;
+mod delete_comment;
+mod comment_access;
+mod attach_file;
+mod read_attachment;
+mod find_cards;
+mod get_board_overview;
 
 pub use list_my_cards::{ListMyCardsUseCase, ListMyCardsInput, ListMyCardsOutput};
 pub use get_card_details::{GetCardDetailsUseCase, GetCardDetailsInput};
 pub use list_boards::{ListBoardsUseCase, ListBoardsInput, ListBoardsOutput};
-pub use list_board_cards::{ListBoardCardsUseCase, ListBoardCardsInput, ListBoardCardsOutput};
+pub use list_board_cards::{ListBoardCardsUseCase, ListBoardCardsInput, ListBoardCardsOutput, BoardSection};
 pub use create_card::{CreateCardUseCase, CreateCardUseCaseInput};
 pub use update_card::{UpdateCardUseCase, UpdateCardUseCaseInput};
 pub use move_card::{MoveCardUseCase, MoveCardInput};
 pub use close_card::{CloseCardUseCase, CloseCardInput};
 pub use reopen_card::{ReopenCardUseCase, ReopenCardInput};
 pub use add_comment::{AddCommentUseCase, AddCommentInput};
+pub use search_cards::{SearchCardsUseCase, SearchCardsInput, SearchCardsOutput, SearchResult};
+pub use request_link::{RequestLinkUseCase, RequestLinkInput, RequestLinkOutput};
+pub use approve_link::{ApproveLinkUseCase, ApproveLinkInput, ApproveLinkOutput};
+pub use verify_link::{VerifyLinkUseCase, VerifyLinkInput, VerifyLinkOutput};
+pub use assign_card::{AssignCardUseCase, AssignCardInput, AssignCardOutput, AssigneeRef};
+pub use unassign_card::{UnassignCardUseCase, UnassignCardInput, UnassignCardOutput};
+pub use tag_card::{TagCardUseCase, TagCardInput, TagCardOutput};
+pub use untag_card::{UntagCardUseCase, UntagCardInput, UntagCardOutput};
+pub use toggle_card_tag::{ToggleCardTagUseCase, ToggleCardTagInput, ToggleCardTagOutput};
+pub use list_tags::{ListTagsUseCase, ListTagsInput, ListTagsOutput};
+pub use set_due_date::{SetDueDateUseCase, SetDueDateInput};
+pub use gild_card::{GildCardUseCase, GildCardInput};
+pub use list_golden_cards::{ListGoldenCardsUseCase, ListGoldenCardsInput, ListGoldenCardsOutput};
+pub use postpone_card::{PostponeCardUseCase, PostponeCardInput};
+pub use get_triage_card::{GetTriageCardUseCase, GetTriageCardInput, GetTriageCardOutput, BoardRef};
+pub use list_due_cards::{ListDueCardsUseCase, ListDueCardsInput, ListDueCardsOutput, DueCard};
+pub use subscribe::{SubscribeUseCase, SubscribeInput, SubscribeOutput, SubscriptionRef, SubscribedTarget};
+pub use list_subscriptions::{ListSubscriptionsUseCase, ListSubscriptionsInput, ListSubscriptionsOutput};
+pub use poll_events::{PollEventsUseCase, PollEventsInput, PollEventsOutput, EventNotification};
+pub use build_digest::{BuildDigestUseCase, BuildDigestInput, Digest};
+pub use get_card_history::{GetCardHistoryUseCase, GetCardHistoryInput, GetCardHistoryOutput, HistoryEntry, Activity};
+pub use list_comments::{ListCommentsUseCase, ListCommentsInput, ListCommentsOutput};
+pub use update_comment::{UpdateCommentUseCase, UpdateCommentInput};
+pub use delete_comment::{DeleteCommentUseCase, DeleteCommentInput};
+pub use attach_file::{AttachFileUseCase, AttachFileInput, AttachFileOutput};
+pub use read_attachment::{ReadAttachmentUseCase, ReadAttachmentInput, ReadAttachmentOutput};
+pub use find_cards::{FindCardsUseCase, FindCardsInput};
+pub use get_board_overview::{GetBoardOverviewUseCase, GetBoardOverviewInput, GetBoardOverviewOutput, SectionOverview};
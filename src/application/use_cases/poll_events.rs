@@ -0,0 +1,238 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use chrono::{DateTime, Duration, Utc};
+use crate::domain::entities::{Card, Comment, Event, NotificationKind};
+use crate::domain::ports::{
+    event_actions, BoardRepository, CardRepository, CommentRepository, EventRepository,
+    SubscriptionRepository,
+};
+use crate::domain::value_objects::FizzyId;
+use crate::application::errors::ApplicationError;
+
+/// How far behind the watermark each poll reads again. Event timestamps come
+/// from whoever wrote them and rows only become visible when their transaction
+/// commits, so an event may show up after newer ones were already read.
+const OVERLAP_MINUTES: i64 = 5;
+
+pub struct PollEventsUseCase {
+    event_repository: Arc<dyn EventRepository>,
+    subscription_repository: Arc<dyn SubscriptionRepository>,
+    card_repository: Arc<dyn CardRepository>,
+    comment_repository: Arc<dyn CommentRepository>,
+    board_repository: Arc<dyn BoardRepository>,
+}
+
+pub struct PollEventsInput {
+    pub account_id: FizzyId,
+    /// Most events to process in one poll
+    pub limit: i64,
+}
+
+/// A subscribed-to event, ready to be sent to the chats that follow it
+pub struct EventNotification {
+    pub kind: NotificationKind,
+    pub event: Event,
+    pub card: Card,
+    /// The new comment, for comment events
+    pub comment: Option<Comment>,
+    /// Where a moved card went, as recorded on the event: the column, or the
+    /// board for board changes
+    pub moved_to: Option<String>,
+    pub chat_ids: Vec<i64>,
+}
+
+pub struct PollEventsOutput {
+    pub notifications: Vec<EventNotification>,
+    /// Creation time of the newest event read; pass to [`PollEventsUseCase::advance`]
+    /// once the notifications have been sent
+    pub tailed_to: Option<DateTime<Utc>>,
+}
+
+impl PollEventsUseCase {
+    pub fn new(
+        event_repository: Arc<dyn EventRepository>,
+        subscription_repository: Arc<dyn SubscriptionRepository>,
+        card_repository: Arc<dyn CardRepository>,
+        comment_repository: Arc<dyn CommentRepository>,
+        board_repository: Arc<dyn BoardRepository>,
+    ) -> Self {
+        Self { event_repository, subscription_repository, card_repository, comment_repository, board_repository }
+    }
+
+    /// Read events recorded since the watermark (less the overlap) that have
+    /// not been handled yet. Events nobody needs to hear about are marked
+    /// handled right away; the rest once [`Self::mark_delivered`] is called.
+    /// On the very first poll the watermark starts at the newest event, so
+    /// history is not replayed.
+    pub async fn execute(&self, input: PollEventsInput) -> Result<PollEventsOutput, ApplicationError> {
+        let overlap = Duration::minutes(OVERLAP_MINUTES);
+
+        let Some(watermark) = self.subscription_repository
+            .get_watermark()
+            .await
+            .map_err(ApplicationError::DomainError)?
+        else {
+            let latest = self.event_repository
+                .latest_created_at(&input.account_id)
+                .await
+                .map_err(ApplicationError::DomainError)?
+                .unwrap_or_else(Utc::now);
+
+            // Events inside the overlap are read again by the next poll
+            let recent = self.event_repository
+                .list_since(&input.account_id, latest - overlap, i64::MAX)
+                .await
+                .map_err(ApplicationError::DomainError)?;
+            for event in &recent {
+                self.mark_delivered(event).await?;
+            }
+
+            self.subscription_repository
+                .set_watermark(latest)
+                .await
+                .map_err(ApplicationError::DomainError)?;
+
+            return Ok(PollEventsOutput { notifications: Vec::new(), tailed_to: None });
+        };
+
+        let since = watermark - overlap;
+        let handled: HashSet<FizzyId> = self.subscription_repository
+            .list_handled_since(since)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .into_iter()
+            .collect();
+
+        // Handled events are read again, so leave room for them within the limit
+        let events = self.event_repository
+            .list_since(&input.account_id, since, input.limit + handled.len() as i64)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        let tailed_to = events.iter().map(|event| event.created_at).max();
+
+        let mut notifications = Vec::new();
+        for event in events.into_iter().filter(|event| !handled.contains(&event.id)) {
+            match self.notification_for(&input.account_id, &event).await? {
+                Some(notification) => notifications.push(notification),
+                None => self.mark_delivered(&event).await?,
+            }
+        }
+
+        Ok(PollEventsOutput { notifications, tailed_to })
+    }
+
+    /// Record that an event's notifications went out, so later polls skip it
+    pub async fn mark_delivered(&self, event: &Event) -> Result<(), ApplicationError> {
+        self.subscription_repository
+            .mark_handled(&event.id, event.created_at)
+            .await
+            .map_err(ApplicationError::DomainError)
+    }
+
+    /// Move the watermark up to `tailed_to`, but keep it close enough to the
+    /// oldest undelivered event that the next poll reads it again
+    pub async fn advance(
+        &self,
+        tailed_to: DateTime<Utc>,
+        oldest_undelivered: Option<DateTime<Utc>>,
+    ) -> Result<(), ApplicationError> {
+        let overlap = Duration::minutes(OVERLAP_MINUTES);
+
+        let previous = self.subscription_repository
+            .get_watermark()
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        let mut watermark = match oldest_undelivered {
+            Some(created_at) => tailed_to.min(created_at + overlap),
+            None => tailed_to,
+        };
+        if let Some(previous) = previous {
+            watermark = watermark.max(previous);
+        }
+
+        self.subscription_repository
+            .set_watermark(watermark)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        // Nothing older than the overlap is read again
+        self.subscription_repository
+            .prune_handled(watermark - overlap)
+            .await
+            .map_err(ApplicationError::DomainError)
+    }
+
+    /// Build the notification for an event, or None when no chat needs one
+    async fn notification_for(
+        &self,
+        account_id: &FizzyId,
+        event: &Event,
+    ) -> Result<Option<EventNotification>, ApplicationError> {
+        let (Some(kind), Some(card_id)) = (event.notification_kind(), event.card_id.as_ref()) else {
+            return Ok(None);
+        };
+
+        let chat_ids = self.subscription_repository
+            .list_chats_for(&event.board_id, card_id)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        if chat_ids.is_empty() {
+            return Ok(None);
+        }
+
+        // The card may have been deleted since
+        let Some(card) = self.card_repository
+            .find_by_id(account_id, card_id)
+            .await
+            .map_err(ApplicationError::DomainError)?
+        else {
+            return Ok(None);
+        };
+
+        let comment = if kind == NotificationKind::Commented {
+            self.comment_repository
+                .find_by_id(account_id, &event.eventable_id)
+                .await
+                .map_err(ApplicationError::DomainError)?
+        } else {
+            None
+        };
+
+        let moved_to = if kind == NotificationKind::Moved {
+            self.moved_to(account_id, event).await?
+        } else {
+            None
+        };
+
+        Ok(Some(EventNotification { kind, event: event.clone(), card, comment, moved_to, chat_ids }))
+    }
+
+    /// Where the event says the card went. The card itself may have moved on
+    /// again by the time the event is read.
+    async fn moved_to(&self, account_id: &FizzyId, event: &Event) -> Result<Option<String>, ApplicationError> {
+        let text = |key: &str| event.particular(key).and_then(|v| v.as_str()).map(str::to_string);
+
+        match event.action.as_str() {
+            event_actions::CARD_BOARD_CHANGED => Ok(text("new_board")),
+            event_actions::CARD_SENT_BACK_TO_TRIAGE => Ok(None),
+            // The bot records the column's ID, the web app its name
+            _ => match text("column_id") {
+                Some(column_id) => {
+                    let columns = self.board_repository
+                        .get_columns(account_id, &event.board_id)
+                        .await
+                        .map_err(ApplicationError::DomainError)?;
+
+                    Ok(columns
+                        .into_iter()
+                        .find(|column| column.id.as_str() == column_id)
+                        .map(|column| column.name))
+                }
+                None => Ok(text("column")),
+            },
+        }
+    }
+}
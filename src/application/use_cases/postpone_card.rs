@@ -0,0 +1,104 @@
+use std::sync::Arc;
+use crate::domain::entities::Card;
+use crate::domain::ports::{BoardRepository, CardRepository, EventRepository, CreateEventInput, event_actions};
+use crate::domain::value_objects::{CardStatus, FizzyId};
+use crate::application::errors::ApplicationError;
+
+pub struct PostponeCardUseCase {
+    card_repository: Arc<dyn CardRepository>,
+    board_repository: Arc<dyn BoardRepository>,
+    event_repository: Arc<dyn EventRepository>,
+}
+
+pub struct PostponeCardInput {
+    pub account_id: FizzyId,
+    pub user_id: FizzyId,
+    pub card_number: i64,
+    /// True to move the card to "Not now", false to resume it
+    pub postpone: bool,
+}
+
+impl PostponeCardUseCase {
+    pub fn new(
+        card_repository: Arc<dyn CardRepository>,
+        board_repository: Arc<dyn BoardRepository>,
+        event_repository: Arc<dyn EventRepository>,
+    ) -> Self {
+        Self { card_repository, board_repository, event_repository }
+    }
+
+    pub async fn execute(&self, input: PostponeCardInput) -> Result<Card, ApplicationError> {
+        let card = self.card_repository
+            .find_by_number(&input.account_id, input.card_number)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Card #{} not found", input.card_number)))?;
+
+        let has_access = self.board_repository
+            .user_has_access(&input.account_id, &card.board_id, &input.user_id)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        if !has_access {
+            return Err(ApplicationError::Unauthorized("No access to this board".to_string()));
+        }
+
+        let postponed = card.status == CardStatus::NotNow;
+
+        if input.postpone {
+            if postponed {
+                return Err(ApplicationError::InvalidInput(format!(
+                    "Card #{} is already postponed",
+                    card.number
+                )));
+            }
+
+            self.card_repository
+                .postpone(&input.account_id, &card.id, &input.user_id)
+                .await
+                .map_err(ApplicationError::DomainError)?;
+
+            // Create event
+            let _ = self.event_repository
+                .create_event(&input.account_id, CreateEventInput {
+                    board_id: card.board_id.clone(),
+                    eventable_id: card.id.clone(),
+                    eventable_type: "Card".to_string(),
+                    creator_id: input.user_id,
+                    action: event_actions::CARD_POSTPONED.to_string(),
+                    particulars: serde_json::json!({}),
+                })
+                .await;
+        } else {
+            if !postponed {
+                return Err(ApplicationError::InvalidInput(format!(
+                    "Card #{} is not postponed",
+                    card.number
+                )));
+            }
+
+            self.card_repository
+                .resume(&input.account_id, &card.id)
+                .await
+                .map_err(ApplicationError::DomainError)?;
+
+            // Create event
+            let _ = self.event_repository
+                .create_event(&input.account_id, CreateEventInput {
+                    board_id: card.board_id.clone(),
+                    eventable_id: card.id.clone(),
+                    eventable_type: "Card".to_string(),
+                    creator_id: input.user_id,
+                    action: event_actions::CARD_RESUMED.to_string(),
+                    particulars: serde_json::json!({}),
+                })
+                .await;
+        }
+
+        self.card_repository
+            .find_by_id(&input.account_id, &card.id)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Card #{} not found", input.card_number)))
+    }
+}
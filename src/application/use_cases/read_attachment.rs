@@ -0,0 +1,67 @@
+use std::sync::Arc;
+use crate::domain::entities::Attachment;
+use crate::domain::ports::{AttachmentRepository, BoardRepository, CardRepository};
+use crate::domain::value_objects::FizzyId;
+use crate::application::errors::ApplicationError;
+
+pub struct ReadAttachmentUseCase {
+    card_repository: Arc<dyn CardRepository>,
+    board_repository: Arc<dyn BoardRepository>,
+    attachment_repository: Arc<dyn AttachmentRepository>,
+}
+
+pub struct ReadAttachmentInput {
+    pub account_id: FizzyId,
+    pub user_id: FizzyId,
+    pub card_number: i64,
+    pub attachment_id: FizzyId,
+}
+
+pub struct ReadAttachmentOutput {
+    pub attachment: Attachment,
+    pub data: Vec<u8>,
+}
+
+impl ReadAttachmentUseCase {
+    pub fn new(
+        card_repository: Arc<dyn CardRepository>,
+        board_repository: Arc<dyn BoardRepository>,
+        attachment_repository: Arc<dyn AttachmentRepository>,
+    ) -> Self {
+        Self { card_repository, board_repository, attachment_repository }
+    }
+
+    /// Read back a file on a card the user can see. The attachment must
+    /// belong to that card, so an ID from elsewhere can't be slipped in.
+    pub async fn execute(&self, input: ReadAttachmentInput) -> Result<ReadAttachmentOutput, ApplicationError> {
+        let card = self.card_repository
+            .find_by_number(&input.account_id, input.card_number)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Card #{} not found", input.card_number)))?;
+
+        let has_access = self.board_repository
+            .user_has_access(&input.account_id, &card.board_id, &input.user_id)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        if !has_access {
+            return Err(ApplicationError::Unauthorized("No access to this board".to_string()));
+        }
+
+        let attachment = self.attachment_repository
+            .list_for_card(&input.account_id, &card.id)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .into_iter()
+            .find(|attachment| attachment.id == input.attachment_id)
+            .ok_or_else(|| ApplicationError::NotFound(format!("That file is not on card #{}", card.number)))?;
+
+        let data = self.attachment_repository
+            .read(&attachment)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        Ok(ReadAttachmentOutput { attachment, data })
+    }
+}
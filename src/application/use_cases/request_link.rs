@@ -0,0 +1,103 @@
+use std::sync::Arc;
+use chrono::{Duration, Utc};
+use crate::domain::entities::{LinkRequest, User};
+use crate::domain::ports::{LinkRequestRepository, UserRepository};
+use crate::domain::value_objects::FizzyId;
+use crate::application::errors::ApplicationError;
+
+/// How long a link code stays valid
+const LINK_CODE_TTL_MINUTES: i64 = 15;
+/// Length of a link code
+const LINK_CODE_LENGTH: usize = 6;
+/// Code alphabet without easily confused characters (0/O, 1/I)
+const LINK_CODE_ALPHABET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+
+pub struct RequestLinkUseCase {
+    user_repository: Arc<dyn UserRepository>,
+    link_request_repository: Arc<dyn LinkRequestRepository>,
+}
+
+pub struct RequestLinkInput {
+    pub account_id: FizzyId,
+    pub telegram_user_id: i64,
+    pub telegram_name: Option<String>,
+    pub chat_id: i64,
+    pub email: String,
+}
+
+pub struct RequestLinkOutput {
+    pub request: LinkRequest,
+    pub user: User,
+    /// Account admins who can approve the request
+    pub admins: Vec<User>,
+}
+
+impl RequestLinkUseCase {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        link_request_repository: Arc<dyn LinkRequestRepository>,
+    ) -> Self {
+        Self { user_repository, link_request_repository }
+    }
+
+    pub async fn execute(&self, input: RequestLinkInput) -> Result<RequestLinkOutput, ApplicationError> {
+        let email = input.email.trim();
+        if email.is_empty() || !email.contains('@') {
+            return Err(ApplicationError::InvalidInput("Please provide your Fizzy email address".to_string()));
+        }
+
+        let user = self.user_repository
+            .find_by_email(&input.account_id, email)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .ok_or_else(|| ApplicationError::NotFound(format!("No active Fizzy user with email {}", email)))?;
+
+        let now = Utc::now();
+        let request = LinkRequest {
+            code: generate_code(),
+            telegram_user_id: input.telegram_user_id,
+            telegram_name: input.telegram_name,
+            chat_id: input.chat_id,
+            user_id: user.id.clone(),
+            created_at: now,
+            expires_at: now + Duration::minutes(LINK_CODE_TTL_MINUTES),
+        };
+
+        self.link_request_repository
+            .create(&request)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        let admins = self.user_repository
+            .list_active(&input.account_id)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .into_iter()
+            .filter(User::is_admin)
+            .collect();
+
+        Ok(RequestLinkOutput { request, user, admins })
+    }
+}
+
+/// Generate a short, human-friendly one-time code
+fn generate_code() -> String {
+    uuid::Uuid::new_v4()
+        .as_bytes()
+        .iter()
+        .take(LINK_CODE_LENGTH)
+        .map(|byte| LINK_CODE_ALPHABET[*byte as usize % LINK_CODE_ALPHABET.len()] as char)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_code_uses_unambiguous_alphabet() {
+        let code = generate_code();
+        assert_eq!(code.len(), LINK_CODE_LENGTH);
+        assert!(code.bytes().all(|c| LINK_CODE_ALPHABET.contains(&c)));
+    }
+}
@@ -1,2 +1,246 @@
-// Search functionality is deferred to a later phase
-// Placeholder for future implementation
+use std::collections::HashMap;
+use std::sync::Arc;
+use crate::domain::entities::Card;
+use crate::domain::ports::{BoardRepository, CardRepository, SearchRecord, SearchRepository};
+use crate::domain::value_objects::FizzyId;
+use crate::application::errors::ApplicationError;
+
+/// Maximum number of cards considered for ranking
+const MAX_CANDIDATES: i64 = 200;
+/// Maximum number of search terms taken from the query
+const MAX_TERMS: usize = 8;
+/// Characters of context shown around a match in snippets
+const SNIPPET_CONTEXT: usize = 40;
+
+pub struct SearchCardsUseCase {
+    search_repository: Arc<dyn SearchRepository>,
+    card_repository: Arc<dyn CardRepository>,
+    board_repository: Arc<dyn BoardRepository>,
+}
+
+pub struct SearchCardsInput {
+    pub account_id: FizzyId,
+    pub user_id: FizzyId,
+    pub query: String,
+    pub page: usize,
+    pub per_page: usize,
+}
+
+pub struct SearchResult {
+    pub card: Card,
+    /// Matching excerpt from the description or a comment, if any
+    pub snippet: Option<String>,
+}
+
+pub struct SearchCardsOutput {
+    pub results: Vec<SearchResult>,
+    pub total: usize,
+    pub page: usize,
+    pub has_more: bool,
+}
+
+/// Ranked card candidate built from one or more search records
+struct RankedCard {
+    card_id: FizzyId,
+    /// Terms found in any of the card's titles / contents
+    title_terms: Vec<bool>,
+    content_terms: Vec<bool>,
+    records: u32,
+    latest: chrono::DateTime<chrono::Utc>,
+    snippet: Option<String>,
+}
+
+impl SearchCardsUseCase {
+    pub fn new(
+        search_repository: Arc<dyn SearchRepository>,
+        card_repository: Arc<dyn CardRepository>,
+        board_repository: Arc<dyn BoardRepository>,
+    ) -> Self {
+        Self { search_repository, card_repository, board_repository }
+    }
+
+    pub async fn execute(&self, input: SearchCardsInput) -> Result<SearchCardsOutput, ApplicationError> {
+        let terms = parse_terms(&input.query);
+        if terms.is_empty() {
+            return Err(ApplicationError::InvalidInput("Search terms cannot be empty".to_string()));
+        }
+
+        // Only search boards the user can see
+        let board_ids: Vec<FizzyId> = self.board_repository
+            .list_accessible(&input.account_id, &input.user_id)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .into_iter()
+            .map(|board| board.id)
+            .collect();
+
+        let records = self.search_repository
+            .search(&input.account_id, &terms, &board_ids, MAX_CANDIDATES)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        let ranked = rank(records, &terms);
+        let total = ranked.len();
+        let per_page = input.per_page.max(1);
+        let start = input.page.saturating_mul(per_page);
+
+        let mut results = Vec::new();
+        for candidate in ranked.into_iter().skip(start).take(per_page) {
+            let card = self.card_repository
+                .find_by_id(&input.account_id, &candidate.card_id)
+                .await
+                .map_err(ApplicationError::DomainError)?;
+
+            // The index can briefly lag behind deleted cards
+            if let Some(card) = card {
+                results.push(SearchResult { card, snippet: candidate.snippet });
+            }
+        }
+
+        Ok(SearchCardsOutput {
+            results,
+            total,
+            page: input.page,
+            has_more: start + per_page < total,
+        })
+    }
+}
+
+/// Split a query into lowercase, de-duplicated terms
+fn parse_terms(query: &str) -> Vec<String> {
+    let mut terms: Vec<String> = Vec::new();
+    for term in query.split_whitespace().map(str::to_lowercase) {
+        if !terms.contains(&term) {
+            terms.push(term);
+        }
+    }
+    terms.truncate(MAX_TERMS);
+    terms
+}
+
+/// Group records by card and order cards by relevance, the way the search
+/// repository picks its candidates: a term found in any of the card's titles
+/// counts 3, in any content 1, and each extra matching record (e.g. another
+/// comment) adds 1; ties go to the most recent.
+fn rank(records: Vec<SearchRecord>, terms: &[String]) -> Vec<RankedCard> {
+    let mut by_card: HashMap<FizzyId, RankedCard> = HashMap::new();
+
+    for record in records {
+        let title = record.title.as_deref().unwrap_or_default().to_lowercase();
+        let content = record.content.as_deref().unwrap_or_default();
+        let content_lower = content.to_lowercase();
+
+        let snippet = if title.contains(terms[0].as_str()) {
+            None
+        } else {
+            snippet(content, &terms[0])
+        };
+
+        let ranked = by_card.entry(record.card_id.clone()).or_insert_with(|| RankedCard {
+            card_id: record.card_id,
+            title_terms: vec![false; terms.len()],
+            content_terms: vec![false; terms.len()],
+            records: 0,
+            latest: record.created_at,
+            snippet: None,
+        });
+
+        for (i, term) in terms.iter().enumerate() {
+            ranked.title_terms[i] |= title.contains(term.as_str());
+            ranked.content_terms[i] |= content_lower.contains(term.as_str());
+        }
+        ranked.records += 1;
+        ranked.latest = ranked.latest.max(record.created_at);
+        if ranked.snippet.is_none() {
+            ranked.snippet = snippet;
+        }
+    }
+
+    let mut ranked: Vec<RankedCard> = by_card.into_values().collect();
+    ranked.sort_by(|a, b| b.score().cmp(&a.score()).then(b.latest.cmp(&a.latest)));
+    ranked
+}
+
+impl RankedCard {
+    fn score(&self) -> u32 {
+        let hits = |found: &[bool]| found.iter().filter(|&&hit| hit).count() as u32;
+        3 * hits(&self.title_terms) + hits(&self.content_terms) + self.records - 1
+    }
+}
+
+/// Extract a short excerpt of `content` around the first occurrence of `term`
+fn snippet(content: &str, term: &str) -> Option<String> {
+    let lower = content.to_lowercase();
+    let byte_pos = lower.find(term)?;
+
+    // Work in chars so multi-byte text is never split mid-character
+    let match_start = lower[..byte_pos].chars().count();
+    let chars: Vec<char> = content.chars().collect();
+    let start = match_start.saturating_sub(SNIPPET_CONTEXT);
+    let end = (match_start + term.chars().count() + SNIPPET_CONTEXT).min(chars.len());
+
+    let mut excerpt: String = chars[start..end].iter().collect();
+    excerpt = excerpt.split_whitespace().collect::<Vec<_>>().join(" ");
+    if start > 0 {
+        excerpt.insert(0, '…');
+    }
+    if end < chars.len() {
+        excerpt.push('…');
+    }
+    Some(excerpt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    fn record(card: &str, kind: &str, title: &str, content: &str, minute: u32) -> SearchRecord {
+        SearchRecord {
+            card_id: FizzyId::from(card),
+            searchable_type: kind.to_string(),
+            title: Some(title.to_string()),
+            content: Some(content.to_string()),
+            created_at: Utc.with_ymd_and_hms(2026, 1, 1, 12, minute, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_title_matches_rank_above_content_matches() {
+        let terms = parse_terms("Login");
+        let ranked = rank(
+            vec![
+                record("a", "Comment", "", "the login page is slow", 30),
+                record("b", "Card", "Login bug", "", 10),
+            ],
+            &terms,
+        );
+
+        assert_eq!(ranked[0].card_id.as_str(), "b");
+        assert_eq!(ranked[1].card_id.as_str(), "a");
+        assert_eq!(ranked[1].snippet.as_deref(), Some("the login page is slow"));
+    }
+
+    #[test]
+    fn test_terms_may_match_across_records() {
+        let terms = parse_terms("login slow");
+        let ranked = rank(
+            vec![
+                record("a", "Card", "Login bug", "", 10),
+                record("a", "Comment", "", "still slow today", 20),
+                record("b", "Card", "Slow login", "", 5),
+            ],
+            &terms,
+        );
+
+        assert_eq!(ranked[0].card_id.as_str(), "b");
+        assert_eq!(ranked[0].score(), 6);
+        assert_eq!(ranked[1].card_id.as_str(), "a");
+        assert_eq!(ranked[1].score(), 5);
+    }
+
+    #[test]
+    fn test_parse_terms_dedupes_and_lowercases() {
+        assert_eq!(parse_terms("Bug  bug LOGIN"), vec!["bug", "login"]);
+    }
+}
@@ -0,0 +1,80 @@
+use std::sync::Arc;
+use chrono::NaiveDate;
+use crate::domain::entities::Card;
+use crate::domain::ports::{
+    BoardRepository, CardRepository, CreateEventInput, EventRepository, UpdateCardInput, event_actions,
+};
+use crate::domain::value_objects::{DueDate, FizzyId};
+use crate::application::errors::ApplicationError;
+
+pub struct SetDueDateUseCase {
+    card_repository: Arc<dyn CardRepository>,
+    board_repository: Arc<dyn BoardRepository>,
+    event_repository: Arc<dyn EventRepository>,
+}
+
+pub struct SetDueDateInput {
+    pub account_id: FizzyId,
+    pub user_id: FizzyId,
+    pub card_number: i64,
+    /// What the user typed, e.g. "fri" or "in 3 days"
+    pub when: String,
+    /// Today's date in the user's timezone
+    pub today: NaiveDate,
+}
+
+impl SetDueDateUseCase {
+    pub fn new(
+        card_repository: Arc<dyn CardRepository>,
+        board_repository: Arc<dyn BoardRepository>,
+        event_repository: Arc<dyn EventRepository>,
+    ) -> Self {
+        Self { card_repository, board_repository, event_repository }
+    }
+
+    pub async fn execute(&self, input: SetDueDateInput) -> Result<Card, ApplicationError> {
+        let due = DueDate::parse(&input.when, input.today)
+            .map_err(|e| ApplicationError::InvalidInput(e.to_string()))?;
+
+        let card = self.card_repository
+            .find_by_number(&input.account_id, input.card_number)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Card #{} not found", input.card_number)))?;
+
+        let has_access = self.board_repository
+            .user_has_access(&input.account_id, &card.board_id, &input.user_id)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        if !has_access {
+            return Err(ApplicationError::Unauthorized("No access to this board".to_string()));
+        }
+
+        let update_input = UpdateCardInput {
+            due_on: Some(due.date()),
+            ..Default::default()
+        };
+
+        let updated = self.card_repository
+            .update(&input.account_id, &card.id, update_input)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        // Create event
+        let _ = self.event_repository
+            .create_event(&input.account_id, CreateEventInput {
+                board_id: card.board_id.clone(),
+                eventable_id: card.id.clone(),
+                eventable_type: "Card".to_string(),
+                creator_id: input.user_id,
+                action: event_actions::CARD_DUE_DATE_CHANGED.to_string(),
+                particulars: serde_json::json!({
+                    "due_on": due.date().map(|d| d.to_string())
+                }),
+            })
+            .await;
+
+        Ok(updated)
+    }
+}
@@ -0,0 +1,90 @@
+use std::sync::Arc;
+use crate::domain::entities::{Board, Card, SubscriptionTarget};
+use crate::domain::ports::{BoardRepository, CardRepository, SubscriptionRepository};
+use crate::domain::value_objects::FizzyId;
+use crate::application::errors::ApplicationError;
+
+/// What to (un)subscribe from, as typed by the user
+pub enum SubscriptionRef {
+    Board(String),
+    Card(i64),
+}
+
+/// A resolved subscription target, for display
+pub enum SubscribedTarget {
+    Board(Board),
+    Card(Box<Card>),
+}
+
+pub struct SubscribeUseCase {
+    subscription_repository: Arc<dyn SubscriptionRepository>,
+    board_repository: Arc<dyn BoardRepository>,
+    card_repository: Arc<dyn CardRepository>,
+}
+
+pub struct SubscribeInput {
+    pub account_id: FizzyId,
+    pub user_id: FizzyId,
+    pub chat_id: i64,
+    pub target: SubscriptionRef,
+    /// True to subscribe, false to unsubscribe
+    pub subscribe: bool,
+}
+
+pub struct SubscribeOutput {
+    pub target: SubscribedTarget,
+    /// False if the chat already was in the requested state
+    pub changed: bool,
+}
+
+impl SubscribeUseCase {
+    pub fn new(
+        subscription_repository: Arc<dyn SubscriptionRepository>,
+        board_repository: Arc<dyn BoardRepository>,
+        card_repository: Arc<dyn CardRepository>,
+    ) -> Self {
+        Self { subscription_repository, board_repository, card_repository }
+    }
+
+    pub async fn execute(&self, input: SubscribeInput) -> Result<SubscribeOutput, ApplicationError> {
+        let (target, board_id, subscribed) = match input.target {
+            SubscriptionRef::Board(name) => {
+                let board = self.board_repository
+                    .find_by_name(&input.account_id, &name)
+                    .await
+                    .map_err(ApplicationError::DomainError)?
+                    .ok_or_else(|| ApplicationError::NotFound(format!("Board '{}' not found", name)))?;
+                (SubscriptionTarget::Board(board.id.clone()), board.id.clone(), SubscribedTarget::Board(board))
+            }
+            SubscriptionRef::Card(number) => {
+                let card = self.card_repository
+                    .find_by_number(&input.account_id, number)
+                    .await
+                    .map_err(ApplicationError::DomainError)?
+                    .ok_or_else(|| ApplicationError::NotFound(format!("Card #{} not found", number)))?;
+                (SubscriptionTarget::Card(card.id.clone()), card.board_id.clone(), SubscribedTarget::Card(Box::new(card)))
+            }
+        };
+
+        // Unsubscribing is always allowed, so a chat can get rid of a board it lost access to
+        if input.subscribe {
+            let has_access = self.board_repository
+                .user_has_access(&input.account_id, &board_id, &input.user_id)
+                .await
+                .map_err(ApplicationError::DomainError)?;
+
+            if !has_access {
+                return Err(ApplicationError::Unauthorized("No access to this board".to_string()));
+            }
+        }
+
+        let changed = if input.subscribe {
+            self.subscription_repository.subscribe(input.chat_id, &target).await
+        } else {
+            self.subscription_repository.unsubscribe(input.chat_id, &target).await
+        }
+        .map_err(ApplicationError::DomainError)?;
+
+        Ok(SubscribeOutput { target: subscribed, changed })
+    }
+}
@@ -0,0 +1,112 @@
+use std::sync::Arc;
+use crate::domain::entities::{Card, Tag};
+use crate::domain::ports::{
+    BoardRepository, CardRepository, CreateEventInput, EventRepository, TagRepository, event_actions,
+};
+use crate::domain::value_objects::FizzyId;
+use crate::application::errors::ApplicationError;
+
+pub struct TagCardUseCase {
+    card_repository: Arc<dyn CardRepository>,
+    board_repository: Arc<dyn BoardRepository>,
+    tag_repository: Arc<dyn TagRepository>,
+    event_repository: Arc<dyn EventRepository>,
+}
+
+pub struct TagCardInput {
+    pub account_id: FizzyId,
+    pub user_id: FizzyId,
+    pub card_number: i64,
+    pub titles: Vec<String>,
+}
+
+pub struct TagCardOutput {
+    pub card: Card,
+    /// Titles newly attached (already present ones are skipped)
+    pub added: Vec<String>,
+}
+
+impl TagCardUseCase {
+    pub fn new(
+        card_repository: Arc<dyn CardRepository>,
+        board_repository: Arc<dyn BoardRepository>,
+        tag_repository: Arc<dyn TagRepository>,
+        event_repository: Arc<dyn EventRepository>,
+    ) -> Self {
+        Self { card_repository, board_repository, tag_repository, event_repository }
+    }
+
+    pub async fn execute(&self, input: TagCardInput) -> Result<TagCardOutput, ApplicationError> {
+        let titles = normalize_titles(&input.titles);
+        if titles.is_empty() {
+            return Err(ApplicationError::InvalidInput("At least one tag is required".to_string()));
+        }
+
+        let card = self.card_repository
+            .find_by_number(&input.account_id, input.card_number)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Card #{} not found", input.card_number)))?;
+
+        let has_access = self.board_repository
+            .user_has_access(&input.account_id, &card.board_id, &input.user_id)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        if !has_access {
+            return Err(ApplicationError::Unauthorized("No access to this board".to_string()));
+        }
+
+        let mut added = Vec::new();
+        for title in titles {
+            // Tags are account-wide; unknown ones are created on the fly
+            let tag = self.tag_repository
+                .find_or_create(&input.account_id, &title)
+                .await
+                .map_err(ApplicationError::DomainError)?;
+
+            let attached = self.tag_repository
+                .add_to_card(&input.account_id, &card.id, &tag.id)
+                .await
+                .map_err(ApplicationError::DomainError)?;
+
+            if attached {
+                added.push(tag.title);
+            }
+        }
+
+        if !added.is_empty() {
+            let _ = self.event_repository
+                .create_event(&input.account_id, CreateEventInput {
+                    board_id: card.board_id.clone(),
+                    eventable_id: card.id.clone(),
+                    eventable_type: "Card".to_string(),
+                    creator_id: input.user_id,
+                    action: event_actions::CARD_TAGGED.to_string(),
+                    particulars: serde_json::json!({
+                        "tag_titles": added
+                    }),
+                })
+                .await;
+        }
+
+        let card = self.card_repository
+            .find_by_id(&input.account_id, &card.id)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .unwrap_or(card);
+
+        Ok(TagCardOutput { card, added })
+    }
+}
+
+/// Normalize and de-duplicate tag titles typed by the user
+pub(crate) fn normalize_titles(titles: &[String]) -> Vec<String> {
+    let mut normalized: Vec<String> = Vec::new();
+    for title in titles.iter().filter_map(|t| Tag::normalize_title(t)) {
+        if !normalized.contains(&title) {
+            normalized.push(title);
+        }
+    }
+    normalized
+}
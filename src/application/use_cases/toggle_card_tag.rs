@@ -0,0 +1,91 @@
+use std::sync::Arc;
+use crate::domain::entities::Tag;
+use crate::domain::ports::{
+    BoardRepository, CardRepository, CreateEventInput, EventRepository, TagRepository, event_actions,
+};
+use crate::domain::value_objects::FizzyId;
+use crate::application::errors::ApplicationError;
+
+pub struct ToggleCardTagUseCase {
+    card_repository: Arc<dyn CardRepository>,
+    board_repository: Arc<dyn BoardRepository>,
+    tag_repository: Arc<dyn TagRepository>,
+    event_repository: Arc<dyn EventRepository>,
+}
+
+pub struct ToggleCardTagInput {
+    pub account_id: FizzyId,
+    pub user_id: FizzyId,
+    pub card_number: i64,
+    pub tag_id: FizzyId,
+}
+
+pub struct ToggleCardTagOutput {
+    pub tag: Tag,
+    /// True if the tag is now on the card, false if it was removed
+    pub attached: bool,
+}
+
+impl ToggleCardTagUseCase {
+    pub fn new(
+        card_repository: Arc<dyn CardRepository>,
+        board_repository: Arc<dyn BoardRepository>,
+        tag_repository: Arc<dyn TagRepository>,
+        event_repository: Arc<dyn EventRepository>,
+    ) -> Self {
+        Self { card_repository, board_repository, tag_repository, event_repository }
+    }
+
+    pub async fn execute(&self, input: ToggleCardTagInput) -> Result<ToggleCardTagOutput, ApplicationError> {
+        let card = self.card_repository
+            .find_by_number(&input.account_id, input.card_number)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Card #{} not found", input.card_number)))?;
+
+        let has_access = self.board_repository
+            .user_has_access(&input.account_id, &card.board_id, &input.user_id)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        if !has_access {
+            return Err(ApplicationError::Unauthorized("No access to this board".to_string()));
+        }
+
+        let tag = self.tag_repository
+            .list(&input.account_id)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .into_iter()
+            .find(|t| t.id == input.tag_id)
+            .ok_or_else(|| ApplicationError::NotFound("Tag not found".to_string()))?;
+
+        let removed = self.tag_repository
+            .remove_from_card(&input.account_id, &card.id, &tag.id)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        if !removed {
+            self.tag_repository
+                .add_to_card(&input.account_id, &card.id, &tag.id)
+                .await
+                .map_err(ApplicationError::DomainError)?;
+        }
+
+        let action = if removed { event_actions::CARD_UNTAGGED } else { event_actions::CARD_TAGGED };
+        let _ = self.event_repository
+            .create_event(&input.account_id, CreateEventInput {
+                board_id: card.board_id.clone(),
+                eventable_id: card.id.clone(),
+                eventable_type: "Card".to_string(),
+                creator_id: input.user_id,
+                action: action.to_string(),
+                particulars: serde_json::json!({
+                    "tag_titles": [&tag.title]
+                }),
+            })
+            .await;
+
+        Ok(ToggleCardTagOutput { tag, attached: !removed })
+    }
+}
@@ -0,0 +1,138 @@
+use std::sync::Arc;
+use crate::domain::entities::{Card, User};
+use crate::domain::ports::{
+    AssignmentRepository, BoardRepository, CardRepository, CreateEventInput, EventRepository, UserRepository,
+    event_actions,
+};
+use crate::domain::value_objects::FizzyId;
+use crate::application::errors::ApplicationError;
+use super::assign_card::{match_users, AssigneeRef};
+
+pub struct UnassignCardUseCase {
+    card_repository: Arc<dyn CardRepository>,
+    board_repository: Arc<dyn BoardRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    assignment_repository: Arc<dyn AssignmentRepository>,
+    event_repository: Arc<dyn EventRepository>,
+}
+
+pub struct UnassignCardInput {
+    pub account_id: FizzyId,
+    pub user_id: FizzyId,
+    pub card_number: i64,
+    /// Who to remove; `None` removes the only assignee
+    pub assignee: Option<AssigneeRef>,
+}
+
+pub enum UnassignCardOutput {
+    Unassigned { card: Card, assignee: User },
+    /// Several assignees fit; let the user pick one
+    Ambiguous { card: Card, candidates: Vec<User> },
+}
+
+impl UnassignCardUseCase {
+    pub fn new(
+        card_repository: Arc<dyn CardRepository>,
+        board_repository: Arc<dyn BoardRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        assignment_repository: Arc<dyn AssignmentRepository>,
+        event_repository: Arc<dyn EventRepository>,
+    ) -> Self {
+        Self { card_repository, board_repository, user_repository, assignment_repository, event_repository }
+    }
+
+    pub async fn execute(&self, input: UnassignCardInput) -> Result<UnassignCardOutput, ApplicationError> {
+        let card = self.card_repository
+            .find_by_number(&input.account_id, input.card_number)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Card #{} not found", input.card_number)))?;
+
+        let has_access = self.board_repository
+            .user_has_access(&input.account_id, &card.board_id, &input.user_id)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        if !has_access {
+            return Err(ApplicationError::Unauthorized("No access to this board".to_string()));
+        }
+
+        let assignee_ids = self.assignment_repository
+            .list_assignee_ids(&input.account_id, &card.id)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        if assignee_ids.is_empty() {
+            return Err(ApplicationError::InvalidInput(format!("Card #{} has no assignees", card.number)));
+        }
+
+        let mut assignees: Vec<User> = self.user_repository
+            .list_active(&input.account_id)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .into_iter()
+            .filter(|u| assignee_ids.contains(&u.id))
+            .collect();
+
+        let assignee = match input.assignee {
+            Some(AssigneeRef::Id(id)) => assignees
+                .into_iter()
+                .find(|u| u.id == id)
+                .ok_or_else(|| ApplicationError::InvalidInput("That user is not assigned to this card".to_string()))?,
+            Some(AssigneeRef::Name(name)) if name.trim().eq_ignore_ascii_case("me") => assignees
+                .into_iter()
+                .find(|u| u.id == input.user_id)
+                .ok_or_else(|| ApplicationError::InvalidInput("You are not assigned to this card".to_string()))?,
+            Some(AssigneeRef::Name(name)) => {
+                let matches = match_users(&name, &assignees);
+                match matches.as_slice() {
+                    [single] => (*single).clone(),
+                    [] => {
+                        return Err(ApplicationError::NotFound(format!(
+                            "No assignee of card #{} matches \"{}\"",
+                            card.number,
+                            name.trim()
+                        )));
+                    }
+                    _ => {
+                        let candidates = matches.into_iter().cloned().collect();
+                        return Ok(UnassignCardOutput::Ambiguous { card, candidates });
+                    }
+                }
+            }
+            None if assignees.len() == 1 => assignees.remove(0),
+            None => return Ok(UnassignCardOutput::Ambiguous { card, candidates: assignees }),
+        };
+
+        let removed = self.assignment_repository
+            .unassign(&input.account_id, &card.id, &assignee.id)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        if !removed {
+            return Err(ApplicationError::InvalidInput(format!("{} is not assigned to this card", assignee.name)));
+        }
+
+        // Create event
+        let _ = self.event_repository
+            .create_event(&input.account_id, CreateEventInput {
+                board_id: card.board_id.clone(),
+                eventable_id: card.id.clone(),
+                eventable_type: "Card".to_string(),
+                creator_id: input.user_id,
+                action: event_actions::CARD_UNASSIGNED.to_string(),
+                particulars: serde_json::json!({
+                    "assignee_ids": [assignee.id.as_str()]
+                }),
+            })
+            .await;
+
+        let card = self.card_repository
+            .find_by_id(&input.account_id, &card.id)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .unwrap_or(card);
+
+        Ok(UnassignCardOutput::Unassigned { card, assignee })
+    }
+}
@@ -0,0 +1,105 @@
+use std::sync::Arc;
+use crate::domain::entities::Card;
+use crate::domain::ports::{
+    BoardRepository, CardRepository, CreateEventInput, EventRepository, TagRepository, event_actions,
+};
+use crate::domain::value_objects::FizzyId;
+use crate::application::errors::ApplicationError;
+use super::tag_card::normalize_titles;
+
+pub struct UntagCardUseCase {
+    card_repository: Arc<dyn CardRepository>,
+    board_repository: Arc<dyn BoardRepository>,
+    tag_repository: Arc<dyn TagRepository>,
+    event_repository: Arc<dyn EventRepository>,
+}
+
+pub struct UntagCardInput {
+    pub account_id: FizzyId,
+    pub user_id: FizzyId,
+    pub card_number: i64,
+    pub titles: Vec<String>,
+}
+
+pub struct UntagCardOutput {
+    pub card: Card,
+    pub removed: Vec<String>,
+    /// Requested titles that weren't on the card
+    pub missing: Vec<String>,
+}
+
+impl UntagCardUseCase {
+    pub fn new(
+        card_repository: Arc<dyn CardRepository>,
+        board_repository: Arc<dyn BoardRepository>,
+        tag_repository: Arc<dyn TagRepository>,
+        event_repository: Arc<dyn EventRepository>,
+    ) -> Self {
+        Self { card_repository, board_repository, tag_repository, event_repository }
+    }
+
+    pub async fn execute(&self, input: UntagCardInput) -> Result<UntagCardOutput, ApplicationError> {
+        let titles = normalize_titles(&input.titles);
+        if titles.is_empty() {
+            return Err(ApplicationError::InvalidInput("At least one tag is required".to_string()));
+        }
+
+        let card = self.card_repository
+            .find_by_number(&input.account_id, input.card_number)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Card #{} not found", input.card_number)))?;
+
+        let has_access = self.board_repository
+            .user_has_access(&input.account_id, &card.board_id, &input.user_id)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        if !has_access {
+            return Err(ApplicationError::Unauthorized("No access to this board".to_string()));
+        }
+
+        let card_tags = self.tag_repository
+            .list_for_card(&input.account_id, &card.id)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        let mut removed = Vec::new();
+        let mut missing = Vec::new();
+        for title in titles {
+            match card_tags.iter().find(|t| t.title.eq_ignore_ascii_case(&title)) {
+                Some(tag) => {
+                    self.tag_repository
+                        .remove_from_card(&input.account_id, &card.id, &tag.id)
+                        .await
+                        .map_err(ApplicationError::DomainError)?;
+                    removed.push(tag.title.clone());
+                }
+                None => missing.push(title),
+            }
+        }
+
+        if !removed.is_empty() {
+            let _ = self.event_repository
+                .create_event(&input.account_id, CreateEventInput {
+                    board_id: card.board_id.clone(),
+                    eventable_id: card.id.clone(),
+                    eventable_type: "Card".to_string(),
+                    creator_id: input.user_id,
+                    action: event_actions::CARD_UNTAGGED.to_string(),
+                    particulars: serde_json::json!({
+                        "tag_titles": removed
+                    }),
+                })
+                .await;
+        }
+
+        let card = self.card_repository
+            .find_by_id(&input.account_id, &card.id)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .unwrap_or(card);
+
+        Ok(UntagCardOutput { card, removed, missing })
+    }
+}
@@ -16,6 +16,7 @@ pub struct UpdateCardUseCaseInput {
     pub user_id: FizzyId,
     pub card_number: i64,
     pub title: Option<String>,
+    /// Description as ActionText HTML
     pub description: Option<String>,
 }
 
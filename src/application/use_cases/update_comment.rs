@@ -0,0 +1,73 @@
+use std::sync::Arc;
+use crate::domain::entities::Comment;
+use crate::domain::ports::{
+    BoardRepository, CardRepository, CommentRepository, CreateEventInput, EventRepository,
+    UserRepository, event_actions,
+};
+use crate::domain::value_objects::FizzyId;
+use crate::application::errors::ApplicationError;
+use super::comment_access::CommentAccess;
+
+pub struct UpdateCommentUseCase {
+    comment_repository: Arc<dyn CommentRepository>,
+    access: CommentAccess,
+    event_repository: Arc<dyn EventRepository>,
+}
+
+pub struct UpdateCommentInput {
+    pub account_id: FizzyId,
+    pub user_id: FizzyId,
+    pub comment_id: FizzyId,
+    /// New body as ActionText HTML
+    pub content: String,
+}
+
+impl UpdateCommentUseCase {
+    pub fn new(
+        comment_repository: Arc<dyn CommentRepository>,
+        card_repository: Arc<dyn CardRepository>,
+        board_repository: Arc<dyn BoardRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        event_repository: Arc<dyn EventRepository>,
+    ) -> Self {
+        let access = CommentAccess::new(
+            comment_repository.clone(),
+            card_repository,
+            board_repository,
+            user_repository,
+        );
+
+        Self { comment_repository, access, event_repository }
+    }
+
+    pub async fn execute(&self, input: UpdateCommentInput) -> Result<Comment, ApplicationError> {
+        if input.content.trim().is_empty() {
+            return Err(ApplicationError::InvalidInput("Comment cannot be empty".to_string()));
+        }
+
+        let (comment, card) = self.access
+            .find_changeable(&input.account_id, &input.user_id, &input.comment_id, "edit")
+            .await?;
+
+        self.comment_repository
+            .update(&input.account_id, &comment.id, &input.content)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        // Create event
+        let _ = self.event_repository
+            .create_event(&input.account_id, CreateEventInput {
+                board_id: card.board_id.clone(),
+                eventable_id: comment.id.clone(),
+                eventable_type: "Comment".to_string(),
+                creator_id: input.user_id,
+                action: event_actions::COMMENT_UPDATED.to_string(),
+                particulars: serde_json::json!({
+                    "card_id": card.id.as_str()
+                }),
+            })
+            .await;
+
+        Ok(Comment { content: input.content, ..comment })
+    }
+}
@@ -0,0 +1,76 @@
+use std::sync::Arc;
+use chrono::Utc;
+use crate::domain::entities::{LinkRequest, User};
+use crate::domain::ports::{CommentRepository, LinkRequestRepository, UserRepository};
+use crate::domain::value_objects::FizzyId;
+use crate::application::errors::ApplicationError;
+
+pub struct VerifyLinkUseCase {
+    user_repository: Arc<dyn UserRepository>,
+    comment_repository: Arc<dyn CommentRepository>,
+    link_request_repository: Arc<dyn LinkRequestRepository>,
+}
+
+pub struct VerifyLinkInput {
+    pub account_id: FizzyId,
+    pub telegram_user_id: i64,
+}
+
+pub struct VerifyLinkOutput {
+    pub request: LinkRequest,
+    pub user: User,
+}
+
+impl VerifyLinkUseCase {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        comment_repository: Arc<dyn CommentRepository>,
+        link_request_repository: Arc<dyn LinkRequestRepository>,
+    ) -> Self {
+        Self { user_repository, comment_repository, link_request_repository }
+    }
+
+    /// Confirm a link request out of band: the requested Fizzy user proves
+    /// control of the account by posting the code in a comment.
+    pub async fn execute(&self, input: VerifyLinkInput) -> Result<VerifyLinkOutput, ApplicationError> {
+        let request = self.link_request_repository
+            .find_by_telegram_user(input.telegram_user_id)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .ok_or_else(|| ApplicationError::NotFound("No pending link request".to_string()))?;
+
+        if request.is_expired(Utc::now()) {
+            self.link_request_repository
+                .delete(&request.code)
+                .await
+                .map_err(ApplicationError::DomainError)?;
+            return Err(ApplicationError::InvalidInput("Link request has expired".to_string()));
+        }
+
+        let comments = self.comment_repository
+            .list_by_creator_since(&input.account_id, &request.user_id, request.created_at)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        if !comments.iter().any(|c| c.content.to_uppercase().contains(&request.code)) {
+            return Err(ApplicationError::InvalidInput(format!(
+                "No comment containing {} found yet",
+                request.code
+            )));
+        }
+
+        self.link_request_repository
+            .delete(&request.code)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        let user = self.user_repository
+            .find_by_id(&input.account_id, &request.user_id)
+            .await
+            .map_err(ApplicationError::DomainError)?
+            .filter(User::is_active)
+            .ok_or_else(|| ApplicationError::NotFound("Requested user no longer exists".to_string()))?;
+
+        Ok(VerifyLinkOutput { request, user })
+    }
+}
@@ -0,0 +1,77 @@
+use crate::domain::value_objects::FizzyId;
+use chrono::{DateTime, Utc};
+
+/// File attached to a card's description or one of its comments
+/// (an ActiveStorage attachment and its blob)
+#[derive(Debug, Clone)]
+pub struct Attachment {
+    pub id: FizzyId,
+    pub account_id: FizzyId,
+    pub blob_id: FizzyId,
+    /// ActiveStorage blob key, which is also the file's name on disk
+    pub key: String,
+    pub filename: String,
+    pub content_type: Option<String>,
+    pub byte_size: i64,
+    pub created_at: DateTime<Utc>,
+    /// The comment the file sits on, if it isn't in the description
+    pub comment_id: Option<FizzyId>,
+}
+
+impl Attachment {
+    /// Whether Telegram can show the file as a photo
+    pub fn is_image(&self) -> bool {
+        matches!(
+            self.content_type.as_deref(),
+            Some("image/jpeg" | "image/png" | "image/gif" | "image/webp")
+        )
+    }
+
+    /// Human-readable file size ("512 B", "1.4 MB")
+    pub fn display_size(&self) -> String {
+        const KB: f64 = 1024.0;
+        const MB: f64 = KB * 1024.0;
+
+        let size = self.byte_size as f64;
+        if size >= MB {
+            format!("{:.1} MB", size / MB)
+        } else if size >= KB {
+            format!("{:.0} KB", size / KB)
+        } else {
+            format!("{} B", self.byte_size)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attachment(content_type: Option<&str>, byte_size: i64) -> Attachment {
+        Attachment {
+            id: FizzyId::generate(),
+            account_id: FizzyId::generate(),
+            blob_id: FizzyId::generate(),
+            key: "abc".to_string(),
+            filename: "file".to_string(),
+            content_type: content_type.map(str::to_string),
+            byte_size,
+            created_at: Utc::now(),
+            comment_id: None,
+        }
+    }
+
+    #[test]
+    fn test_display_size() {
+        assert_eq!(attachment(None, 512).display_size(), "512 B");
+        assert_eq!(attachment(None, 40_000).display_size(), "39 KB");
+        assert_eq!(attachment(None, 1_500_000).display_size(), "1.4 MB");
+    }
+
+    #[test]
+    fn test_is_image() {
+        assert!(attachment(Some("image/png"), 1).is_image());
+        assert!(!attachment(Some("application/pdf"), 1).is_image());
+        assert!(!attachment(None, 1).is_image());
+    }
+}
@@ -1,6 +1,17 @@
 use crate::domain::value_objects::{CardStatus, FizzyId};
 use chrono::{DateTime, NaiveDate, Utc};
 
+/// Cards due within this many days count as due soon
+pub const DUE_SOON_DAYS: i64 = 2;
+
+/// Where a card stands relative to its due date
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DueStatus {
+    Overdue,
+    DueSoon,
+    Upcoming,
+}
+
 /// Main work item (task/issue) in Fizzy
 #[derive(Debug, Clone)]
 pub struct Card {
@@ -39,6 +50,23 @@ impl Card {
         self.status.is_active()
     }
 
+    /// Get the due date status as of `today`; closed cards are never overdue
+    pub fn due_status(&self, today: NaiveDate) -> Option<DueStatus> {
+        let due = self.due_on?;
+        if self.status == CardStatus::Closed {
+            return None;
+        }
+
+        let days_left = (due - today).num_days();
+        Some(if days_left < 0 {
+            DueStatus::Overdue
+        } else if days_left <= DUE_SOON_DAYS {
+            DueStatus::DueSoon
+        } else {
+            DueStatus::Upcoming
+        })
+    }
+
     /// Generate a URL to view the card in Fizzy web UI
     pub fn web_url(&self, base_url: Option<&str>) -> Option<String> {
         base_url.map(|url| {
@@ -1,7 +1,7 @@
 use crate::domain::value_objects::FizzyId;
 
 /// Kanban column within a board
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Column {
     pub id: FizzyId,
     pub account_id: FizzyId,
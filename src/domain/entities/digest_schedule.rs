@@ -0,0 +1,57 @@
+use crate::domain::value_objects::FizzyId;
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use chrono_tz::Tz;
+
+/// When a chat gets its daily digest. The digest covers the boards the
+/// Fizzy user who set it up can access.
+#[derive(Debug, Clone)]
+pub struct DigestSchedule {
+    pub chat_id: i64,
+    pub user_id: FizzyId,
+    /// Local time of day to send at
+    pub send_at: NaiveTime,
+    pub timezone: Tz,
+    /// Local date of the last digest sent
+    pub last_sent_on: Option<NaiveDate>,
+}
+
+impl DigestSchedule {
+    /// The local date a digest is due for at `now`, if one hasn't gone out yet today
+    pub fn due_on(&self, now: DateTime<Utc>) -> Option<NaiveDate> {
+        let local = now.with_timezone(&self.timezone);
+        let today = local.date_naive();
+
+        (local.time() >= self.send_at && self.last_sent_on != Some(today)).then_some(today)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn schedule(last_sent_on: Option<NaiveDate>) -> DigestSchedule {
+        DigestSchedule {
+            chat_id: 1,
+            user_id: FizzyId::generate(),
+            send_at: NaiveTime::from_hms_opt(8, 30, 0).unwrap(),
+            timezone: chrono_tz::Europe::Berlin,
+            last_sent_on,
+        }
+    }
+
+    #[test]
+    fn test_due_on_uses_local_time() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 15).unwrap();
+
+        // 06:00 UTC is 08:00 in Berlin (CEST)
+        let before = Utc.with_ymd_and_hms(2026, 10, 15, 6, 0, 0).unwrap();
+        // 06:30 UTC is 08:30 in Berlin
+        let at = Utc.with_ymd_and_hms(2026, 10, 15, 6, 30, 0).unwrap();
+
+        assert_eq!(schedule(None).due_on(before), None);
+        assert_eq!(schedule(None).due_on(at), Some(today));
+        assert_eq!(schedule(Some(today)).due_on(at), None);
+        assert_eq!(schedule(today.pred_opt()).due_on(at), Some(today));
+    }
+}
@@ -0,0 +1,108 @@
+use crate::domain::ports::event_actions;
+use crate::domain::value_objects::FizzyId;
+use chrono::{DateTime, Utc};
+
+/// Entry in Fizzy's activity timeline (`events` table), written by both the
+/// web app and the bot
+#[derive(Debug, Clone)]
+pub struct Event {
+    pub id: FizzyId,
+    pub account_id: FizzyId,
+    pub board_id: FizzyId,
+    pub eventable_id: FizzyId,
+    pub eventable_type: String,
+    pub creator_id: FizzyId,
+    pub action: String,
+    pub particulars: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+
+    // Denormalized fields
+    /// The card the event is about (the commented card for comment events)
+    pub card_id: Option<FizzyId>,
+    pub creator_name: Option<String>,
+}
+
+/// Card activity that subscribed chats get notified about
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationKind {
+    Created,
+    Moved,
+    Closed,
+    Reopened,
+    Commented,
+}
+
+impl Event {
+    /// Classify the event for notifications. Covers the actions the web app
+    /// records as well as the bot's own.
+    pub fn notification_kind(&self) -> Option<NotificationKind> {
+        match self.action.as_str() {
+            event_actions::CARD_CREATED | event_actions::CARD_PUBLISHED => {
+                Some(NotificationKind::Created)
+            }
+            event_actions::CARD_COLUMN_CHANGED
+            | event_actions::CARD_TRIAGED
+            | event_actions::CARD_SENT_BACK_TO_TRIAGE
+            | event_actions::CARD_BOARD_CHANGED => Some(NotificationKind::Moved),
+            event_actions::CARD_CLOSED => Some(NotificationKind::Closed),
+            event_actions::CARD_REOPENED => Some(NotificationKind::Reopened),
+            event_actions::COMMENT_CREATED => Some(NotificationKind::Commented),
+            _ => None,
+        }
+    }
+
+    /// Look up a value in `particulars`. The web app nests its values under a
+    /// `particulars` key while the bot writes them at the top level.
+    pub fn particular(&self, key: &str) -> Option<&serde_json::Value> {
+        self.particulars
+            .get(key)
+            .or_else(|| self.particulars.get("particulars")?.get(key))
+            .filter(|value| !value.is_null())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(action: &str) -> Event {
+        Event {
+            id: FizzyId::generate(),
+            account_id: FizzyId::generate(),
+            board_id: FizzyId::generate(),
+            eventable_id: FizzyId::generate(),
+            eventable_type: "Card".to_string(),
+            creator_id: FizzyId::generate(),
+            action: action.to_string(),
+            particulars: serde_json::json!({}),
+            created_at: Utc::now(),
+            card_id: None,
+            creator_name: None,
+        }
+    }
+
+    #[test]
+    fn test_notification_kind() {
+        assert_eq!(event("card_published").notification_kind(), Some(NotificationKind::Created));
+        assert_eq!(event("card_created").notification_kind(), Some(NotificationKind::Created));
+        assert_eq!(event("card_triaged").notification_kind(), Some(NotificationKind::Moved));
+        assert_eq!(event("card_column_changed").notification_kind(), Some(NotificationKind::Moved));
+        assert_eq!(event("card_closed").notification_kind(), Some(NotificationKind::Closed));
+        assert_eq!(event("card_reopened").notification_kind(), Some(NotificationKind::Reopened));
+        assert_eq!(event("comment_created").notification_kind(), Some(NotificationKind::Commented));
+        assert_eq!(event("card_assigned").notification_kind(), None);
+    }
+
+    #[test]
+    fn test_particular_reads_flat_and_nested_values() {
+        let mut flat = event("card_column_changed");
+        flat.particulars = serde_json::json!({ "column_id": "abc", "due_on": null });
+        assert_eq!(flat.particular("column_id"), Some(&serde_json::json!("abc")));
+        assert_eq!(flat.particular("due_on"), None);
+
+        let mut nested = event("card_triaged");
+        nested.particulars = serde_json::json!({ "particulars": { "column": "Doing" } });
+        assert_eq!(nested.particular("column"), Some(&serde_json::json!("Doing")));
+        assert_eq!(nested.particular("column_id"), None);
+    }
+}
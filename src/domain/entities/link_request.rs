@@ -0,0 +1,22 @@
+use crate::domain::value_objects::FizzyId;
+use chrono::{DateTime, Utc};
+
+/// Pending request from a Telegram user to act as a Fizzy user.
+/// Identified by a short one-time code that expires.
+#[derive(Debug, Clone)]
+pub struct LinkRequest {
+    pub code: String,
+    pub telegram_user_id: i64,
+    pub telegram_name: Option<String>,
+    pub chat_id: i64,
+    pub user_id: FizzyId,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl LinkRequest {
+    /// Check if the code can no longer be used
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        now >= self.expires_at
+    }
+}
@@ -1,11 +1,25 @@
+mod attachment;
 mod card;
 mod board;
 mod column;
 mod comment;
+mod digest_schedule;
+mod event;
+mod link_request;
+mod subscription;
+mod tag;
 mod user;
+mod user_link;
 
-pub use card::Card;
+pub use attachment::Attachment;
+pub use card::{Card, DueStatus, DUE_SOON_DAYS};
 pub use board::Board;
 pub use column::Column;
 pub use comment::Comment;
-pub use user::User;
+pub use digest_schedule::DigestSchedule;
+pub use event::{Event, NotificationKind};
+pub use link_request::LinkRequest;
+pub use subscription::{Subscription, SubscriptionTarget};
+pub use tag::Tag;
+pub use user::{User, UserRole};
+pub use user_link::UserLink;
@@ -0,0 +1,33 @@
+use crate::domain::value_objects::FizzyId;
+use chrono::{DateTime, Utc};
+
+/// What a chat is subscribed to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubscriptionTarget {
+    Board(FizzyId),
+    Card(FizzyId),
+}
+
+impl SubscriptionTarget {
+    /// Convert to database string representation of the target type
+    pub fn type_str(&self) -> &'static str {
+        match self {
+            SubscriptionTarget::Board(_) => "board",
+            SubscriptionTarget::Card(_) => "card",
+        }
+    }
+
+    pub fn id(&self) -> &FizzyId {
+        match self {
+            SubscriptionTarget::Board(id) | SubscriptionTarget::Card(id) => id,
+        }
+    }
+}
+
+/// A chat following activity on a board or a single card
+#[derive(Debug, Clone)]
+pub struct Subscription {
+    pub chat_id: i64,
+    pub target: SubscriptionTarget,
+    pub created_at: DateTime<Utc>,
+}
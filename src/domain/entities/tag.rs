@@ -0,0 +1,31 @@
+use crate::domain::value_objects::FizzyId;
+use chrono::{DateTime, Utc};
+
+/// Account-wide label that can be attached to cards
+#[derive(Debug, Clone)]
+pub struct Tag {
+    pub id: FizzyId,
+    pub account_id: FizzyId,
+    pub title: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Tag {
+    /// Normalize user input into a tag title ("#Bug " -> "bug")
+    pub fn normalize_title(input: &str) -> Option<String> {
+        let title = input.trim().trim_start_matches('#').trim().to_lowercase();
+        (!title.is_empty()).then_some(title)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_title() {
+        assert_eq!(Tag::normalize_title(" #Bug "), Some("bug".to_string()));
+        assert_eq!(Tag::normalize_title("#"), None);
+    }
+}
@@ -1,5 +1,6 @@
 use crate::domain::value_objects::FizzyId;
 use chrono::{DateTime, Utc};
+use std::{fmt, str::FromStr};
 
 /// User role in an account
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -10,17 +11,31 @@ pub enum UserRole {
     System,
 }
 
-impl UserRole {
-    pub fn from_str(s: &str) -> Option<Self> {
+#[derive(Debug, PartialEq, Eq)]
+pub struct UserRoleError;
+
+impl fmt::Display for UserRoleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid user role")
+    }
+}
+
+impl FromStr for UserRole {
+    type Err = UserRoleError;
+
+    /// Parse from database string representation
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "owner" => Some(UserRole::Owner),
-            "admin" => Some(UserRole::Admin),
-            "member" => Some(UserRole::Member),
-            "system" => Some(UserRole::System),
-            _ => None,
+            "owner" => Ok(UserRole::Owner),
+            "admin" => Ok(UserRole::Admin),
+            "member" => Ok(UserRole::Member),
+            "system" => Ok(UserRole::System),
+            _ => Err(UserRoleError),
         }
     }
+}
 
+impl UserRole {
     pub fn as_str(&self) -> &'static str {
         match self {
             UserRole::Owner => "owner",
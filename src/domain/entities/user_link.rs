@@ -0,0 +1,10 @@
+use crate::domain::value_objects::FizzyId;
+use chrono::{DateTime, Utc};
+
+/// Binding between a Telegram account and the Fizzy user it acts as
+#[derive(Debug, Clone)]
+pub struct UserLink {
+    pub telegram_user_id: i64,
+    pub user_id: FizzyId,
+    pub created_at: DateTime<Utc>,
+}
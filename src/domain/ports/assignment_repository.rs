@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use crate::domain::value_objects::FizzyId;
+use crate::domain::errors::DomainError;
+
+/// Port for card assignment operations
+#[async_trait]
+pub trait AssignmentRepository: Send + Sync {
+    /// List the IDs of users assigned to a card
+    async fn list_assignee_ids(
+        &self,
+        account_id: &FizzyId,
+        card_id: &FizzyId,
+    ) -> Result<Vec<FizzyId>, DomainError>;
+
+    /// Assign a user to a card. Returns false if they were already assigned.
+    async fn assign(
+        &self,
+        account_id: &FizzyId,
+        card_id: &FizzyId,
+        assignee_id: &FizzyId,
+        assigner_id: &FizzyId,
+    ) -> Result<bool, DomainError>;
+
+    /// Remove a user from a card. Returns false if they were not assigned.
+    async fn unassign(
+        &self,
+        account_id: &FizzyId,
+        card_id: &FizzyId,
+        assignee_id: &FizzyId,
+    ) -> Result<bool, DomainError>;
+}
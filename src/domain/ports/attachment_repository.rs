@@ -0,0 +1,57 @@
+use async_trait::async_trait;
+use crate::domain::entities::Attachment;
+use crate::domain::errors::DomainError;
+use crate::domain::value_objects::FizzyId;
+
+/// Rich text a file is attached to
+#[derive(Debug, Clone)]
+pub enum AttachmentTarget {
+    /// The card's description
+    CardDescription(FizzyId),
+    /// A new comment on the card, created together with the attachment.
+    /// The file is embedded after the ActionText `body`.
+    NewComment {
+        card_id: FizzyId,
+        creator_id: FizzyId,
+        body: String,
+    },
+}
+
+/// A file to store
+#[derive(Debug, Clone)]
+pub struct NewAttachment {
+    pub filename: String,
+    pub content_type: Option<String>,
+    pub data: Vec<u8>,
+}
+
+/// Port for file attachments (ActiveStorage blobs and their files)
+#[async_trait]
+pub trait AttachmentRepository: Send + Sync {
+    /// Store a file and embed it in a description or a new comment. The
+    /// rows are written in one transaction; the file is removed again if
+    /// that fails.
+    async fn create(
+        &self,
+        account_id: &FizzyId,
+        target: &AttachmentTarget,
+        file: NewAttachment,
+    ) -> Result<Attachment, DomainError>;
+
+    /// List the files on a card's description and comments (oldest first)
+    async fn list_for_card(
+        &self,
+        account_id: &FizzyId,
+        card_id: &FizzyId,
+    ) -> Result<Vec<Attachment>, DomainError>;
+
+    /// Find an attachment by its ID
+    async fn find_by_id(
+        &self,
+        account_id: &FizzyId,
+        id: &FizzyId,
+    ) -> Result<Option<Attachment>, DomainError>;
+
+    /// Read an attachment's file contents
+    async fn read(&self, attachment: &Attachment) -> Result<Vec<u8>, DomainError>;
+}
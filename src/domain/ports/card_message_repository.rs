@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::errors::DomainError;
+
+/// Port for remembering which card each message sent by the bot is about,
+/// so replies to those messages can act on the card
+#[async_trait]
+pub trait CardMessageRepository: Send + Sync {
+    /// Record the card a message refers to, replacing any previous card
+    async fn remember(&self, chat_id: i64, message_id: i32, card_number: i64) -> Result<(), DomainError>;
+
+    /// Get the card number a message refers to
+    async fn find(&self, chat_id: i64, message_id: i32) -> Result<Option<i64>, DomainError>;
+
+    /// Forget messages remembered before the given time
+    async fn prune_before(&self, before: DateTime<Utc>) -> Result<(), DomainError>;
+}
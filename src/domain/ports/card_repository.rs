@@ -2,7 +2,7 @@ use crate::domain::entities::Card;
 use crate::domain::errors::DomainError;
 use crate::domain::value_objects::{CardStatus, FizzyId};
 use async_trait::async_trait;
-use chrono::NaiveDate;
+use chrono::{DateTime, NaiveDate, Utc};
 
 /// Input for creating a new card
 #[derive(Debug, Clone)]
@@ -22,7 +22,8 @@ pub struct UpdateCardInput {
     pub description: Option<String>,
     pub status: Option<CardStatus>,
     pub column_id: Option<FizzyId>,
-    pub due_on: Option<NaiveDate>,
+    /// `Some(None)` clears the due date
+    pub due_on: Option<Option<NaiveDate>>,
 }
 
 /// Filters for listing cards
@@ -31,15 +32,116 @@ pub struct CardFilters {
     pub assignee_id: Option<FizzyId>,
     pub creator_id: Option<FizzyId>,
     pub board_id: Option<FizzyId>,
+    /// Only cards on one of these boards, e.g. the ones a user can access.
+    /// An empty list matches nothing.
+    pub board_ids: Option<Vec<FizzyId>>,
     pub column_id: Option<FizzyId>,
     pub status: Option<Vec<CardStatus>>,
     pub exclude_status: Option<Vec<CardStatus>>,
     pub exclude_closed: Option<bool>,
+    /// Only closed cards
+    pub only_closed: Option<bool>,
+    /// Leave out cards postponed with "Not now"
+    pub exclude_not_now: Option<bool>,
+    /// Only cards postponed with "Not now"
+    pub only_not_now: Option<bool>,
     pub is_golden: Option<bool>,
+    /// Only cards not yet placed in a column
+    pub awaiting_triage: Option<bool>,
+    /// Only cards with a due date on or before this day
+    pub due_on_or_before: Option<NaiveDate>,
+    /// Only cards whose title starts with this (ignoring case)
+    pub title_prefix: Option<String>,
+    /// Only cards with a word after the first in their title starting with
+    /// this (ignoring case)
+    pub title_word_prefix: Option<String>,
+    /// Only cards less recently active than this one (keyset pagination)
+    pub after: Option<CardCursor>,
+    /// Only cards more recently active than this one (keyset pagination).
+    /// The closest `limit` cards are returned, still most recent first.
+    pub before: Option<CardCursor>,
     pub limit: Option<i64>,
     pub offset: Option<i64>,
 }
 
+/// A card's position in lists ordered by most recent activity
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CardCursor {
+    pub last_active_at: DateTime<Utc>,
+    /// Breaks ties between cards active at the same instant
+    pub number: i64,
+}
+
+impl CardCursor {
+    pub fn of(card: &Card) -> Self {
+        Self {
+            last_active_at: card.last_active_at,
+            number: card.number,
+        }
+    }
+}
+
+/// Which page of an activity-ordered card list to load
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CardPage {
+    #[default]
+    First,
+    /// The page following the card at this cursor
+    After(CardCursor),
+    /// The page preceding the card at this cursor
+    Before(CardCursor),
+}
+
+/// One page of a card list
+#[derive(Debug, Clone)]
+pub struct CardListPage {
+    pub cards: Vec<Card>,
+    pub has_prev: bool,
+    pub has_next: bool,
+}
+
+impl CardFilters {
+    /// Restrict the filters to a page, fetching one extra card to tell whether more follow
+    pub fn page(self, page: CardPage, per_page: usize) -> Self {
+        let (after, before) = match page {
+            CardPage::First => (None, None),
+            CardPage::After(cursor) => (Some(cursor), None),
+            CardPage::Before(cursor) => (None, Some(cursor)),
+        };
+
+        Self {
+            after,
+            before,
+            limit: Some(per_page as i64 + 1),
+            offset: None,
+            ..self
+        }
+    }
+}
+
+impl CardListPage {
+    /// Build a page from cards listed with [`CardFilters::page`].
+    /// Any cards beyond `per_page` are dropped from the side away from the cursor.
+    pub fn new(mut cards: Vec<Card>, page: CardPage, per_page: usize) -> Self {
+        let more = cards.len() > per_page;
+
+        match page {
+            CardPage::Before(_) => {
+                cards.drain(..cards.len().saturating_sub(per_page));
+                Self { cards, has_prev: more, has_next: true }
+            }
+            CardPage::First | CardPage::After(_) => {
+                cards.truncate(per_page);
+                Self {
+                    cards,
+                    has_prev: matches!(page, CardPage::After(_)),
+                    has_next: more,
+                }
+            }
+        }
+    }
+}
+
 /// Port for card repository operations
 #[async_trait]
 pub trait CardRepository: Send + Sync {
@@ -64,6 +166,9 @@ pub trait CardRepository: Send + Sync {
         filters: CardFilters,
     ) -> Result<Vec<Card>, DomainError>;
 
+    /// Count the cards matching the filters, ignoring `limit` and `offset`
+    async fn count(&self, account_id: &FizzyId, filters: CardFilters) -> Result<i64, DomainError>;
+
     /// Create a new card
     async fn create(
         &self,
@@ -89,4 +194,76 @@ pub trait CardRepository: Send + Sync {
 
     /// Reopen a closed card
     async fn reopen(&self, account_id: &FizzyId, card_id: &FizzyId) -> Result<(), DomainError>;
+
+    /// Move a card to "Not now": reopens it, sends it back to triage and records who postponed it
+    async fn postpone(
+        &self,
+        account_id: &FizzyId,
+        card_id: &FizzyId,
+        user_id: &FizzyId,
+    ) -> Result<(), DomainError>;
+
+    /// Bring a postponed card back
+    async fn resume(&self, account_id: &FizzyId, card_id: &FizzyId) -> Result<(), DomainError>;
+
+    /// Mark or unmark a card as golden. Returns false if it already was in that state.
+    async fn set_golden(
+        &self,
+        account_id: &FizzyId,
+        card_id: &FizzyId,
+        golden: bool,
+    ) -> Result<bool, DomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card(number: i64) -> Card {
+        let now = Utc::now();
+        Card {
+            id: FizzyId::generate(),
+            account_id: FizzyId::generate(),
+            board_id: FizzyId::generate(),
+            column_id: None,
+            creator_id: FizzyId::generate(),
+            number,
+            title: format!("Card {}", number),
+            description: None,
+            status: CardStatus::Published,
+            due_on: None,
+            last_active_at: now,
+            created_at: now,
+            updated_at: now,
+            board_name: None,
+            column_name: None,
+            column_color: None,
+            creator_name: None,
+            assignee_names: Vec::new(),
+            tag_titles: Vec::new(),
+            is_golden: false,
+        }
+    }
+
+    fn numbers(page: &CardListPage) -> Vec<i64> {
+        page.cards.iter().map(|c| c.number).collect()
+    }
+
+    #[test]
+    fn test_card_list_page_trims_the_lookahead_card() {
+        let cursor = CardCursor::of(&card(9));
+
+        let first = CardListPage::new(vec![card(5), card(4), card(3)], CardPage::First, 2);
+        assert_eq!(numbers(&first), vec![5, 4]);
+        assert!(!first.has_prev && first.has_next);
+
+        let last = CardListPage::new(vec![card(2)], CardPage::After(cursor), 2);
+        assert_eq!(numbers(&last), vec![2]);
+        assert!(last.has_prev && !last.has_next);
+
+        // Going back, the extra card is the most recent one
+        let back = CardListPage::new(vec![card(8), card(7), card(6)], CardPage::Before(cursor), 2);
+        assert_eq!(numbers(&back), vec![7, 6]);
+        assert!(back.has_prev && back.has_next);
+    }
 }
@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use crate::domain::entities::Comment;
 use crate::domain::value_objects::FizzyId;
 use crate::domain::errors::DomainError;
@@ -11,7 +12,23 @@ pub trait CommentRepository: Send + Sync {
         &self,
         account_id: &FizzyId,
         card_id: &FizzyId,
-        limit: Option<i64>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Comment>, DomainError>;
+
+    /// Find a comment by its ID
+    async fn find_by_id(
+        &self,
+        account_id: &FizzyId,
+        id: &FizzyId,
+    ) -> Result<Option<Comment>, DomainError>;
+
+    /// List comments written by a user since a point in time (any card)
+    async fn list_by_creator_since(
+        &self,
+        account_id: &FizzyId,
+        creator_id: &FizzyId,
+        since: DateTime<Utc>,
     ) -> Result<Vec<Comment>, DomainError>;
 
     /// Add a comment to a card
@@ -22,4 +39,15 @@ pub trait CommentRepository: Send + Sync {
         creator_id: &FizzyId,
         content: &str,
     ) -> Result<Comment, DomainError>;
+
+    /// Replace a comment's body
+    async fn update(
+        &self,
+        account_id: &FizzyId,
+        id: &FizzyId,
+        content: &str,
+    ) -> Result<(), DomainError>;
+
+    /// Delete a comment along with its body
+    async fn delete(&self, account_id: &FizzyId, id: &FizzyId) -> Result<(), DomainError>;
 }
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use crate::domain::entities::DigestSchedule;
+use crate::domain::errors::DomainError;
+
+/// Port for per-chat daily digest schedules
+#[async_trait]
+pub trait DigestScheduleRepository: Send + Sync {
+    /// Get a chat's schedule
+    async fn find(&self, chat_id: i64) -> Result<Option<DigestSchedule>, DomainError>;
+
+    /// List all schedules
+    async fn list(&self) -> Result<Vec<DigestSchedule>, DomainError>;
+
+    /// Create or replace a chat's schedule
+    async fn save(&self, schedule: &DigestSchedule) -> Result<(), DomainError>;
+
+    /// Remove a chat's schedule. Returns false if it had none.
+    async fn delete(&self, chat_id: i64) -> Result<bool, DomainError>;
+
+    /// Record the local date a digest was sent for
+    async fn mark_sent(&self, chat_id: i64, sent_on: NaiveDate) -> Result<(), DomainError>;
+}
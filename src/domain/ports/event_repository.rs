@@ -1,6 +1,8 @@
+use crate::domain::entities::Event;
 use crate::domain::errors::DomainError;
 use crate::domain::value_objects::FizzyId;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 
 /// Input for creating an event (audit trail)
 #[derive(Debug, Clone)]
@@ -21,7 +23,25 @@ pub mod event_actions {
     pub const CARD_REOPENED: &str = "card_reopened";
     pub const CARD_COLUMN_CHANGED: &str = "card_column_changed";
     pub const CARD_BOARD_CHANGED: &str = "card_board_changed";
+    pub const CARD_ASSIGNED: &str = "card_assigned";
+    pub const CARD_UNASSIGNED: &str = "card_unassigned";
+    pub const CARD_DUE_DATE_CHANGED: &str = "card_due_date_changed";
+    pub const CARD_GILDED: &str = "card_gilded";
+    pub const CARD_UNGILDED: &str = "card_ungilded";
+    pub const CARD_POSTPONED: &str = "card_postponed";
+    pub const CARD_RESUMED: &str = "card_resumed";
+    pub const CARD_TAGGED: &str = "card_tagged";
+    pub const CARD_UNTAGGED: &str = "card_untagged";
     pub const COMMENT_CREATED: &str = "comment_created";
+    pub const COMMENT_UPDATED: &str = "comment_updated";
+    pub const COMMENT_DELETED: &str = "comment_deleted";
+
+    // Only recorded by the web app
+    pub const CARD_PUBLISHED: &str = "card_published";
+    pub const CARD_TRIAGED: &str = "card_triaged";
+    pub const CARD_SENT_BACK_TO_TRIAGE: &str = "card_sent_back_to_triage";
+    pub const CARD_TITLE_CHANGED: &str = "card_title_changed";
+    pub const CARD_AUTO_POSTPONED: &str = "card_auto_postponed";
 }
 
 /// Port for event repository operations (audit trail)
@@ -33,4 +53,33 @@ pub trait EventRepository: Send + Sync {
         account_id: &FizzyId,
         input: CreateEventInput,
     ) -> Result<(), DomainError>;
+
+    /// Get the creation time of the most recent event, if any
+    async fn latest_created_at(&self, account_id: &FizzyId) -> Result<Option<DateTime<Utc>>, DomainError>;
+
+    /// List events created at or after `since`, oldest first
+    async fn list_since(
+        &self,
+        account_id: &FizzyId,
+        since: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<Event>, DomainError>;
+
+    /// List a card's events, including comments on it, oldest first
+    async fn list_for_card(
+        &self,
+        account_id: &FizzyId,
+        card_id: &FizzyId,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Event>, DomainError>;
+
+    /// List events with one of the given actions recorded in `[since, until)`, oldest first
+    async fn list_between(
+        &self,
+        account_id: &FizzyId,
+        since: DateTime<Utc>,
+        until: DateTime<Utc>,
+        actions: &[&str],
+    ) -> Result<Vec<Event>, DomainError>;
 }
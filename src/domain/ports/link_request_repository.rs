@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+use crate::domain::entities::LinkRequest;
+use crate::domain::errors::DomainError;
+
+/// Port for pending account link requests
+#[async_trait]
+pub trait LinkRequestRepository: Send + Sync {
+    /// Store a request, replacing any earlier one from the same Telegram user
+    async fn create(&self, request: &LinkRequest) -> Result<(), DomainError>;
+
+    /// Find a request by its one-time code
+    async fn find_by_code(&self, code: &str) -> Result<Option<LinkRequest>, DomainError>;
+
+    /// Find the pending request of a Telegram user
+    async fn find_by_telegram_user(
+        &self,
+        telegram_user_id: i64,
+    ) -> Result<Option<LinkRequest>, DomainError>;
+
+    /// Remove a request once it has been used or denied
+    async fn delete(&self, code: &str) -> Result<(), DomainError>;
+}
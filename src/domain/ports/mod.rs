@@ -1,19 +1,37 @@
+pub mod assignment_repository;
+pub mod attachment_repository;
 pub mod board_repository;
+pub mod card_message_repository;
 pub mod card_repository;
 pub mod comment_repository;
+pub mod digest_schedule_repository;
 pub mod event_repository;
+pub mod link_request_repository;
+pub mod reminder_repository;
+pub mod search_repository;
+pub mod subscription_repository;
+pub mod tag_repository;
+pub mod user_link_repository;
+pub mod user_repository;
+pub mod user_settings_repository;
 
+pub use assignment_repository::AssignmentRepository;
+pub use attachment_repository::{AttachmentRepository, AttachmentTarget, NewAttachment};
 pub use board_repository::BoardRepository;
-pub use card_repository::{CardFilters, CardRepository, CreateCardInput, UpdateCardInput};
+pub use card_message_repository::CardMessageRepository;
+pub use card_repository::{
+    CardCursor, CardFilters, CardListPage, CardPage, CardRepository, CreateCardInput,
+    UpdateCardInput,
+};
 pub use comment_repository::CommentRepository;
-pub use event_repository::{CreateEventInput, EventRepository};
+pub use digest_schedule_repository::DigestScheduleRepository;
+pub use event_repository::{event_actions, CreateEventInput, EventRepository};
+pub use link_request_repository::LinkRequestRepository;
+pub use reminder_repository::ReminderRepository;
+pub use search_repository::{SearchRecord, SearchRepository};
+pub use subscription_repository::SubscriptionRepository;
+pub use tag_repository::TagRepository;
+pub use user_link_repository::UserLinkRepository;
+pub use user_repository::UserRepository;
+pub use user_settings_repository::UserSettingsRepository;
 
-pub mod event_actions {
-    pub const CARD_CREATED: &str = "card_created";
-    pub const CARD_UPDATED: &str = "card_updated";
-    pub const CARD_CLOSED: &str = "card_closed";
-    pub const CARD_REOPENED: &str = "card_reopened";
-    pub const CARD_COLUMN_CHANGED: &str = "card_column_changed";
-    pub const CARD_BOARD_CHANGED: &str = "card_board_changed";
-    pub const COMMENT_CREATED: &str = "comment_created";
-}
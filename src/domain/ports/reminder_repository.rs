@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use crate::domain::value_objects::{FizzyId, ReminderThreshold};
+use crate::domain::errors::DomainError;
+
+/// Port for due-date reminder bookkeeping, so each reminder goes out only once.
+/// The due date is part of the key: moving it re-arms the reminders.
+#[async_trait]
+pub trait ReminderRepository: Send + Sync {
+    /// Check whether a reminder was already sent
+    async fn was_sent(
+        &self,
+        card_id: &FizzyId,
+        telegram_user_id: i64,
+        due_on: NaiveDate,
+        threshold: ReminderThreshold,
+    ) -> Result<bool, DomainError>;
+
+    /// Record that a reminder was sent
+    async fn mark_sent(
+        &self,
+        card_id: &FizzyId,
+        telegram_user_id: i64,
+        due_on: NaiveDate,
+        threshold: ReminderThreshold,
+    ) -> Result<(), DomainError>;
+}
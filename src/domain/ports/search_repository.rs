@@ -0,0 +1,29 @@
+use crate::domain::errors::DomainError;
+use crate::domain::value_objects::FizzyId;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+/// A row from Fizzy's search index (one per card or comment)
+#[derive(Debug, Clone)]
+pub struct SearchRecord {
+    pub card_id: FizzyId,
+    pub searchable_type: String,
+    pub title: Option<String>,
+    pub content: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Port for full-text search over cards and comments
+#[async_trait]
+pub trait SearchRepository: Send + Sync {
+    /// Find the `limit` most relevant cards whose records together contain
+    /// every term (in a title or content), restricted to the given boards.
+    /// Returns those cards' records that match any term, most recent first.
+    async fn search(
+        &self,
+        account_id: &FizzyId,
+        terms: &[String],
+        board_ids: &[FizzyId],
+        limit: i64,
+    ) -> Result<Vec<SearchRecord>, DomainError>;
+}
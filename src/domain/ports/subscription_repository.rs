@@ -0,0 +1,41 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::entities::{Subscription, SubscriptionTarget};
+use crate::domain::value_objects::FizzyId;
+use crate::domain::errors::DomainError;
+
+/// Port for chat subscriptions to board/card activity, and how far the
+/// events table has been tailed for notifications
+#[async_trait]
+pub trait SubscriptionRepository: Send + Sync {
+    /// Subscribe a chat. Returns false if it was already subscribed.
+    async fn subscribe(&self, chat_id: i64, target: &SubscriptionTarget) -> Result<bool, DomainError>;
+
+    /// Unsubscribe a chat. Returns false if it was not subscribed.
+    async fn unsubscribe(&self, chat_id: i64, target: &SubscriptionTarget) -> Result<bool, DomainError>;
+
+    /// List a chat's subscriptions
+    async fn list_for_chat(&self, chat_id: i64) -> Result<Vec<Subscription>, DomainError>;
+
+    /// List chats subscribed to a board or to a card on it
+    async fn list_chats_for(
+        &self,
+        board_id: &FizzyId,
+        card_id: &FizzyId,
+    ) -> Result<Vec<i64>, DomainError>;
+
+    /// Get the creation time events have been tailed up to
+    async fn get_watermark(&self) -> Result<Option<DateTime<Utc>>, DomainError>;
+
+    /// Store the creation time events have been tailed up to
+    async fn set_watermark(&self, created_at: DateTime<Utc>) -> Result<(), DomainError>;
+
+    /// List the handled events created at or after `since`
+    async fn list_handled_since(&self, since: DateTime<Utc>) -> Result<Vec<FizzyId>, DomainError>;
+
+    /// Record that an event's notifications have been sent, or that none were needed
+    async fn mark_handled(&self, event_id: &FizzyId, created_at: DateTime<Utc>) -> Result<(), DomainError>;
+
+    /// Forget handled events created before `before`
+    async fn prune_handled(&self, before: DateTime<Utc>) -> Result<(), DomainError>;
+}
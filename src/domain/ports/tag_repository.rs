@@ -0,0 +1,37 @@
+use async_trait::async_trait;
+use crate::domain::entities::Tag;
+use crate::domain::value_objects::FizzyId;
+use crate::domain::errors::DomainError;
+
+/// Port for tag repository operations
+#[async_trait]
+pub trait TagRepository: Send + Sync {
+    /// List all tags in the account, alphabetically
+    async fn list(&self, account_id: &FizzyId) -> Result<Vec<Tag>, DomainError>;
+
+    /// List the tags attached to a card
+    async fn list_for_card(
+        &self,
+        account_id: &FizzyId,
+        card_id: &FizzyId,
+    ) -> Result<Vec<Tag>, DomainError>;
+
+    /// Find a tag by title, creating it in the account if it doesn't exist
+    async fn find_or_create(&self, account_id: &FizzyId, title: &str) -> Result<Tag, DomainError>;
+
+    /// Attach a tag to a card. Returns false if it was already attached.
+    async fn add_to_card(
+        &self,
+        account_id: &FizzyId,
+        card_id: &FizzyId,
+        tag_id: &FizzyId,
+    ) -> Result<bool, DomainError>;
+
+    /// Detach a tag from a card. Returns false if it wasn't attached.
+    async fn remove_from_card(
+        &self,
+        account_id: &FizzyId,
+        card_id: &FizzyId,
+        tag_id: &FizzyId,
+    ) -> Result<bool, DomainError>;
+}
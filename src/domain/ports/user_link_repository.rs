@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+use crate::domain::entities::UserLink;
+use crate::domain::value_objects::FizzyId;
+use crate::domain::errors::DomainError;
+
+/// Port for persisted Telegram -> Fizzy user links
+#[async_trait]
+pub trait UserLinkRepository: Send + Sync {
+    /// List all persisted links
+    async fn list(&self) -> Result<Vec<UserLink>, DomainError>;
+
+    /// Create or replace the link for a Telegram user
+    async fn save(
+        &self,
+        telegram_user_id: i64,
+        user_id: &FizzyId,
+    ) -> Result<UserLink, DomainError>;
+
+    /// Remove the link for a Telegram user
+    async fn delete(&self, telegram_user_id: i64) -> Result<(), DomainError>;
+}
@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+use crate::domain::entities::User;
+use crate::domain::value_objects::FizzyId;
+use crate::domain::errors::DomainError;
+
+/// Port for user repository operations
+#[async_trait]
+pub trait UserRepository: Send + Sync {
+    /// Find a user by ID
+    async fn find_by_id(
+        &self,
+        account_id: &FizzyId,
+        id: &FizzyId,
+    ) -> Result<Option<User>, DomainError>;
+
+    /// Find an active user by identity email (case-insensitive)
+    async fn find_by_email(
+        &self,
+        account_id: &FizzyId,
+        email: &str,
+    ) -> Result<Option<User>, DomainError>;
+
+    /// List active, non-system users in the account
+    async fn list_active(&self, account_id: &FizzyId) -> Result<Vec<User>, DomainError>;
+
+    /// List active, non-system users with access to a board
+    async fn list_board_members(
+        &self,
+        account_id: &FizzyId,
+        board_id: &FizzyId,
+    ) -> Result<Vec<User>, DomainError>;
+}
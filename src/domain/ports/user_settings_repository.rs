@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use crate::domain::errors::DomainError;
+use crate::domain::value_objects::FizzyId;
+
+/// Port for per-Telegram-user bot preferences
+#[async_trait]
+pub trait UserSettingsRepository: Send + Sync {
+    /// Get the IANA timezone a Telegram user has chosen, if any
+    async fn get_timezone(&self, telegram_user_id: i64) -> Result<Option<String>, DomainError>;
+
+    /// Store the IANA timezone for a Telegram user
+    async fn set_timezone(&self, telegram_user_id: i64, timezone: &str) -> Result<(), DomainError>;
+
+    /// Get the board a Telegram user last created a card on, if any
+    async fn get_last_board(&self, telegram_user_id: i64) -> Result<Option<FizzyId>, DomainError>;
+
+    /// Remember the board a Telegram user last created a card on
+    async fn set_last_board(&self, telegram_user_id: i64, board_id: &FizzyId) -> Result<(), DomainError>;
+}
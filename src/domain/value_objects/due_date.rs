@@ -0,0 +1,132 @@
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+use std::fmt;
+
+/// A due date as typed by a user, resolved against "today" in their timezone.
+/// Understands "today", "tomorrow", weekday names ("fri", "next friday"),
+/// "next week", "in 3 days" / "in 2 weeks", ISO dates and "none".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DueDate {
+    On(NaiveDate),
+    Clear,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct DueDateError(String);
+
+impl fmt::Display for DueDateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "can't understand \"{}\" (try tomorrow, fri, next week, in 3 days, 2026-11-02 or none)",
+            self.0
+        )
+    }
+}
+
+impl DueDate {
+    pub fn parse(input: &str, today: NaiveDate) -> Result<Self, DueDateError> {
+        let normalized = input.trim().to_lowercase();
+        let words: Vec<&str> = normalized.split_whitespace().collect();
+        let error = || DueDateError(input.trim().to_string());
+
+        let date = match words.as_slice() {
+            ["none" | "clear" | "remove" | "no"] => return Ok(DueDate::Clear),
+            ["today"] => Some(today),
+            ["tomorrow" | "tmrw" | "tmr"] => today.checked_add_days(Days::new(1)),
+            ["next", "week"] => Some(next_monday(today)),
+            ["next", day] => {
+                let weekday = parse_weekday(day).ok_or_else(error)?;
+                let monday = next_monday(today);
+                monday.checked_add_days(Days::new(weekday.num_days_from_monday() as u64))
+            }
+            ["in", amount, unit] => {
+                let amount: u64 = match *amount {
+                    "a" | "an" | "one" => 1,
+                    n => n.parse().map_err(|_| error())?,
+                };
+                let days = match *unit {
+                    "day" | "days" => amount,
+                    "week" | "weeks" => amount.checked_mul(7).ok_or_else(error)?,
+                    _ => return Err(error()),
+                };
+                today.checked_add_days(Days::new(days))
+            }
+            [word] => match parse_weekday(word) {
+                Some(weekday) => Some(upcoming(today, weekday)),
+                None => NaiveDate::parse_from_str(word, "%Y-%m-%d").ok(),
+            },
+            _ => None,
+        };
+
+        date.map(DueDate::On).ok_or_else(error)
+    }
+
+    pub fn date(&self) -> Option<NaiveDate> {
+        match self {
+            DueDate::On(date) => Some(*date),
+            DueDate::Clear => None,
+        }
+    }
+}
+
+/// Parse full or abbreviated English weekday names
+fn parse_weekday(word: &str) -> Option<Weekday> {
+    match word {
+        "mon" | "monday" => Some(Weekday::Mon),
+        "tue" | "tues" | "tuesday" => Some(Weekday::Tue),
+        "wed" | "weds" | "wednesday" => Some(Weekday::Wed),
+        "thu" | "thur" | "thurs" | "thursday" => Some(Weekday::Thu),
+        "fri" | "friday" => Some(Weekday::Fri),
+        "sat" | "saturday" => Some(Weekday::Sat),
+        "sun" | "sunday" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+/// The next occurrence of `weekday` after today (a week ahead if today is that day)
+fn upcoming(today: NaiveDate, weekday: Weekday) -> NaiveDate {
+    let current = today.weekday().num_days_from_monday() as i64;
+    let target = weekday.num_days_from_monday() as i64;
+    let ahead = (target - current).rem_euclid(7);
+    let ahead = if ahead == 0 { 7 } else { ahead };
+    today + chrono::Duration::days(ahead)
+}
+
+/// Monday of the following week
+fn next_monday(today: NaiveDate) -> NaiveDate {
+    today + chrono::Duration::days(7 - today.weekday().num_days_from_monday() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_parse_relative_dates() {
+        // Thursday
+        let today = date(2026, 10, 15);
+
+        assert_eq!(DueDate::parse("today", today), Ok(DueDate::On(today)));
+        assert_eq!(DueDate::parse("Tomorrow", today), Ok(DueDate::On(date(2026, 10, 16))));
+        assert_eq!(DueDate::parse("fri", today), Ok(DueDate::On(date(2026, 10, 16))));
+        assert_eq!(DueDate::parse("thursday", today), Ok(DueDate::On(date(2026, 10, 22))));
+        assert_eq!(DueDate::parse("next week", today), Ok(DueDate::On(date(2026, 10, 19))));
+        assert_eq!(DueDate::parse("next fri", today), Ok(DueDate::On(date(2026, 10, 23))));
+        assert_eq!(DueDate::parse("in 3 days", today), Ok(DueDate::On(date(2026, 10, 18))));
+        assert_eq!(DueDate::parse("in a week", today), Ok(DueDate::On(date(2026, 10, 22))));
+    }
+
+    #[test]
+    fn test_parse_absolute_and_clear() {
+        let today = date(2026, 10, 15);
+
+        assert_eq!(DueDate::parse("2026-11-02", today), Ok(DueDate::On(date(2026, 11, 2))));
+        assert_eq!(DueDate::parse("none", today), Ok(DueDate::Clear));
+        assert!(DueDate::parse("someday", today).is_err());
+        assert!(DueDate::parse("in 3 fortnights", today).is_err());
+    }
+}
@@ -1,5 +1,9 @@
 mod fizzy_id;
 mod card_status;
+mod due_date;
+mod reminder_threshold;
 
 pub use fizzy_id::FizzyId;
 pub use card_status::CardStatus;
+pub use due_date::{DueDate, DueDateError};
+pub use reminder_threshold::ReminderThreshold;
@@ -0,0 +1,60 @@
+use chrono::NaiveDate;
+
+/// Points relative to a card's due date at which a reminder goes out.
+/// Each one is sent at most once per card, recipient and due date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReminderThreshold {
+    DayBefore,
+    DueToday,
+    Overdue,
+}
+
+impl ReminderThreshold {
+    /// The threshold a card due on `due_on` has reached as of `today`, if any
+    pub fn reached(due_on: NaiveDate, today: NaiveDate) -> Option<Self> {
+        match (due_on - today).num_days() {
+            1 => Some(ReminderThreshold::DayBefore),
+            0 => Some(ReminderThreshold::DueToday),
+            days if days < 0 => Some(ReminderThreshold::Overdue),
+            _ => None,
+        }
+    }
+
+    /// Convert to database string representation
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReminderThreshold::DayBefore => "day_before",
+            ReminderThreshold::DueToday => "due_today",
+            ReminderThreshold::Overdue => "overdue",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_reached() {
+        let today = date(2026, 10, 15);
+
+        assert_eq!(ReminderThreshold::reached(date(2026, 10, 18), today), None);
+        assert_eq!(ReminderThreshold::reached(date(2026, 10, 17), today), None);
+        assert_eq!(
+            ReminderThreshold::reached(date(2026, 10, 16), today),
+            Some(ReminderThreshold::DayBefore)
+        );
+        assert_eq!(
+            ReminderThreshold::reached(today, today),
+            Some(ReminderThreshold::DueToday)
+        );
+        assert_eq!(
+            ReminderThreshold::reached(date(2026, 10, 1), today),
+            Some(ReminderThreshold::Overdue)
+        );
+    }
+}
@@ -1,80 +1,166 @@
+use chrono_tz::Tz;
+use std::collections::HashMap;
 use std::env;
+use std::path::Path;
+use std::time::Duration;
 
 #[derive(Debug, Clone)]
 pub struct AppConfig {
     pub telegram: TelegramConfig,
     pub database: DatabaseConfig,
     pub fizzy: FizzyConfig,
+    pub scheduler: SchedulerConfig,
 }
 
 #[derive(Debug, Clone)]
 pub struct TelegramConfig {
     pub bot_token: String,
-    pub allowed_user_ids: Vec<i64>,
+    /// Telegram user ID -> Fizzy user ID, as configured by the admin
+    pub user_map: HashMap<i64, String>,
 }
 
 #[derive(Debug, Clone)]
 pub struct DatabaseConfig {
     pub path: String,
     pub max_connections: u32,
+    /// Bot-owned SQLite database (user links, reminders, subscriptions...)
+    pub state_path: String,
+    /// Root of the Rails app's local ActiveStorage Disk service
+    pub storage_path: String,
+    /// Name of that service in the Rails app's storage.yml
+    pub storage_service: String,
 }
 
 #[derive(Debug, Clone)]
 pub struct FizzyConfig {
     pub account_id: String,
-    pub user_id: String,
+    /// Legacy single-user mode: every allowed Telegram user acts as this user
+    pub user_id: Option<String>,
     pub default_board_id: String,
     pub base_url: Option<String>,
+    /// The Rails app's secret_key_base, needed to embed attached files so the web app shows them
+    pub secret_key_base: Option<String>,
+    /// Timezone for users who haven't picked one with /timezone
+    pub timezone: Tz,
+}
+
+#[derive(Debug, Clone)]
+pub struct SchedulerConfig {
+    /// How often to look for cards that need a due-date reminder
+    pub reminder_interval: Duration,
+    /// How often to check Fizzy's events table for subscription notifications
+    pub event_poll_interval: Duration,
 }
 
 impl AppConfig {
     pub fn from_env() -> Result<Self, String> {
+        let fizzy = FizzyConfig::from_env()?;
         Ok(Self {
-            telegram: TelegramConfig::from_env()?,
+            telegram: TelegramConfig::from_env(fizzy.user_id.as_deref())?,
             database: DatabaseConfig::from_env()?,
-            fizzy: FizzyConfig::from_env()?,
+            fizzy,
+            scheduler: SchedulerConfig::from_env()?,
         })
     }
 }
 
 impl TelegramConfig {
-    fn from_env() -> Result<Self, String> {
+    fn from_env(legacy_user_id: Option<&str>) -> Result<Self, String> {
         let bot_token = env::var("TELEGRAM_BOT_TOKEN")
             .map_err(|_| "TELEGRAM_BOT_TOKEN not set")?;
 
-        let allowed_user_ids = env::var("TELEGRAM_ALLOWED_USER_IDS")
-            .map_err(|_| "TELEGRAM_ALLOWED_USER_IDS not set")?
-            .split(',')
-            .map(|s| s.trim().parse::<i64>())
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|_| "Invalid TELEGRAM_ALLOWED_USER_IDS format")?;
+        let user_map = match env::var("TELEGRAM_USER_MAP") {
+            Ok(value) => parse_user_map(&value)?,
+            // Legacy configuration: a list of allowed IDs sharing FIZZY_USER_ID
+            Err(_) => match (env::var("TELEGRAM_ALLOWED_USER_IDS"), legacy_user_id) {
+                (Ok(ids), Some(user_id)) => ids
+                    .split(',')
+                    .filter(|s| !s.trim().is_empty())
+                    .map(|s| s.trim().parse::<i64>().map(|id| (id, user_id.to_string())))
+                    .collect::<Result<HashMap<_, _>, _>>()
+                    .map_err(|_| "Invalid TELEGRAM_ALLOWED_USER_IDS format")?,
+                (Ok(_), None) => {
+                    return Err("TELEGRAM_ALLOWED_USER_IDS requires FIZZY_USER_ID; prefer TELEGRAM_USER_MAP".to_string());
+                }
+                (Err(_), _) => HashMap::new(),
+            },
+        };
 
         Ok(Self {
             bot_token,
-            allowed_user_ids,
+            user_map,
         })
     }
 
+    /// Telegram users configured to use the bot (derived from the user map)
+    pub fn allowed_user_ids(&self) -> Vec<i64> {
+        self.user_map.keys().copied().collect()
+    }
+
     pub fn is_user_allowed(&self, user_id: i64) -> bool {
-        self.allowed_user_ids.contains(&user_id)
+        self.user_map.contains_key(&user_id)
     }
 }
 
+/// Parse "telegram_id:fizzy_user_id" pairs separated by commas
+fn parse_user_map(value: &str) -> Result<HashMap<i64, String>, String> {
+    value
+        .split(',')
+        .filter(|entry| !entry.trim().is_empty())
+        .map(|entry| {
+            let (telegram_id, user_id) = entry
+                .split_once(':')
+                .ok_or_else(|| format!("Invalid TELEGRAM_USER_MAP entry '{}'", entry.trim()))?;
+            let telegram_id = telegram_id
+                .trim()
+                .parse::<i64>()
+                .map_err(|_| format!("Invalid Telegram user ID in TELEGRAM_USER_MAP: '{}'", telegram_id.trim()))?;
+            Ok((telegram_id, user_id.trim().to_string()))
+        })
+        .collect()
+}
+
 impl DatabaseConfig {
     fn from_env() -> Result<Self, String> {
+        let path = env::var("DATABASE_PATH")
+            .map_err(|_| "DATABASE_PATH not set")?;
+
+        // Default the bot's own database next to the Fizzy one
+        let state_path = env::var("BOT_DATABASE_PATH").unwrap_or_else(|_| {
+            Path::new(&path)
+                .with_file_name("sparkling.sqlite3")
+                .to_string_lossy()
+                .into_owned()
+        });
+
+        // Rails keeps uploaded files in the same storage directory as the database
+        let storage_path = env::var("FIZZY_STORAGE_PATH").unwrap_or_else(|_| {
+            Path::new(&path)
+                .parent()
+                .unwrap_or_else(|| Path::new("."))
+                .to_string_lossy()
+                .into_owned()
+        });
+
         Ok(Self {
-            path: env::var("DATABASE_PATH")
-                .map_err(|_| "DATABASE_PATH not set")?,
+            path,
             max_connections: env::var("DATABASE_MAX_CONNECTIONS")
                 .unwrap_or_else(|_| "5".to_string())
                 .parse()
                 .map_err(|_| "Invalid DATABASE_MAX_CONNECTIONS")?,
+            state_path,
+            storage_path,
+            storage_service: env::var("FIZZY_STORAGE_SERVICE").unwrap_or_else(|_| "local".to_string()),
         })
     }
 
     pub fn connection_string(&self) -> String {
         format!("sqlite://{}?mode=rwc", self.path)
     }
+
+    pub fn state_connection_string(&self) -> String {
+        format!("sqlite://{}?mode=rwc", self.state_path)
+    }
 }
 
 impl FizzyConfig {
@@ -82,11 +168,57 @@ impl FizzyConfig {
         Ok(Self {
             account_id: env::var("FIZZY_ACCOUNT_ID")
                 .map_err(|_| "FIZZY_ACCOUNT_ID not set")?,
-            user_id: env::var("FIZZY_USER_ID")
-                .map_err(|_| "FIZZY_USER_ID not set")?,
+            user_id: env::var("FIZZY_USER_ID").ok(),
             default_board_id: env::var("FIZZY_DEFAULT_BOARD_ID")
                 .map_err(|_| "FIZZY_DEFAULT_BOARD_ID not set")?,
             base_url: env::var("FIZZY_BASE_URL").ok(),
+            secret_key_base: env::var("FIZZY_SECRET_KEY_BASE").ok(),
+            timezone: env::var("FIZZY_TIMEZONE")
+                .unwrap_or_else(|_| "UTC".to_string())
+                .parse()
+                .map_err(|_| "Invalid FIZZY_TIMEZONE (expected an IANA name like Europe/Berlin)")?,
         })
     }
 }
+
+impl SchedulerConfig {
+    fn from_env() -> Result<Self, String> {
+        let reminder_interval_secs: u64 = env::var("REMINDER_INTERVAL_SECS")
+            .unwrap_or_else(|_| "300".to_string())
+            .parse()
+            .map_err(|_| "Invalid REMINDER_INTERVAL_SECS")?;
+
+        let event_poll_interval_secs: u64 = env::var("EVENT_POLL_INTERVAL_SECS")
+            .unwrap_or_else(|_| "30".to_string())
+            .parse()
+            .map_err(|_| "Invalid EVENT_POLL_INTERVAL_SECS")?;
+
+        if reminder_interval_secs == 0 || event_poll_interval_secs == 0 {
+            return Err("Scheduler intervals must be greater than 0".to_string());
+        }
+
+        Ok(Self {
+            reminder_interval: Duration::from_secs(reminder_interval_secs),
+            event_poll_interval: Duration::from_secs(event_poll_interval_secs),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_user_map() {
+        let map = parse_user_map("123:03f5vvmj8fk3aoqsk7yeyqzxm, 456:03f5vvmj8fk3aoqsk7yeyqzxn").unwrap();
+        assert_eq!(map.len(), 2);
+        assert_eq!(map[&123], "03f5vvmj8fk3aoqsk7yeyqzxm");
+        assert_eq!(map[&456], "03f5vvmj8fk3aoqsk7yeyqzxn");
+    }
+
+    #[test]
+    fn test_parse_user_map_rejects_bad_entries() {
+        assert!(parse_user_map("123").is_err());
+        assert!(parse_user_map("abc:03f5vvmj8fk3aoqsk7yeyqzxm").is_err());
+    }
+}
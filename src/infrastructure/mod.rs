@@ -1,3 +1,5 @@
 pub mod persistence;
+pub mod scheduler;
 pub mod telegram;
 pub mod config;
+pub mod text;
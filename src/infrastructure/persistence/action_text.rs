@@ -0,0 +1,140 @@
+//! ActionText's stored form for files embedded in rich text.
+//!
+//! The web app renders an embedded file from an `<action-text-attachment>`
+//! node whose `sgid` is a signed GlobalID of the ActiveStorage blob. The
+//! signature is an HMAC keyed from the app's `secret_key_base`, so a node the
+//! bot writes is only accepted if it was signed with the same secret.
+
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use sha1::Sha1;
+use sha2::Sha256;
+use crate::domain::value_objects::FizzyId;
+use crate::infrastructure::text::{escape_attribute, escape_html};
+
+/// GlobalID app name, derived by Rails from the application's module name
+const GLOBAL_ID_APP: &str = "fizzy";
+
+/// Salt Rails derives the `signed_global_ids` verifier key with
+const VERIFIER_SALT: &str = "signed_global_ids";
+
+/// `ActiveSupport::KeyGenerator` defaults
+const KEY_ITERATIONS: u32 = 1000;
+const KEY_LENGTH: usize = 64;
+
+/// Purpose ActionText signs attachable GlobalIDs for
+const ATTACHABLE_PURPOSE: &str = "attachable";
+
+/// Signs GlobalIDs the way the web app's `signed_global_ids` message verifier does
+#[derive(Clone)]
+pub struct GlobalIdSigner {
+    key: Vec<u8>,
+}
+
+impl GlobalIdSigner {
+    pub fn new(secret_key_base: &str) -> Self {
+        Self {
+            key: pbkdf2_sha256(secret_key_base.as_bytes(), VERIFIER_SALT.as_bytes(), KEY_ITERATIONS, KEY_LENGTH),
+        }
+    }
+
+    /// The blob's `attachable_sgid`: a never-expiring signed GlobalID for ActionText
+    pub fn attachable_sgid(&self, blob_id: &FizzyId) -> String {
+        let gid = format!("gid://{}/ActiveStorage::Blob/{}", GLOBAL_ID_APP, blob_id);
+        let message = serde_json::json!({
+            "_rails": { "data": gid, "pur": ATTACHABLE_PURPOSE }
+        });
+        let data = base64::engine::general_purpose::STANDARD.encode(message.to_string());
+
+        let mut mac = Hmac::<Sha1>::new_from_slice(&self.key).expect("HMAC accepts keys of any length");
+        mac.update(data.as_bytes());
+        let digest: String = mac.finalize().into_bytes().iter().map(|b| format!("{:02x}", b)).collect();
+
+        format!("{}--{}", data, digest)
+    }
+}
+
+/// The node embedding a blob in rich text. Without a signer the file is only
+/// mentioned by name, since the web app would reject an unsigned node.
+pub fn attachment_node(
+    signer: Option<&GlobalIdSigner>,
+    blob_id: &FizzyId,
+    filename: &str,
+    content_type: Option<&str>,
+    byte_size: i64,
+) -> String {
+    let Some(signer) = signer else {
+        return format!("<div>📎 {}</div>", escape_html(filename));
+    };
+
+    format!(
+        r#"<action-text-attachment sgid="{}" content-type="{}" filename="{}" filesize="{}"></action-text-attachment>"#,
+        escape_attribute(&signer.attachable_sgid(blob_id)),
+        escape_attribute(content_type.unwrap_or("application/octet-stream")),
+        escape_attribute(filename),
+        byte_size
+    )
+}
+
+/// PBKDF2-HMAC-SHA256, as `ActiveSupport::KeyGenerator` uses it
+fn pbkdf2_sha256(password: &[u8], salt: &[u8], iterations: u32, length: usize) -> Vec<u8> {
+    let prf = || Hmac::<Sha256>::new_from_slice(password).expect("HMAC accepts keys of any length");
+
+    let mut key = Vec::with_capacity(length);
+    let mut block: u32 = 1;
+    while key.len() < length {
+        let mut mac = prf();
+        mac.update(salt);
+        mac.update(&block.to_be_bytes());
+        let mut u = mac.finalize().into_bytes();
+        let mut t = u;
+
+        for _ in 1..iterations {
+            let mut mac = prf();
+            mac.update(&u);
+            u = mac.finalize().into_bytes();
+            t.iter_mut().zip(u.iter()).for_each(|(t, u)| *t ^= u);
+        }
+
+        key.extend_from_slice(&t);
+        block += 1;
+    }
+
+    key.truncate(length);
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pbkdf2_sha256() {
+        // RFC 7914, section 11
+        let key: String = pbkdf2_sha256(b"password", b"salt", 1, 32).iter().map(|b| format!("{:02x}", b)).collect();
+        assert_eq!(key, "120fb6cffcf8b32c43e7225256c4f837a86548c92ccc35480805987cb70be17b");
+    }
+
+    #[test]
+    fn test_attachable_sgid() {
+        let signer = GlobalIdSigner::new("secret");
+        let sgid = signer.attachable_sgid(&FizzyId::new("03f5vaqsa6p5y6dukhphs58ux".to_string()));
+
+        assert_eq!(
+            sgid,
+            "eyJfcmFpbHMiOnsiZGF0YSI6ImdpZDovL2Zpenp5L0FjdGl2ZVN0b3JhZ2U6OkJsb2IvMDNmNXZhcXNhNnA1eTZkdWtocGhzNTh1eCIsInB1ciI6ImF0dGFjaGFibGUifX0=--216e4ff1a7bc0127dc2fdccdb48d4b3443de462d"
+        );
+    }
+
+    #[test]
+    fn test_attachment_node() {
+        let signer = GlobalIdSigner::new("secret");
+        let blob_id = FizzyId::generate();
+
+        let node = attachment_node(Some(&signer), &blob_id, "R&D \"plan\".pdf", Some("application/pdf"), 2048);
+        assert!(node.starts_with("<action-text-attachment sgid=\""));
+        assert!(node.contains(r#"content-type="application/pdf" filename="R&amp;D &quot;plan&quot;.pdf" filesize="2048""#));
+
+        assert_eq!(attachment_node(None, &blob_id, "<x>.png", None, 1), "<div>📎 &lt;x&gt;.png</div>");
+    }
+}
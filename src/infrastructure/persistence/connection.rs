@@ -1,6 +1,7 @@
 use sqlx::sqlite::{SqlitePool, SqlitePoolOptions};
 use std::time::Duration;
 use crate::infrastructure::config::AppConfig;
+use crate::infrastructure::persistence::state_schema::STATE_SCHEMA;
 
 /// Create a SQLite connection pool from configuration
 pub async fn create_pool(config: &AppConfig) -> Result<SqlitePool, sqlx::Error> {
@@ -20,3 +21,22 @@ pub async fn create_pool(config: &AppConfig) -> Result<SqlitePool, sqlx::Error>
 
     Ok(pool)
 }
+
+/// Create the pool for the bot's own database and make sure its tables exist
+pub async fn create_state_pool(config: &AppConfig) -> Result<SqlitePool, sqlx::Error> {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .acquire_timeout(Duration::from_secs(10))
+        .connect(&config.database.state_connection_string())
+        .await?;
+
+    for statement in STATE_SCHEMA {
+        sqlx::query(statement)
+            .execute(&pool)
+            .await?;
+    }
+
+    tracing::info!("Bot state database ready at {}", config.database.state_path);
+
+    Ok(pool)
+}
@@ -1,13 +1,42 @@
+mod action_text;
 mod connection;
 mod id_generator;
+mod mysql_assignment_repo;
+mod mysql_attachment_repo;
 mod mysql_card_repo;
 mod mysql_board_repo;
 mod mysql_comment_repo;
 mod mysql_event_repo;
+mod mysql_search_repo;
+mod mysql_tag_repo;
+mod mysql_user_repo;
+mod state_card_message_repo;
+mod state_digest_schedule_repo;
+mod state_link_request_repo;
+mod state_reminder_repo;
+mod state_schema;
+mod state_subscription_repo;
+mod state_user_link_repo;
+mod state_user_settings_repo;
+#[cfg(test)]
+mod test_support;
 
-pub use connection::create_pool;
+pub use action_text::GlobalIdSigner;
+pub use connection::{create_pool, create_state_pool};
 pub use id_generator::FizzyIdGenerator;
+pub use mysql_assignment_repo::SqliteAssignmentRepository;
+pub use mysql_attachment_repo::SqliteAttachmentRepository;
 pub use mysql_card_repo::SqliteCardRepository;
 pub use mysql_board_repo::SqliteBoardRepository;
 pub use mysql_comment_repo::SqliteCommentRepository;
 pub use mysql_event_repo::SqliteEventRepository;
+pub use mysql_search_repo::SqliteSearchRepository;
+pub use mysql_tag_repo::SqliteTagRepository;
+pub use mysql_user_repo::SqliteUserRepository;
+pub use state_card_message_repo::SqliteCardMessageRepository;
+pub use state_digest_schedule_repo::SqliteDigestScheduleRepository;
+pub use state_link_request_repo::SqliteLinkRequestRepository;
+pub use state_reminder_repo::SqliteReminderRepository;
+pub use state_subscription_repo::SqliteSubscriptionRepository;
+pub use state_user_link_repo::SqliteUserLinkRepository;
+pub use state_user_settings_repo::SqliteUserSettingsRepository;
@@ -0,0 +1,140 @@
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+use crate::domain::ports::AssignmentRepository;
+use crate::domain::value_objects::FizzyId;
+use crate::domain::errors::DomainError;
+
+pub struct SqliteAssignmentRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteAssignmentRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AssignmentRepository for SqliteAssignmentRepository {
+    async fn list_assignee_ids(
+        &self,
+        account_id: &FizzyId,
+        card_id: &FizzyId,
+    ) -> Result<Vec<FizzyId>, DomainError> {
+        sqlx::query_scalar::<_, FizzyId>(
+            r#"
+            SELECT assignee_id
+            FROM assignments
+            WHERE account_id = ? AND card_id = ?
+            ORDER BY created_at ASC
+            "#,
+        )
+        .bind(account_id)
+        .bind(card_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))
+    }
+
+    async fn assign(
+        &self,
+        account_id: &FizzyId,
+        card_id: &FizzyId,
+        assignee_id: &FizzyId,
+        assigner_id: &FizzyId,
+    ) -> Result<bool, DomainError> {
+        let mut tx = self.pool.begin().await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        let existing: Option<FizzyId> = sqlx::query_scalar(
+            "SELECT id FROM assignments WHERE account_id = ? AND card_id = ? AND assignee_id = ?",
+        )
+        .bind(account_id)
+        .bind(card_id)
+        .bind(assignee_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        if existing.is_some() {
+            return Ok(false);
+        }
+
+        // 1. Insert assignment record
+        sqlx::query(
+            r#"
+            INSERT INTO assignments (id, account_id, card_id, assignee_id, assigner_id, created_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, datetime('now'), datetime('now'))
+            "#,
+        )
+        .bind(FizzyId::generate())
+        .bind(account_id)
+        .bind(card_id)
+        .bind(assignee_id)
+        .bind(assigner_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        // 2. Update card's last_active_at
+        touch_card(&mut tx, account_id, card_id).await?;
+
+        tx.commit().await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(true)
+    }
+
+    async fn unassign(
+        &self,
+        account_id: &FizzyId,
+        card_id: &FizzyId,
+        assignee_id: &FizzyId,
+    ) -> Result<bool, DomainError> {
+        let mut tx = self.pool.begin().await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        let result = sqlx::query(
+            "DELETE FROM assignments WHERE account_id = ? AND card_id = ? AND assignee_id = ?",
+        )
+        .bind(account_id)
+        .bind(card_id)
+        .bind(assignee_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Ok(false);
+        }
+
+        touch_card(&mut tx, account_id, card_id).await?;
+
+        tx.commit().await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(true)
+    }
+}
+
+/// Bump a card's activity timestamps inside a transaction
+async fn touch_card(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    account_id: &FizzyId,
+    card_id: &FizzyId,
+) -> Result<(), DomainError> {
+    sqlx::query(
+        r#"
+        UPDATE cards
+        SET last_active_at = datetime('now'), updated_at = datetime('now')
+        WHERE id = ? AND account_id = ?
+        "#,
+    )
+    .bind(card_id)
+    .bind(account_id)
+    .execute(&mut **tx)
+    .await
+    .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+    Ok(())
+}
@@ -0,0 +1,490 @@
+use async_trait::async_trait;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use md5::{Digest, Md5};
+use sqlx::{FromRow, SqlitePool};
+use std::path::PathBuf;
+use uuid::Uuid;
+use crate::domain::entities::Attachment;
+use crate::domain::ports::{AttachmentRepository, AttachmentTarget, NewAttachment};
+use crate::domain::value_objects::FizzyId;
+use crate::domain::errors::DomainError;
+use super::action_text::{self, GlobalIdSigner};
+use super::mysql_search_repo::{insert_record_query, search_table, search_text};
+
+/// Length of ActiveStorage blob keys (`SecureRandom.base36(28)` in Rails)
+const KEY_LENGTH: usize = 28;
+
+/// Stores files the way the Rails app's local Disk service does: blob and
+/// attachment rows in the database, the file itself under
+/// `<storage root>/<key[0..2]>/<key[2..4]>/<key>`
+pub struct SqliteAttachmentRepository {
+    pool: SqlitePool,
+    storage_root: PathBuf,
+    /// ActiveStorage service the files are recorded under
+    service_name: String,
+    /// Signs the embedded files' GlobalIDs; without it they are only mentioned by name
+    signer: Option<GlobalIdSigner>,
+}
+
+/// Raw row from the attachments/blobs join
+#[derive(Debug, FromRow)]
+struct AttachmentRow {
+    id: FizzyId,
+    account_id: FizzyId,
+    blob_id: FizzyId,
+    key: String,
+    filename: String,
+    content_type: Option<String>,
+    byte_size: i64,
+    created_at: DateTime<Utc>,
+    comment_id: Option<FizzyId>,
+}
+
+impl From<AttachmentRow> for Attachment {
+    fn from(row: AttachmentRow) -> Self {
+        Attachment {
+            id: row.id,
+            account_id: row.account_id,
+            blob_id: row.blob_id,
+            key: row.key,
+            filename: row.filename,
+            content_type: row.content_type,
+            byte_size: row.byte_size,
+            created_at: row.created_at,
+            comment_id: row.comment_id,
+        }
+    }
+}
+
+impl SqliteAttachmentRepository {
+    pub fn new(
+        pool: SqlitePool,
+        storage_root: impl Into<PathBuf>,
+        service_name: impl Into<String>,
+        signer: Option<GlobalIdSigner>,
+    ) -> Self {
+        Self { pool, storage_root: storage_root.into(), service_name: service_name.into(), signer }
+    }
+
+    /// Base query for loading attachments with their blobs
+    fn base_attachment_query() -> &'static str {
+        r#"
+        SELECT
+            a.id,
+            a.account_id,
+            a.blob_id,
+            b.key,
+            b.filename,
+            b.content_type,
+            b.byte_size,
+            a.created_at,
+            CASE WHEN rt.record_type = 'Comment' THEN rt.record_id END as comment_id
+        FROM active_storage_attachments a
+        JOIN active_storage_blobs b ON b.id = a.blob_id
+        LEFT JOIN action_text_rich_texts rt ON a.record_type = 'ActionText::RichText' AND rt.id = a.record_id
+        "#
+    }
+
+    /// Where the Disk service keeps the file for a blob key
+    fn path_for(&self, key: &str) -> PathBuf {
+        self.storage_root.join(&key[..2]).join(&key[2..4]).join(key)
+    }
+
+    /// Generate a random lowercase base36 blob key
+    fn generate_key() -> String {
+        let mut key = String::with_capacity(KEY_LENGTH);
+        while key.len() < KEY_LENGTH {
+            let mut n = Uuid::new_v4().as_u128();
+            for _ in 0..20 {
+                key.push(char::from_digit((n % 36) as u32, 36).unwrap_or('0'));
+                n /= 36;
+            }
+        }
+        key.truncate(KEY_LENGTH);
+        key
+    }
+
+    /// Write the blob and attachment rows and embed the file in the rich text,
+    /// creating (and indexing in `search_table`) the comment first for
+    /// [`AttachmentTarget::NewComment`]. Returns the blob, the attachment and
+    /// the comment the file went on, if any.
+    async fn insert_rows(
+        &self,
+        account_id: &FizzyId,
+        target: &AttachmentTarget,
+        file: &NewAttachment,
+        key: &str,
+        search_table: &str,
+    ) -> Result<(FizzyId, FizzyId, Option<FizzyId>), sqlx::Error> {
+        let blob_id = FizzyId::generate();
+        let attachment_id = FizzyId::generate();
+        let checksum = base64::engine::general_purpose::STANDARD.encode(Md5::digest(&file.data));
+        let byte_size = file.data.len() as i64;
+        let node = action_text::attachment_node(
+            self.signer.as_ref(),
+            &blob_id,
+            &file.filename,
+            file.content_type.as_deref(),
+            byte_size,
+        );
+
+        let mut tx = self.pool.begin().await?;
+
+        // 1. Find the rich text the file goes into, creating the comment or an
+        //    empty description if needed
+        let (card_id, comment_id, rich_text_id) = match target {
+            AttachmentTarget::CardDescription(card_id) => {
+                let existing = sqlx::query_scalar::<_, FizzyId>(
+                    r#"
+                    SELECT id FROM action_text_rich_texts
+                    WHERE account_id = ? AND record_type = 'Card' AND record_id = ? AND name = 'description'
+                    "#,
+                )
+                .bind(account_id)
+                .bind(card_id)
+                .fetch_optional(&mut *tx)
+                .await?;
+
+                let rich_text_id = match existing {
+                    Some(id) => id,
+                    None => {
+                        let id = FizzyId::generate();
+                        sqlx::query(
+                            r#"
+                            INSERT INTO action_text_rich_texts (
+                                id, account_id, record_type, record_id, name, body, created_at, updated_at
+                            )
+                            VALUES (?, ?, 'Card', ?, 'description', '', datetime('now'), datetime('now'))
+                            "#,
+                        )
+                        .bind(&id)
+                        .bind(account_id)
+                        .bind(card_id)
+                        .execute(&mut *tx)
+                        .await?;
+                        id
+                    }
+                };
+
+                (card_id, None, rich_text_id)
+            }
+            AttachmentTarget::NewComment { card_id, creator_id, body } => {
+                let comment_id = FizzyId::generate();
+                sqlx::query(
+                    r#"
+                    INSERT INTO comments (id, account_id, card_id, creator_id, created_at, updated_at)
+                    VALUES (?, ?, ?, ?, datetime('now'), datetime('now'))
+                    "#,
+                )
+                .bind(&comment_id)
+                .bind(account_id)
+                .bind(card_id)
+                .bind(creator_id)
+                .execute(&mut *tx)
+                .await?;
+
+                let rich_text_id = FizzyId::generate();
+                sqlx::query(
+                    r#"
+                    INSERT INTO action_text_rich_texts (
+                        id, account_id, record_type, record_id, name, body, created_at, updated_at
+                    )
+                    VALUES (?, ?, 'Comment', ?, 'body', ?, datetime('now'), datetime('now'))
+                    "#,
+                )
+                .bind(&rich_text_id)
+                .bind(account_id)
+                .bind(&comment_id)
+                .bind(body)
+                .execute(&mut *tx)
+                .await?;
+
+                sqlx::query(&insert_record_query(search_table))
+                    .bind(account_id)
+                    .bind("Comment")
+                    .bind(&comment_id)
+                    .bind(None::<String>)
+                    .bind(search_text(body))
+                    .bind(card_id)
+                    .execute(&mut *tx)
+                    .await?;
+
+                (card_id, Some(comment_id), rich_text_id)
+            }
+        };
+
+        // 2. Blob and attachment rows
+        sqlx::query(
+            r#"
+            INSERT INTO active_storage_blobs (
+                id, account_id, key, filename, content_type, metadata, service_name,
+                byte_size, checksum, created_at
+            )
+            VALUES (?, ?, ?, ?, ?, '{"identified":true}', ?, ?, ?, datetime('now'))
+            "#,
+        )
+        .bind(&blob_id)
+        .bind(account_id)
+        .bind(key)
+        .bind(&file.filename)
+        .bind(&file.content_type)
+        .bind(&self.service_name)
+        .bind(byte_size)
+        .bind(&checksum)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO active_storage_attachments (
+                id, account_id, name, record_type, record_id, blob_id, created_at
+            )
+            VALUES (?, ?, 'embeds', 'ActionText::RichText', ?, ?, datetime('now'))
+            "#,
+        )
+        .bind(&attachment_id)
+        .bind(account_id)
+        .bind(&rich_text_id)
+        .bind(&blob_id)
+        .execute(&mut *tx)
+        .await?;
+
+        // 3. Embed the file in the text so the web app shows it
+        sqlx::query(
+            r#"
+            UPDATE action_text_rich_texts
+            SET body = COALESCE(body, '') || ?, updated_at = datetime('now')
+            WHERE id = ?
+            "#,
+        )
+        .bind(&node)
+        .bind(&rich_text_id)
+        .execute(&mut *tx)
+        .await?;
+
+        // 4. Count the upload as activity on the card
+        sqlx::query(
+            r#"
+            UPDATE cards
+            SET last_active_at = datetime('now'), updated_at = datetime('now')
+            WHERE id = ? AND account_id = ?
+            "#,
+        )
+        .bind(card_id)
+        .bind(account_id)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok((blob_id, attachment_id, comment_id))
+    }
+}
+
+#[async_trait]
+impl AttachmentRepository for SqliteAttachmentRepository {
+    async fn create(
+        &self,
+        account_id: &FizzyId,
+        target: &AttachmentTarget,
+        file: NewAttachment,
+    ) -> Result<Attachment, DomainError> {
+        let search_table = search_table(&self.pool, account_id).await?;
+
+        // Write the file first, outside the transaction; nothing points to it until the rows commit
+        let key = Self::generate_key();
+        let path = self.path_for(&key);
+        if let Some(dir) = path.parent() {
+            tokio::fs::create_dir_all(dir).await
+                .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+        }
+        tokio::fs::write(&path, &file.data).await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        let result = self
+            .insert_rows(account_id, target, &file, &key, &search_table)
+            .await;
+
+        let (blob_id, attachment_id, comment_id) = match result {
+            Ok(ids) => ids,
+            Err(e) => {
+                // Don't leave a file behind that no blob points to
+                let _ = tokio::fs::remove_file(&path).await;
+                return Err(DomainError::InfrastructureError(e.to_string()));
+            }
+        };
+
+        Ok(Attachment {
+            id: attachment_id,
+            account_id: account_id.clone(),
+            blob_id,
+            key,
+            filename: file.filename,
+            byte_size: file.data.len() as i64,
+            content_type: file.content_type,
+            created_at: Utc::now(),
+            comment_id,
+        })
+    }
+
+    async fn list_for_card(
+        &self,
+        account_id: &FizzyId,
+        card_id: &FizzyId,
+    ) -> Result<Vec<Attachment>, DomainError> {
+        let query = format!(
+            r#"{}
+            WHERE a.account_id = ?
+              AND a.record_type = 'ActionText::RichText'
+              AND a.name = 'embeds'
+              AND (
+                (rt.record_type = 'Card' AND rt.record_id = ?)
+                OR (rt.record_type = 'Comment' AND rt.record_id IN (SELECT id FROM comments WHERE card_id = ?))
+              )
+            ORDER BY a.created_at ASC, a.id ASC
+            "#,
+            Self::base_attachment_query()
+        );
+
+        let rows = sqlx::query_as::<_, AttachmentRow>(&query)
+            .bind(account_id)
+            .bind(card_id)
+            .bind(card_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(Attachment::from).collect())
+    }
+
+    async fn find_by_id(
+        &self,
+        account_id: &FizzyId,
+        id: &FizzyId,
+    ) -> Result<Option<Attachment>, DomainError> {
+        let query = format!(
+            "{} WHERE a.account_id = ? AND a.id = ?",
+            Self::base_attachment_query()
+        );
+
+        let row = sqlx::query_as::<_, AttachmentRow>(&query)
+            .bind(account_id)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(row.map(Attachment::from))
+    }
+
+    async fn read(&self, attachment: &Attachment) -> Result<Vec<u8>, DomainError> {
+        if attachment.key.len() < 4 {
+            return Err(DomainError::InvalidState {
+                message: format!("Invalid blob key: {}", attachment.key),
+            });
+        }
+
+        tokio::fs::read(self.path_for(&attachment.key)).await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::persistence::test_support::{fizzy_pool, seed_board};
+
+    fn storage_root() -> PathBuf {
+        std::env::temp_dir().join(format!("sparkling-storage-{}", FizzyId::generate()))
+    }
+
+    fn file() -> NewAttachment {
+        NewAttachment {
+            filename: "plan.pdf".to_string(),
+            content_type: Some("application/pdf".to_string()),
+            data: b"%PDF-1.7".to_vec(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_on_new_comment() {
+        let pool = fizzy_pool().await;
+        let seed = seed_board(&pool).await;
+        let root = storage_root();
+        let repo = SqliteAttachmentRepository::new(pool.clone(), &root, "disk", Some(GlobalIdSigner::new("secret")));
+        let card_id = FizzyId::generate();
+        sqlx::query("INSERT INTO cards (id, account_id, board_id, creator_id, number, title) VALUES (?, ?, ?, ?, 1, 'Card')")
+            .bind(&card_id).bind(&seed.account_id).bind(&seed.board_id).bind(&seed.user_id)
+            .execute(&pool).await.unwrap();
+
+        let target = AttachmentTarget::NewComment {
+            card_id: card_id.clone(),
+            creator_id: seed.user_id.clone(),
+            body: "<div>Signed copy</div>".to_string(),
+        };
+        let attachment = repo.create(&seed.account_id, &target, file()).await.unwrap();
+
+        let comment_id = attachment.comment_id.clone().unwrap();
+        let body: String = sqlx::query_scalar("SELECT body FROM action_text_rich_texts WHERE record_type = 'Comment' AND record_id = ?")
+            .bind(&comment_id)
+            .fetch_one(&pool).await.unwrap();
+        assert!(body.starts_with("<div>Signed copy</div><action-text-attachment sgid=\""));
+
+        let indexed: String = sqlx::query_scalar("SELECT content FROM search_records WHERE searchable_type = 'Comment' AND searchable_id = ?")
+            .bind(&comment_id)
+            .fetch_one(&pool).await.unwrap();
+        assert_eq!(indexed, "Signed copy");
+
+        let service: String = sqlx::query_scalar("SELECT service_name FROM active_storage_blobs WHERE id = ?")
+            .bind(&attachment.blob_id)
+            .fetch_one(&pool).await.unwrap();
+        assert_eq!(service, "disk");
+
+        let listed = repo.list_for_card(&seed.account_id, &card_id).await.unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].comment_id.as_ref(), Some(&comment_id));
+        assert_eq!(repo.read(&listed[0]).await.unwrap(), b"%PDF-1.7");
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[tokio::test]
+    async fn test_failed_create_leaves_nothing_behind() {
+        let pool = fizzy_pool().await;
+        let seed = seed_board(&pool).await;
+        let root = storage_root();
+        let repo = SqliteAttachmentRepository::new(pool.clone(), &root, "local", None);
+
+        sqlx::query("DROP TABLE active_storage_attachments").execute(&pool).await.unwrap();
+
+        let target = AttachmentTarget::NewComment {
+            card_id: FizzyId::generate(),
+            creator_id: seed.user_id.clone(),
+            body: "<div>Signed copy</div>".to_string(),
+        };
+        assert!(repo.create(&seed.account_id, &target, file()).await.is_err());
+
+        let comments: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM comments").fetch_one(&pool).await.unwrap();
+        assert_eq!(comments, 0);
+
+        let files = walk(&root);
+        assert!(files.is_empty(), "left behind: {:?}", files);
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    /// Files under a directory, recursively
+    fn walk(dir: &std::path::Path) -> Vec<PathBuf> {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return Vec::new();
+        };
+        entries
+            .flatten()
+            .flat_map(|entry| {
+                let path = entry.path();
+                if path.is_dir() { walk(&path) } else { vec![path] }
+            })
+            .collect()
+    }
+}
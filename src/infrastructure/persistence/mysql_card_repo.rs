@@ -4,7 +4,27 @@ use crate::domain::ports::{CardFilters, CardRepository, CreateCardInput, UpdateC
 use crate::domain::value_objects::{CardStatus, FizzyId};
 use async_trait::async_trait;
 use chrono::{DateTime, NaiveDate, Utc};
-use sqlx::{FromRow, SqlitePool};
+use std::collections::HashMap;
+use sqlx::query::QueryAs;
+use sqlx::sqlite::SqliteArguments;
+use sqlx::{FromRow, Sqlite, SqlitePool};
+use super::mysql_search_repo::{escape_like, insert_record_query, search_table, search_text};
+
+/// Assignee names of several cards, for `load_names_by_card`
+const ASSIGNEE_NAMES_QUERY: &str = r#"
+    SELECT a.card_id, u.name
+    FROM assignments a
+    JOIN users u ON a.assignee_id = u.id
+    WHERE a.account_id = ? AND a.card_id IN ({})
+"#;
+
+/// Tag titles of several cards, for `load_names_by_card`
+const TAG_TITLES_QUERY: &str = r#"
+    SELECT tg.card_id, t.title
+    FROM taggings tg
+    JOIN tags t ON tg.tag_id = t.id
+    WHERE tg.account_id = ? AND tg.card_id IN ({})
+"#;
 
 pub struct SqliteCardRepository {
     pool: SqlitePool,
@@ -53,7 +73,7 @@ impl CardRow {
             creator_id: self.creator_id,
             number: self.number,
             title: self.title,
-            description: None, // Rich text loaded separately if needed
+            description: None, // Rich text loaded separately by find_by_number/find_by_id
             status,
             due_on: self.due_on,
             last_active_at: self.last_active_at,
@@ -121,6 +141,61 @@ impl SqliteCardRepository {
         Ok(rows)
     }
 
+    /// Run a `(card_id, name)` query for several cards at once, grouping the
+    /// names by card. The query's `{}` is replaced by the card ID placeholders.
+    async fn load_names_by_card(
+        &self,
+        query: &str,
+        account_id: &FizzyId,
+        card_ids: &[&FizzyId],
+    ) -> Result<HashMap<FizzyId, Vec<String>>, DomainError> {
+        let mut names: HashMap<FizzyId, Vec<String>> = HashMap::new();
+        if card_ids.is_empty() {
+            return Ok(names);
+        }
+
+        let placeholders = card_ids.iter().map(|_| "?").collect::<Vec<_>>().join(", ");
+        let query = query.replace("{}", &placeholders);
+
+        let mut query_builder = sqlx::query_as::<_, (FizzyId, String)>(&query).bind(account_id);
+        for card_id in card_ids {
+            query_builder = query_builder.bind(*card_id);
+        }
+
+        let rows = query_builder
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        for (card_id, name) in rows {
+            names.entry(card_id).or_default().push(name);
+        }
+
+        Ok(names)
+    }
+
+    /// Load the description rich text for a card
+    async fn load_description(
+        &self,
+        account_id: &FizzyId,
+        card_id: &FizzyId,
+    ) -> Result<Option<String>, DomainError> {
+        let body = sqlx::query_scalar::<_, Option<String>>(
+            r#"
+            SELECT body
+            FROM action_text_rich_texts
+            WHERE account_id = ? AND record_type = 'Card' AND record_id = ? AND name = 'description'
+            "#,
+        )
+        .bind(account_id)
+        .bind(card_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(body.flatten().filter(|body| !body.trim().is_empty()))
+    }
+
     /// Base query for loading cards with all JOINs
     fn base_card_query() -> &'static str {
         r#"
@@ -174,7 +249,10 @@ impl CardRepository for SqliteCardRepository {
             Some(card_row) => {
                 let assignees = self.load_assignees(account_id, &card_row.id).await?;
                 let tags = self.load_tags(account_id, &card_row.id).await?;
-                Ok(Some(card_row.into_card(assignees, tags)?))
+                let description = self.load_description(account_id, &card_row.id).await?;
+                let mut card = card_row.into_card(assignees, tags)?;
+                card.description = description;
+                Ok(Some(card))
             }
             None => Ok(None),
         }
@@ -201,7 +279,10 @@ impl CardRepository for SqliteCardRepository {
             Some(card_row) => {
                 let assignees = self.load_assignees(account_id, &card_row.id).await?;
                 let tags = self.load_tags(account_id, &card_row.id).await?;
-                Ok(Some(card_row.into_card(assignees, tags)?))
+                let description = self.load_description(account_id, &card_row.id).await?;
+                let mut card = card_row.into_card(assignees, tags)?;
+                card.description = description;
+                Ok(Some(card))
             }
             None => Ok(None),
         }
@@ -212,90 +293,39 @@ impl CardRepository for SqliteCardRepository {
         account_id: &FizzyId,
         filters: CardFilters,
     ) -> Result<Vec<Card>, DomainError> {
-        let mut query = format!("{} WHERE c.account_id = ?", Self::base_card_query());
-        let mut bindings: Vec<String> = vec![account_id.to_string()];
-
-        // Add assignee filter (requires JOIN)
-        if let Some(ref assignee_id) = filters.assignee_id {
-            query.push_str(" AND EXISTS (SELECT 1 FROM assignments a WHERE a.card_id = c.id AND a.assignee_id = ?)");
-            bindings.push(assignee_id.to_string());
-        }
-
-        // Add board filter
-        if let Some(ref board_id) = filters.board_id {
-            query.push_str(" AND c.board_id = ?");
-            bindings.push(board_id.to_string());
-        }
-
-        // Add column filter
-        if let Some(ref column_id) = filters.column_id {
-            query.push_str(" AND c.column_id = ?");
-            bindings.push(column_id.to_string());
-        }
-
-        // Add status inclusion filter
-        if let Some(ref statuses) = filters.status {
-            if !statuses.is_empty() {
-                let placeholders = statuses.iter().map(|_| "?").collect::<Vec<_>>().join(", ");
-                query.push_str(&format!(" AND c.status IN ({})", placeholders));
-                for status in statuses {
-                    bindings.push(status.as_str().to_string());
-                }
-            }
-        }
-
-        // Add status exclusion filter
-        if let Some(ref exclude_statuses) = filters.exclude_status {
-            if !exclude_statuses.is_empty() {
-                let placeholders = exclude_statuses
-                    .iter()
-                    .map(|_| "?")
-                    .collect::<Vec<_>>()
-                    .join(", ");
-                query.push_str(&format!(" AND c.status NOT IN ({})", placeholders));
-                for status in exclude_statuses {
-                    bindings.push(status.as_str().to_string());
-                }
-            }
-        }
-
-        // Add golden filter
-        if let Some(is_golden) = filters.is_golden {
-            if is_golden {
-                query.push_str(" AND cg.id IS NOT NULL");
-            } else {
-                query.push_str(" AND cg.id IS NULL");
-            }
-        }
-
-        // Order by last_active_at descending
-        query.push_str(" ORDER BY c.last_active_at DESC");
-
-        // Add limit
-        if let Some(limit) = filters.limit {
-            query.push_str(&format!(" LIMIT {}", limit));
-        }
-
-        // Add offset
-        if let Some(offset) = filters.offset {
-            query.push_str(&format!(" OFFSET {}", offset));
-        }
+        let rows = self.execute_list_query(account_id, &filters).await?;
+
+        // Load assignees and tags for all the cards at once
+        let card_ids: Vec<&FizzyId> = rows.iter().map(|row| &row.id).collect();
+        let mut assignees = self.load_names_by_card(ASSIGNEE_NAMES_QUERY, account_id, &card_ids).await?;
+        let mut tags = self.load_names_by_card(TAG_TITLES_QUERY, account_id, &card_ids).await?;
+
+        rows.into_iter()
+            .map(|row| {
+                let card_assignees = assignees.remove(&row.id).unwrap_or_default();
+                let card_tags = tags.remove(&row.id).unwrap_or_default();
+                row.into_card(card_assignees, card_tags)
+            })
+            .collect()
+    }
 
-        // Build the query with dynamic bindings
-        // SQLx doesn't support dynamic binding easily, so we rebuild with proper types
-        let rows = self
-            .execute_list_query(&query, account_id, &filters)
-            .await?;
+    async fn count(&self, account_id: &FizzyId, filters: CardFilters) -> Result<i64, DomainError> {
+        let query = format!(
+            r#"
+            SELECT COUNT(*)
+            FROM cards c
+            LEFT JOIN card_goldnesses cg ON c.id = cg.card_id
+            WHERE {}
+            "#,
+            Self::filter_conditions(&filters)
+        );
 
-        // Load assignees and tags for each card
-        let mut cards = Vec::with_capacity(rows.len());
-        for row in rows {
-            let assignees = self.load_assignees(account_id, &row.id).await?;
-            let tags = self.load_tags(account_id, &row.id).await?;
-            cards.push(row.into_card(assignees, tags)?);
-        }
+        let (count,) = Self::bind_filters(sqlx::query_as::<_, (i64,)>(&query), account_id, &filters)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
 
-        Ok(cards)
+        Ok(count)
     }
 
     async fn create(
@@ -304,6 +334,7 @@ impl CardRepository for SqliteCardRepository {
         input: CreateCardInput,
     ) -> Result<Card, DomainError> {
         let card_id = FizzyId::generate();
+        let search_table = search_table(&self.pool, account_id).await?;
 
         // Start a transaction
         let mut tx = self.pool.begin().await
@@ -364,6 +395,18 @@ impl CardRepository for SqliteCardRepository {
             .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
         }
 
+        // 4. Index it so /search finds it
+        sqlx::query(&insert_record_query(&search_table))
+            .bind(account_id)
+            .bind("Card")
+            .bind(&card_id)
+            .bind(&input.title)
+            .bind(input.description.as_deref().map(search_text))
+            .bind(&card_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
         // Commit transaction
         tx.commit().await
             .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
@@ -376,12 +419,88 @@ impl CardRepository for SqliteCardRepository {
 
     async fn update(
         &self,
-        _account_id: &FizzyId,
-        _card_id: &FizzyId,
-        _input: UpdateCardInput,
+        account_id: &FizzyId,
+        card_id: &FizzyId,
+        input: UpdateCardInput,
     ) -> Result<Card, DomainError> {
-        // TODO: Implement in Phase 3
-        todo!("update implementation")
+        // Start a transaction
+        let mut tx = self.pool.begin().await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        // 1. Update card columns, keeping current values for fields not provided
+        let result = sqlx::query(
+            r#"
+            UPDATE cards
+            SET title = COALESCE(?, title),
+                status = COALESCE(?, status),
+                column_id = COALESCE(?, column_id),
+                due_on = CASE WHEN ? THEN ? ELSE due_on END,
+                last_active_at = datetime('now'),
+                updated_at = datetime('now')
+            WHERE id = ? AND account_id = ?
+            "#,
+        )
+        .bind(&input.title)
+        .bind(input.status.map(|s| s.as_str()))
+        .bind(&input.column_id)
+        .bind(input.due_on.is_some())
+        .bind(input.due_on.flatten())
+        .bind(card_id)
+        .bind(account_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::NotFound {
+                entity: "Card".to_string(),
+                id: card_id.to_string(),
+            });
+        }
+
+        // 2. Update the description, inserting the rich text row if it doesn't exist yet
+        if let Some(ref description) = input.description {
+            let updated = sqlx::query(
+                r#"
+                UPDATE action_text_rich_texts
+                SET body = ?, updated_at = datetime('now')
+                WHERE account_id = ? AND record_type = 'Card' AND record_id = ? AND name = 'description'
+                "#,
+            )
+            .bind(description)
+            .bind(account_id)
+            .bind(card_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+            if updated.rows_affected() == 0 {
+                let rich_text_id = FizzyId::generate();
+                sqlx::query(
+                    r#"
+                    INSERT INTO action_text_rich_texts (
+                        id, account_id, record_type, record_id, name, body, created_at, updated_at
+                    )
+                    VALUES (?, ?, 'Card', ?, 'description', ?, datetime('now'), datetime('now'))
+                    "#,
+                )
+                .bind(&rich_text_id)
+                .bind(account_id)
+                .bind(card_id)
+                .bind(description)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+            }
+        }
+
+        tx.commit().await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        // Fetch and return the updated card
+        self.find_by_id(account_id, card_id)
+            .await?
+            .ok_or_else(|| DomainError::InfrastructureError("Failed to fetch updated card".to_string()))
     }
 
     async fn close(
@@ -468,18 +587,172 @@ impl CardRepository for SqliteCardRepository {
 
         Ok(())
     }
+
+    async fn postpone(
+        &self,
+        account_id: &FizzyId,
+        card_id: &FizzyId,
+        user_id: &FizzyId,
+    ) -> Result<(), DomainError> {
+        let not_now_id = FizzyId::generate();
+
+        // Start a transaction
+        let mut tx = self.pool.begin().await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        // 1. Reopen the card if it was closed
+        sqlx::query("DELETE FROM closures WHERE card_id = ? AND account_id = ?")
+            .bind(card_id)
+            .bind(account_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        // 2. Insert not-now record unless the card is already postponed
+        sqlx::query(
+            r#"
+            INSERT INTO card_not_nows (id, account_id, card_id, user_id, created_at, updated_at)
+            SELECT ?, ?, ?, ?, datetime('now'), datetime('now')
+            WHERE NOT EXISTS (SELECT 1 FROM card_not_nows WHERE card_id = ?)
+            "#,
+        )
+        .bind(&not_now_id)
+        .bind(account_id)
+        .bind(card_id)
+        .bind(user_id)
+        .bind(card_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        // 3. Update card status and send it back to triage
+        sqlx::query(
+            r#"
+            UPDATE cards
+            SET status = 'not_now', column_id = NULL,
+                last_active_at = datetime('now'), updated_at = datetime('now')
+            WHERE id = ? AND account_id = ?
+            "#,
+        )
+        .bind(card_id)
+        .bind(account_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        tx.commit().await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn resume(&self, account_id: &FizzyId, card_id: &FizzyId) -> Result<(), DomainError> {
+        // Start a transaction
+        let mut tx = self.pool.begin().await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        // 1. Delete not-now record
+        sqlx::query("DELETE FROM card_not_nows WHERE card_id = ? AND account_id = ?")
+            .bind(card_id)
+            .bind(account_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        // 2. Update card status back to published
+        sqlx::query(
+            r#"
+            UPDATE cards
+            SET status = 'published', last_active_at = datetime('now'), updated_at = datetime('now')
+            WHERE id = ? AND account_id = ?
+            "#,
+        )
+        .bind(card_id)
+        .bind(account_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        tx.commit().await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn set_golden(
+        &self,
+        account_id: &FizzyId,
+        card_id: &FizzyId,
+        golden: bool,
+    ) -> Result<bool, DomainError> {
+        let result = if golden {
+            sqlx::query(
+                r#"
+                INSERT INTO card_goldnesses (id, account_id, card_id, created_at, updated_at)
+                SELECT ?, ?, ?, datetime('now'), datetime('now')
+                WHERE NOT EXISTS (SELECT 1 FROM card_goldnesses WHERE card_id = ?)
+                "#,
+            )
+            .bind(FizzyId::generate())
+            .bind(account_id)
+            .bind(card_id)
+            .bind(card_id)
+            .execute(&self.pool)
+            .await
+        } else {
+            sqlx::query("DELETE FROM card_goldnesses WHERE card_id = ? AND account_id = ?")
+                .bind(card_id)
+                .bind(account_id)
+                .execute(&self.pool)
+                .await
+        }
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(result.rows_affected() > 0)
+    }
 }
 
 impl SqliteCardRepository {
-    /// Execute list query with proper bindings
-    /// This is separated because SQLx requires compile-time known bindings
+    /// Run the list query for the given filters, binding their values in order
     async fn execute_list_query(
         &self,
-        _query_template: &str,
         account_id: &FizzyId,
         filters: &CardFilters,
     ) -> Result<Vec<CardRow>, DomainError> {
-        // Build dynamic query with all conditions
+        // Walking back from a cursor needs the closest cards first; they are
+        // put back in the usual order below
+        let direction = if filters.before.is_some() { "ASC" } else { "DESC" };
+
+        let mut query = format!(
+            "{} WHERE {} ORDER BY c.last_active_at {direction}, c.number {direction}",
+            Self::base_card_query(),
+            Self::filter_conditions(filters),
+        );
+
+        if let Some(limit) = filters.limit {
+            query.push_str(&format!(" LIMIT {}", limit));
+        }
+
+        if let Some(offset) = filters.offset {
+            query.push_str(&format!(" OFFSET {}", offset));
+        }
+
+        let query_builder = Self::bind_filters(sqlx::query_as::<_, CardRow>(&query), account_id, filters);
+
+        let mut rows = query_builder
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        if filters.before.is_some() {
+            rows.reverse();
+        }
+
+        Ok(rows)
+    }
+
+    /// Build the WHERE clause for the given filters; `bind_filters` binds its values in the same order
+    fn filter_conditions(filters: &CardFilters) -> String {
         let mut conditions = vec!["c.account_id = ?".to_string()];
 
         if filters.assignee_id.is_some() {
@@ -493,38 +766,65 @@ impl SqliteCardRepository {
             conditions.push("c.creator_id = ?".to_string());
         }
 
-        if let Some(exclude_closed) = filters.exclude_closed {
-            if exclude_closed {
-                conditions.push(
-                    "NOT EXISTS (SELECT 1 FROM closures cl WHERE cl.card_id = c.id)".to_string(),
-                );
-            }
+        if let Some(exclude_closed) = filters.exclude_closed
+            && exclude_closed
+        {
+            conditions.push(
+                "NOT EXISTS (SELECT 1 FROM closures cl WHERE cl.card_id = c.id)".to_string(),
+            );
+        }
+
+        if let Some(true) = filters.only_closed {
+            conditions.push(
+                "EXISTS (SELECT 1 FROM closures cl WHERE cl.card_id = c.id)".to_string(),
+            );
+        }
+
+        if let Some(true) = filters.exclude_not_now {
+            conditions.push(
+                "NOT EXISTS (SELECT 1 FROM card_not_nows nn WHERE nn.card_id = c.id)".to_string(),
+            );
+        }
+
+        if let Some(true) = filters.only_not_now {
+            conditions.push(
+                "EXISTS (SELECT 1 FROM card_not_nows nn WHERE nn.card_id = c.id)".to_string(),
+            );
         }
 
         if filters.board_id.is_some() {
             conditions.push("c.board_id = ?".to_string());
         }
 
+        if let Some(ref board_ids) = filters.board_ids {
+            if board_ids.is_empty() {
+                conditions.push("0".to_string());
+            } else {
+                let placeholders = board_ids.iter().map(|_| "?").collect::<Vec<_>>().join(", ");
+                conditions.push(format!("c.board_id IN ({})", placeholders));
+            }
+        }
+
         if filters.column_id.is_some() {
             conditions.push("c.column_id = ?".to_string());
         }
 
-        if let Some(ref statuses) = filters.status {
-            if !statuses.is_empty() {
-                let placeholders = statuses.iter().map(|_| "?").collect::<Vec<_>>().join(", ");
-                conditions.push(format!("c.status IN ({})", placeholders));
-            }
+        if let Some(ref statuses) = filters.status
+            && !statuses.is_empty()
+        {
+            let placeholders = statuses.iter().map(|_| "?").collect::<Vec<_>>().join(", ");
+            conditions.push(format!("c.status IN ({})", placeholders));
         }
 
-        if let Some(ref exclude_statuses) = filters.exclude_status {
-            if !exclude_statuses.is_empty() {
-                let placeholders = exclude_statuses
-                    .iter()
-                    .map(|_| "?")
-                    .collect::<Vec<_>>()
-                    .join(", ");
-                conditions.push(format!("c.status NOT IN ({})", placeholders));
-            }
+        if let Some(ref exclude_statuses) = filters.exclude_status
+            && !exclude_statuses.is_empty()
+        {
+            let placeholders = exclude_statuses
+                .iter()
+                .map(|_| "?")
+                .collect::<Vec<_>>()
+                .join(", ");
+            conditions.push(format!("c.status NOT IN ({})", placeholders));
         }
 
         if let Some(is_golden) = filters.is_golden {
@@ -535,49 +835,43 @@ impl SqliteCardRepository {
             }
         }
 
-        let where_clause = conditions.join(" AND ");
-        let mut query = format!(
-            r#"
-            SELECT
-                c.id,
-                c.account_id,
-                c.board_id,
-                c.column_id,
-                c.creator_id,
-                c.number,
-                c.title,
-                c.status,
-                c.due_on,
-                c.last_active_at,
-                c.created_at,
-                c.updated_at,
-                b.name as board_name,
-                col.name as column_name,
-                col.color as column_color,
-                u.name as creator_name,
-                CASE WHEN cg.id IS NOT NULL THEN true ELSE false END as is_golden
-            FROM cards c
-            JOIN boards b ON c.board_id = b.id
-            LEFT JOIN columns col ON c.column_id = col.id
-            JOIN users u ON c.creator_id = u.id
-            LEFT JOIN card_goldnesses cg ON c.id = cg.card_id
-            WHERE {}
-            ORDER BY c.last_active_at DESC
-            "#,
-            where_clause
-        );
+        if let Some(true) = filters.awaiting_triage {
+            conditions.push("c.column_id IS NULL".to_string());
+        }
 
-        if let Some(limit) = filters.limit {
-            query.push_str(&format!(" LIMIT {}", limit));
+        if filters.due_on_or_before.is_some() {
+            conditions.push("c.due_on IS NOT NULL AND c.due_on <= ?".to_string());
         }
 
-        if let Some(offset) = filters.offset {
-            query.push_str(&format!(" OFFSET {}", offset));
+        if filters.title_prefix.is_some() {
+            conditions.push("c.title LIKE ? ESCAPE '\\'".to_string());
+        }
+
+        if filters.title_word_prefix.is_some() {
+            conditions.push("c.title LIKE ? ESCAPE '\\'".to_string());
+        }
+
+        if filters.after.is_some() {
+            conditions.push(
+                "(c.last_active_at < ? OR (c.last_active_at = ? AND c.number < ?))".to_string(),
+            );
+        }
+
+        if filters.before.is_some() {
+            conditions.push(
+                "(c.last_active_at > ? OR (c.last_active_at = ? AND c.number > ?))".to_string(),
+            );
         }
 
-        // Now bind values in order
-        let mut query_builder = sqlx::query_as::<_, CardRow>(&query);
+        conditions.join(" AND ")
+    }
 
+    /// Bind the values for the WHERE clause built by `filter_conditions`
+    fn bind_filters<'q, O>(
+        mut query_builder: QueryAs<'q, Sqlite, O, SqliteArguments<'q>>,
+        account_id: &'q FizzyId,
+        filters: &'q CardFilters,
+    ) -> QueryAs<'q, Sqlite, O, SqliteArguments<'q>> {
         // Always bind account_id first
         query_builder = query_builder.bind(account_id);
 
@@ -594,6 +888,10 @@ impl SqliteCardRepository {
             query_builder = query_builder.bind(board_id);
         }
 
+        for board_id in filters.board_ids.iter().flatten() {
+            query_builder = query_builder.bind(board_id);
+        }
+
         if let Some(ref column_id) = filters.column_id {
             query_builder = query_builder.bind(column_id);
         }
@@ -610,11 +908,292 @@ impl SqliteCardRepository {
             }
         }
 
-        let rows = query_builder
-            .fetch_all(&self.pool)
+        if let Some(due_on) = filters.due_on_or_before {
+            query_builder = query_builder.bind(due_on);
+        }
+
+        if let Some(ref prefix) = filters.title_prefix {
+            query_builder = query_builder.bind(format!("{}%", escape_like(prefix)));
+        }
+
+        if let Some(ref prefix) = filters.title_word_prefix {
+            query_builder = query_builder.bind(format!("% {}%", escape_like(prefix)));
+        }
+
+        for cursor in [&filters.after, &filters.before].into_iter().flatten() {
+            let last_active_at = db_timestamp(cursor.last_active_at);
+            query_builder = query_builder
+                .bind(last_active_at.clone())
+                .bind(last_active_at)
+                .bind(cursor.number);
+        }
+
+        query_builder
+    }
+}
+
+/// Format a timestamp the way it is stored, so cursors compare equal to the
+/// row they came from: Rails and SQLite's `datetime('now')` both write
+/// "YYYY-MM-DD HH:MM:SS", adding microseconds only when there are any
+fn db_timestamp(at: DateTime<Utc>) -> String {
+    if at.timestamp_subsec_micros() == 0 {
+        at.format("%Y-%m-%d %H:%M:%S").to_string()
+    } else {
+        at.format("%Y-%m-%d %H:%M:%S%.6f").to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::persistence::test_support::{fizzy_pool, seed_board};
+
+    #[tokio::test]
+    async fn test_update() {
+        let pool = fizzy_pool().await;
+        let seed = seed_board(&pool).await;
+        let repo = SqliteCardRepository::new(pool.clone());
+
+        let card = repo
+            .create(&seed.account_id, CreateCardInput {
+                board_id: seed.board_id.clone(),
+                creator_id: seed.user_id.clone(),
+                title: "Draft".to_string(),
+                description: None,
+                status: CardStatus::Published,
+                column_id: None,
+            })
             .await
-            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+            .unwrap();
+        let due = NaiveDate::from_ymd_opt(2026, 11, 2).unwrap();
+
+        // Every field at once, inserting the missing description row
+        let updated = repo
+            .update(&seed.account_id, &card.id, UpdateCardInput {
+                title: Some("Final".to_string()),
+                description: Some("<div>First</div>".to_string()),
+                status: Some(CardStatus::Drafted),
+                column_id: Some(seed.column_id.clone()),
+                due_on: Some(Some(due)),
+            })
+            .await
+            .unwrap();
+        assert_eq!(updated.title, "Final");
+        assert_eq!(updated.description.as_deref(), Some("<div>First</div>"));
+        assert_eq!(updated.status, CardStatus::Drafted);
+        assert_eq!(updated.column_name.as_deref(), Some("Doing"));
+        assert_eq!(updated.due_on, Some(due));
+
+        // Omitted fields keep their values; the description row is updated in place
+        let updated = repo
+            .update(&seed.account_id, &card.id, UpdateCardInput {
+                description: Some("<div>Second</div>".to_string()),
+                due_on: Some(None),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(updated.title, "Final");
+        assert_eq!(updated.column_id, Some(seed.column_id.clone()));
+        assert_eq!(updated.description.as_deref(), Some("<div>Second</div>"));
+        assert_eq!(updated.due_on, None);
+
+        let descriptions: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM action_text_rich_texts WHERE record_id = ?",
+        )
+        .bind(&card.id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(descriptions, 1);
 
-        Ok(rows)
+        let missing = repo
+            .update(&seed.account_id, &FizzyId::generate(), UpdateCardInput::default())
+            .await;
+        assert!(matches!(missing, Err(DomainError::NotFound { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_list_filters_boards_in_sql() {
+        let pool = fizzy_pool().await;
+        let seed = seed_board(&pool).await;
+        let repo = SqliteCardRepository::new(pool.clone());
+        let other_board = FizzyId::generate();
+        sqlx::query("INSERT INTO boards (id, account_id, creator_id, name) VALUES (?, ?, ?, 'Other')")
+            .bind(&other_board)
+            .bind(&seed.account_id)
+            .bind(&seed.user_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        for board_id in [&seed.board_id, &other_board, &seed.board_id] {
+            repo.create(&seed.account_id, CreateCardInput {
+                board_id: board_id.clone(),
+                creator_id: seed.user_id.clone(),
+                title: "Card".to_string(),
+                description: None,
+                status: CardStatus::Published,
+                column_id: None,
+            })
+            .await
+            .unwrap();
+        }
+
+        let card_id: FizzyId = sqlx::query_scalar("SELECT id FROM cards WHERE number = 1")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO assignments (id, account_id, card_id, assignee_id) VALUES (?, ?, ?, ?)")
+            .bind(FizzyId::generate())
+            .bind(&seed.account_id)
+            .bind(&card_id)
+            .bind(&seed.user_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let list = |board_ids: Vec<FizzyId>, limit: Option<i64>| {
+            repo.list(&seed.account_id, CardFilters {
+                board_ids: Some(board_ids),
+                limit,
+                ..Default::default()
+            })
+        };
+
+        let cards = list(vec![seed.board_id.clone()], None).await.unwrap();
+        let mut numbers: Vec<i64> = cards.iter().map(|c| c.number).collect();
+        numbers.sort();
+        assert_eq!(numbers, vec![1, 3]);
+        let assigned = cards.iter().find(|c| c.number == 1).unwrap();
+        assert_eq!(assigned.assignee_names, vec!["Alice".to_string()]);
+
+        assert_eq!(list(vec![seed.board_id.clone()], Some(1)).await.unwrap().len(), 1);
+        assert!(list(Vec::new(), None).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_list_filters_title_prefixes() {
+        let pool = fizzy_pool().await;
+        let seed = seed_board(&pool).await;
+        let repo = SqliteCardRepository::new(pool);
+
+        for title in ["Login page", "Fix login", "Blogin", "50% off", "500 errors"] {
+            repo.create(&seed.account_id, CreateCardInput {
+                board_id: seed.board_id.clone(),
+                creator_id: seed.user_id.clone(),
+                title: title.to_string(),
+                description: None,
+                status: CardStatus::Published,
+                column_id: None,
+            })
+            .await
+            .unwrap();
+        }
+
+        let titles = |filters: CardFilters| {
+            let repo = &repo;
+            let account_id = &seed.account_id;
+            async move {
+                let mut titles: Vec<String> = repo.list(account_id, filters)
+                    .await
+                    .unwrap()
+                    .into_iter()
+                    .map(|card| card.title)
+                    .collect();
+                titles.sort();
+                titles
+            }
+        };
+
+        let starting = titles(CardFilters { title_prefix: Some("login".to_string()), ..Default::default() }).await;
+        assert_eq!(starting, vec!["Login page"]);
+
+        let word = titles(CardFilters { title_word_prefix: Some("login".to_string()), ..Default::default() }).await;
+        assert_eq!(word, vec!["Fix login"]);
+
+        let literal = titles(CardFilters { title_prefix: Some("50%".to_string()), ..Default::default() }).await;
+        assert_eq!(literal, vec!["50% off"]);
+    }
+
+    #[tokio::test]
+    async fn test_list_filters_closed_and_not_now() {
+        let pool = fizzy_pool().await;
+        let seed = seed_board(&pool).await;
+        let repo = SqliteCardRepository::new(pool);
+
+        let mut ids = Vec::new();
+        for title in ["Open", "Closed", "Postponed"] {
+            let card = repo.create(&seed.account_id, CreateCardInput {
+                board_id: seed.board_id.clone(),
+                creator_id: seed.user_id.clone(),
+                title: title.to_string(),
+                description: None,
+                status: CardStatus::Published,
+                column_id: None,
+            })
+            .await
+            .unwrap();
+            ids.push(card.id);
+        }
+        repo.close(&seed.account_id, &ids[1], &seed.user_id).await.unwrap();
+        repo.postpone(&seed.account_id, &ids[2], &seed.user_id).await.unwrap();
+
+        let titles = |filters: CardFilters| {
+            let repo = &repo;
+            let account_id = &seed.account_id;
+            async move {
+                repo.list(account_id, filters)
+                    .await
+                    .unwrap()
+                    .into_iter()
+                    .map(|card| card.title)
+                    .collect::<Vec<_>>()
+            }
+        };
+
+        let active = titles(CardFilters {
+            exclude_closed: Some(true),
+            exclude_not_now: Some(true),
+            ..Default::default()
+        }).await;
+        assert_eq!(active, vec!["Open"]);
+
+        let closed = titles(CardFilters { only_closed: Some(true), ..Default::default() }).await;
+        assert_eq!(closed, vec!["Closed"]);
+
+        let postponed = titles(CardFilters { only_not_now: Some(true), ..Default::default() }).await;
+        assert_eq!(postponed, vec!["Postponed"]);
+    }
+
+    #[tokio::test]
+    async fn test_create_indexes_card_for_search() {
+        let pool = fizzy_pool().await;
+        let seed = seed_board(&pool).await;
+        let repo = SqliteCardRepository::new(pool.clone());
+
+        let card = repo.create(&seed.account_id, CreateCardInput {
+            board_id: seed.board_id.clone(),
+            creator_id: seed.user_id.clone(),
+            title: "Fix login".to_string(),
+            description: Some("<div>Users get <b>logged out</b></div>".to_string()),
+            status: CardStatus::Published,
+            column_id: None,
+        })
+        .await
+        .unwrap();
+
+        let (searchable_type, board_id, title, content): (String, FizzyId, String, String) = sqlx::query_as(
+            "SELECT searchable_type, board_id, title, content FROM search_records WHERE searchable_id = ?",
+        )
+        .bind(&card.id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        assert_eq!(searchable_type, "Card");
+        assert_eq!(board_id, seed.board_id);
+        assert_eq!(title, "Fix login");
+        assert_eq!(content, "Users get logged out");
     }
 }
@@ -3,8 +3,9 @@ use crate::domain::errors::DomainError;
 use crate::domain::ports::CommentRepository;
 use crate::domain::value_objects::FizzyId;
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use sqlx::{SqlitePool, Row};
+use super::mysql_search_repo::{insert_record_query, search_table, search_text};
 
 pub struct SqliteCommentRepository {
     pool: SqlitePool,
@@ -22,9 +23,11 @@ impl CommentRepository for SqliteCommentRepository {
         &self,
         account_id: &FizzyId,
         card_id: &FizzyId,
-        limit: Option<i64>,
+        limit: i64,
+        offset: i64,
     ) -> Result<Vec<Comment>, DomainError> {
-        let query = "
+        let rows = sqlx::query(
+            r#"
             SELECT
                 c.id,
                 c.account_id,
@@ -32,26 +35,27 @@ impl CommentRepository for SqliteCommentRepository {
                 c.creator_id,
                 c.created_at,
                 c.updated_at,
-                rt. body as content
+                rt.body as content,
+                u.name as creator_name
             FROM comments c
             INNER JOIN action_text_rich_texts rt
-                ON c.id = rt.record_id
+                ON rt.record_id = c.id
+                AND rt.record_type = 'Comment'
+                AND rt.name = 'body'
+            LEFT JOIN users u ON c.creator_id = u.id
             WHERE c.account_id = ?
                 AND c.card_id = ?
-                AND c.record_type = 'Comment'
-                AND rt.name = 'body'
-            ORDER BY c.created_at DESC
-            LIMIT ?
-           ";
-        let limit = limit.unwrap_or(50);
-
-        let rows = sqlx::query(query)
-            .bind(account_id)
-            .bind(card_id)
-            .bind(limit)
-            .fetch_all(&self.pool)
-            .await
-            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+            ORDER BY c.created_at DESC, c.id DESC
+            LIMIT ? OFFSET ?
+            "#,
+        )
+        .bind(account_id)
+        .bind(card_id)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
 
         let comments = rows
             .into_iter()
@@ -64,11 +68,7 @@ impl CommentRepository for SqliteCommentRepository {
                     content: row.try_get("content")?,
                     created_at: row.try_get("created_at")?,
                     updated_at: row.try_get("updated_at")?,
-
-                    // TODO: Implement in phase 3
-                    // For now user is None, because I don't want to currently
-                    // deal with the users JOIN operation
-                    creator_name: None,
+                    creator_name: row.try_get("creator_name")?,
                 })
             })
             .collect::<Result<Vec<Comment>, sqlx::Error>>()
@@ -77,6 +77,105 @@ impl CommentRepository for SqliteCommentRepository {
         Ok(comments)
     }
 
+    async fn find_by_id(
+        &self,
+        account_id: &FizzyId,
+        id: &FizzyId,
+    ) -> Result<Option<Comment>, DomainError> {
+        let row = sqlx::query(
+            r#"
+            SELECT
+                c.id,
+                c.account_id,
+                c.card_id,
+                c.creator_id,
+                c.created_at,
+                c.updated_at,
+                rt.body as content,
+                u.name as creator_name
+            FROM comments c
+            INNER JOIN action_text_rich_texts rt
+                ON rt.record_id = c.id
+                AND rt.record_type = 'Comment'
+                AND rt.name = 'body'
+            LEFT JOIN users u ON c.creator_id = u.id
+            WHERE c.account_id = ?
+                AND c.id = ?
+            "#,
+        )
+        .bind(account_id)
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        row.map(|row| {
+            Ok(Comment {
+                id: row.try_get("id")?,
+                account_id: row.try_get("account_id")?,
+                creator_id: row.try_get("creator_id")?,
+                card_id: row.try_get("card_id")?,
+                content: row.try_get("content")?,
+                created_at: row.try_get("created_at")?,
+                updated_at: row.try_get("updated_at")?,
+                creator_name: row.try_get("creator_name")?,
+            })
+        })
+        .transpose()
+        .map_err(|e: sqlx::Error| DomainError::InfrastructureError(e.to_string()))
+    }
+
+    async fn list_by_creator_since(
+        &self,
+        account_id: &FizzyId,
+        creator_id: &FizzyId,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<Comment>, DomainError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                c.id,
+                c.account_id,
+                c.card_id,
+                c.creator_id,
+                c.created_at,
+                c.updated_at,
+                rt.body as content
+            FROM comments c
+            INNER JOIN action_text_rich_texts rt
+                ON rt.record_id = c.id
+                AND rt.record_type = 'Comment'
+                AND rt.name = 'body'
+            WHERE c.account_id = ?
+                AND c.creator_id = ?
+                AND c.created_at >= datetime(?)
+            ORDER BY c.created_at DESC
+            "#,
+        )
+        .bind(account_id)
+        .bind(creator_id)
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        rows.into_iter()
+            .map(|row| {
+                Ok(Comment {
+                    id: row.try_get("id")?,
+                    account_id: row.try_get("account_id")?,
+                    creator_id: row.try_get("creator_id")?,
+                    card_id: row.try_get("card_id")?,
+                    content: row.try_get("content")?,
+                    created_at: row.try_get("created_at")?,
+                    updated_at: row.try_get("updated_at")?,
+                    creator_name: None,
+                })
+            })
+            .collect::<Result<Vec<Comment>, sqlx::Error>>()
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))
+    }
+
     async fn create(
         &self,
         account_id: &FizzyId,
@@ -86,6 +185,7 @@ impl CommentRepository for SqliteCommentRepository {
     ) -> Result<Comment, DomainError> {
         let comment_id = FizzyId::generate();
         let now = Utc::now();
+        let search_table = search_table(&self.pool, account_id).await?;
 
         // Start a transaction
         let mut tx = self.pool.begin().await
@@ -124,7 +224,19 @@ impl CommentRepository for SqliteCommentRepository {
         .await
         .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
 
-        // 3. Update card's last_active_at
+        // 3. Index it so /search finds it
+        sqlx::query(&insert_record_query(&search_table))
+            .bind(account_id)
+            .bind("Comment")
+            .bind(&comment_id)
+            .bind(None::<String>)
+            .bind(search_text(content))
+            .bind(card_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        // 4. Update card's last_active_at
         sqlx::query(
             r#"
             UPDATE cards
@@ -153,4 +265,227 @@ impl CommentRepository for SqliteCommentRepository {
             creator_name: None,
         })
     }
+
+    async fn update(
+        &self,
+        account_id: &FizzyId,
+        id: &FizzyId,
+        content: &str,
+    ) -> Result<(), DomainError> {
+        let search_table = search_table(&self.pool, account_id).await?;
+
+        let mut tx = self.pool.begin().await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        let result = sqlx::query(
+            r#"
+            UPDATE comments
+            SET updated_at = datetime('now')
+            WHERE id = ? AND account_id = ?
+            "#,
+        )
+        .bind(id)
+        .bind(account_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::NotFound {
+                entity: "Comment".to_string(),
+                id: id.to_string(),
+            });
+        }
+
+        sqlx::query(
+            r#"
+            UPDATE action_text_rich_texts
+            SET body = ?, updated_at = datetime('now')
+            WHERE account_id = ? AND record_type = 'Comment' AND record_id = ? AND name = 'body'
+            "#,
+        )
+        .bind(content)
+        .bind(account_id)
+        .bind(id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        // Keep /search in step with the new text
+        sqlx::query(&format!(
+            "UPDATE {} SET content = ? WHERE account_id = ? AND searchable_type = 'Comment' AND searchable_id = ?",
+            search_table
+        ))
+        .bind(search_text(content))
+        .bind(account_id)
+        .bind(id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        tx.commit().await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, account_id: &FizzyId, id: &FizzyId) -> Result<(), DomainError> {
+        let search_table = search_table(&self.pool, account_id).await?;
+
+        let mut tx = self.pool.begin().await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        // 1. Remove the files embedded in the body: blobs no other record
+        //    uses, then the attachments themselves
+        sqlx::query(
+            r#"
+            DELETE FROM active_storage_blobs
+            WHERE id IN (
+                SELECT a.blob_id FROM active_storage_attachments a
+                JOIN action_text_rich_texts rt ON rt.id = a.record_id
+                WHERE a.record_type = 'ActionText::RichText'
+                  AND rt.account_id = ? AND rt.record_type = 'Comment' AND rt.record_id = ?
+            )
+            AND NOT EXISTS (
+                SELECT 1 FROM active_storage_attachments other
+                WHERE other.blob_id = active_storage_blobs.id
+                  AND NOT (other.record_type = 'ActionText::RichText' AND other.record_id IN (
+                      SELECT id FROM action_text_rich_texts
+                      WHERE account_id = ? AND record_type = 'Comment' AND record_id = ?
+                  ))
+            )
+            "#,
+        )
+        .bind(account_id)
+        .bind(id)
+        .bind(account_id)
+        .bind(id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        sqlx::query(
+            r#"
+            DELETE FROM active_storage_attachments
+            WHERE record_type = 'ActionText::RichText' AND record_id IN (
+                SELECT id FROM action_text_rich_texts
+                WHERE account_id = ? AND record_type = 'Comment' AND record_id = ?
+            )
+            "#,
+        )
+        .bind(account_id)
+        .bind(id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        // 2. Remove the body
+        sqlx::query(
+            r#"
+            DELETE FROM action_text_rich_texts
+            WHERE account_id = ? AND record_type = 'Comment' AND record_id = ? AND name = 'body'
+            "#,
+        )
+        .bind(account_id)
+        .bind(id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        // 3. Remove its events, which the timeline would otherwise try to load
+        sqlx::query("DELETE FROM events WHERE account_id = ? AND eventable_type = 'Comment' AND eventable_id = ?")
+            .bind(account_id)
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        // 4. Drop it from the search index so it no longer turns up in /search
+        sqlx::query(&format!(
+            "DELETE FROM {} WHERE account_id = ? AND searchable_type = 'Comment' AND searchable_id = ?",
+            search_table
+        ))
+        .bind(account_id)
+        .bind(id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        // 5. Remove the comment itself
+        let result = sqlx::query("DELETE FROM comments WHERE id = ? AND account_id = ?")
+            .bind(id)
+            .bind(account_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::NotFound {
+                entity: "Comment".to_string(),
+                id: id.to_string(),
+            });
+        }
+
+        tx.commit().await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::persistence::mysql_search_repo::search_shard;
+    use crate::infrastructure::persistence::test_support::{fizzy_pool, seed_board};
+
+    #[tokio::test]
+    async fn test_update_and_delete_keep_related_rows_in_step() {
+        let pool = fizzy_pool().await;
+        let seed = seed_board(&pool).await;
+        let repo = SqliteCommentRepository::new(pool.clone());
+        let card_id = FizzyId::generate();
+        sqlx::query("INSERT INTO cards (id, account_id, board_id, creator_id, number, title) VALUES (?, ?, ?, ?, 1, 'Card')")
+            .bind(&card_id).bind(&seed.account_id).bind(&seed.board_id).bind(&seed.user_id)
+            .execute(&pool).await.unwrap();
+
+        let shard = format!("search_records_{}", search_shard(&seed.account_id));
+        sqlx::query(&format!("CREATE TABLE {} AS SELECT * FROM search_records WHERE 0", shard))
+            .execute(&pool).await.unwrap();
+
+        let comment = repo.create(&seed.account_id, &card_id, &seed.user_id, "<div>Frist draft</div>").await.unwrap();
+        let (content, board_id): (String, FizzyId) = sqlx::query_as(&format!("SELECT content, board_id FROM {} WHERE searchable_id = ?", shard))
+            .bind(&comment.id)
+            .fetch_one(&pool).await.unwrap();
+        assert_eq!(content, "Frist draft");
+        assert_eq!(board_id, seed.board_id);
+
+        repo.update(&seed.account_id, &comment.id, "<div>First <b>draft</b></div>").await.unwrap();
+        let content: String = sqlx::query_scalar(&format!("SELECT content FROM {} WHERE searchable_id = ?", shard))
+            .bind(&comment.id)
+            .fetch_one(&pool).await.unwrap();
+        assert_eq!(content, "First draft");
+
+        // A file embedded in the comment, and the comment's creation event
+        let rich_text_id: FizzyId = sqlx::query_scalar("SELECT id FROM action_text_rich_texts WHERE record_id = ?")
+            .bind(&comment.id)
+            .fetch_one(&pool).await.unwrap();
+        let blob_id = FizzyId::generate();
+        sqlx::query("INSERT INTO active_storage_blobs (id, account_id, key, filename) VALUES (?, ?, 'k', 'a.png')")
+            .bind(&blob_id).bind(&seed.account_id)
+            .execute(&pool).await.unwrap();
+        sqlx::query("INSERT INTO active_storage_attachments (id, account_id, name, record_type, record_id, blob_id) VALUES (?, ?, 'embeds', 'ActionText::RichText', ?, ?)")
+            .bind(FizzyId::generate()).bind(&seed.account_id).bind(&rich_text_id).bind(&blob_id)
+            .execute(&pool).await.unwrap();
+        sqlx::query("INSERT INTO events (id, account_id, board_id, eventable_id, eventable_type, creator_id, action) VALUES (?, ?, ?, ?, 'Comment', ?, 'comment_created')")
+            .bind(FizzyId::generate()).bind(&seed.account_id).bind(&seed.board_id).bind(&comment.id).bind(&seed.user_id)
+            .execute(&pool).await.unwrap();
+
+        repo.delete(&seed.account_id, &comment.id).await.unwrap();
+        for table in [shard.as_str(), "comments", "action_text_rich_texts", "active_storage_attachments", "active_storage_blobs", "events"] {
+            let remaining: i64 = sqlx::query_scalar(&format!("SELECT COUNT(*) FROM {}", table))
+                .fetch_one(&pool).await.unwrap();
+            assert_eq!(remaining, 0, "{} still has rows", table);
+        }
+    }
 }
@@ -1,5 +1,7 @@
 use async_trait::async_trait;
-use sqlx::SqlitePool;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, SqlitePool};
+use crate::domain::entities::Event;
 use crate::domain::ports::{EventRepository, CreateEventInput};
 use crate::domain::value_objects::FizzyId;
 use crate::domain::errors::DomainError;
@@ -8,10 +10,73 @@ pub struct SqliteEventRepository {
     pool: SqlitePool,
 }
 
+/// Raw row from the events query
+#[derive(Debug, FromRow)]
+struct EventRow {
+    id: FizzyId,
+    account_id: FizzyId,
+    board_id: FizzyId,
+    eventable_id: FizzyId,
+    eventable_type: String,
+    creator_id: FizzyId,
+    action: String,
+    particulars: Option<String>,
+    created_at: DateTime<Utc>,
+    card_id: Option<FizzyId>,
+    creator_name: Option<String>,
+}
+
+impl From<EventRow> for Event {
+    fn from(row: EventRow) -> Self {
+        let particulars = row
+            .particulars
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_else(|| serde_json::json!({}));
+
+        Event {
+            id: row.id,
+            account_id: row.account_id,
+            board_id: row.board_id,
+            eventable_id: row.eventable_id,
+            eventable_type: row.eventable_type,
+            creator_id: row.creator_id,
+            action: row.action,
+            particulars,
+            created_at: row.created_at,
+            card_id: row.card_id,
+            creator_name: row.creator_name,
+        }
+    }
+}
+
 impl SqliteEventRepository {
     pub fn new(pool: SqlitePool) -> Self {
         Self { pool }
     }
+
+    /// Base query for loading events with the card they refer to and their creator
+    fn base_event_query() -> &'static str {
+        r#"
+        SELECT
+            e.id,
+            e.account_id,
+            e.board_id,
+            e.eventable_id,
+            e.eventable_type,
+            e.creator_id,
+            e.action,
+            e.particulars,
+            e.created_at,
+            CASE e.eventable_type
+                WHEN 'Card' THEN e.eventable_id
+                WHEN 'Comment' THEN cm.card_id
+            END as card_id,
+            u.name as creator_name
+        FROM events e
+        LEFT JOIN comments cm ON e.eventable_type = 'Comment' AND cm.id = e.eventable_id
+        LEFT JOIN users u ON e.creator_id = u.id
+        "#
+    }
 }
 
 #[async_trait]
@@ -47,4 +112,148 @@ impl EventRepository for SqliteEventRepository {
 
         Ok(())
     }
+
+    async fn latest_created_at(&self, account_id: &FizzyId) -> Result<Option<DateTime<Utc>>, DomainError> {
+        sqlx::query_scalar::<_, DateTime<Utc>>(
+            "SELECT created_at FROM events WHERE account_id = ? ORDER BY created_at DESC LIMIT 1",
+        )
+        .bind(account_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))
+    }
+
+    async fn list_since(
+        &self,
+        account_id: &FizzyId,
+        since: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<Event>, DomainError> {
+        let query = format!(
+            r#"{}
+            WHERE e.account_id = ? AND e.created_at >= datetime(?)
+            ORDER BY e.created_at ASC, e.id ASC
+            LIMIT ?"#,
+            Self::base_event_query()
+        );
+
+        let rows = sqlx::query_as::<_, EventRow>(&query)
+            .bind(account_id)
+            .bind(since)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(Event::from).collect())
+    }
+
+    async fn list_for_card(
+        &self,
+        account_id: &FizzyId,
+        card_id: &FizzyId,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Event>, DomainError> {
+        let query = format!(
+            r#"{}
+            WHERE e.account_id = ?
+                AND ((e.eventable_type = 'Card' AND e.eventable_id = ?)
+                    OR (e.eventable_type = 'Comment' AND cm.card_id = ?))
+            ORDER BY e.created_at ASC, e.id ASC
+            LIMIT ? OFFSET ?"#,
+            Self::base_event_query()
+        );
+
+        let rows = sqlx::query_as::<_, EventRow>(&query)
+            .bind(account_id)
+            .bind(card_id)
+            .bind(card_id)
+            .bind(limit)
+            .bind(offset)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(Event::from).collect())
+    }
+
+    async fn list_between(
+        &self,
+        account_id: &FizzyId,
+        since: DateTime<Utc>,
+        until: DateTime<Utc>,
+        actions: &[&str],
+    ) -> Result<Vec<Event>, DomainError> {
+        if actions.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let placeholders = actions.iter().map(|_| "?").collect::<Vec<_>>().join(", ");
+        let query = format!(
+            r#"{}
+            WHERE e.account_id = ?
+                AND e.created_at >= datetime(?)
+                AND e.created_at < datetime(?)
+                AND e.action IN ({})
+            ORDER BY e.created_at ASC"#,
+            Self::base_event_query(),
+            placeholders
+        );
+
+        let mut query_builder = sqlx::query_as::<_, EventRow>(&query)
+            .bind(account_id)
+            .bind(since)
+            .bind(until);
+
+        for action in actions {
+            query_builder = query_builder.bind(*action);
+        }
+
+        let rows = query_builder
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(Event::from).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use crate::infrastructure::persistence::test_support::{fizzy_pool, seed_board};
+
+    #[tokio::test]
+    async fn test_list_since_orders_by_creation_time() {
+        let pool = fizzy_pool().await;
+        let seed = seed_board(&pool).await;
+        let repo = SqliteEventRepository::new(pool.clone());
+
+        // IDs don't follow creation time when a transaction commits late
+        let late = FizzyId::generate();
+        let early = FizzyId::generate();
+        for (id, action, created_at) in [
+            (&early, "card_created", "2026-10-17 10:00:00.250000"),
+            (&late, "card_closed", "2026-10-17 10:05:00.500000"),
+        ] {
+            sqlx::query("INSERT INTO events (id, account_id, board_id, eventable_id, eventable_type, creator_id, action, created_at, updated_at) VALUES (?, ?, ?, ?, 'Card', ?, ?, ?, ?)")
+                .bind(id).bind(&seed.account_id).bind(&seed.board_id).bind(FizzyId::generate())
+                .bind(&seed.user_id).bind(action).bind(created_at).bind(created_at)
+                .execute(&pool).await.unwrap();
+        }
+
+        let latest = repo.latest_created_at(&seed.account_id).await.unwrap().unwrap();
+        assert_eq!(latest, Utc.with_ymd_and_hms(2026, 10, 17, 10, 5, 0).unwrap() + chrono::Duration::milliseconds(500));
+
+        let since = Utc.with_ymd_and_hms(2026, 10, 17, 10, 0, 0).unwrap();
+        let events = repo.list_since(&seed.account_id, since, 10).await.unwrap();
+        let ids: Vec<&FizzyId> = events.iter().map(|e| &e.id).collect();
+        assert_eq!(ids, vec![&early, &late]);
+
+        let events = repo.list_since(&seed.account_id, since + chrono::Duration::minutes(1), 10).await.unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].id, late);
+    }
 }
@@ -0,0 +1,257 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, SqlitePool};
+use crate::domain::errors::DomainError;
+use crate::domain::ports::{SearchRecord, SearchRepository};
+use crate::domain::value_objects::FizzyId;
+
+/// Number of search_records_N tables Fizzy spreads accounts across
+const SEARCH_SHARD_COUNT: u32 = 16;
+
+/// Line width for the plain text kept in the search index; wide enough that
+/// html2text never wraps
+const SEARCH_TEXT_WIDTH: usize = 10_000;
+
+pub struct SqliteSearchRepository {
+    pool: SqlitePool,
+}
+
+/// Raw row from a search records table
+#[derive(Debug, FromRow)]
+struct SearchRecordRow {
+    card_id: FizzyId,
+    searchable_type: String,
+    title: Option<String>,
+    content: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<SearchRecordRow> for SearchRecord {
+    fn from(row: SearchRecordRow) -> Self {
+        SearchRecord {
+            card_id: row.card_id,
+            searchable_type: row.searchable_type,
+            title: row.title,
+            content: row.content,
+            created_at: row.created_at,
+        }
+    }
+}
+
+impl SqliteSearchRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Resolve the search table for an account.
+/// Uses the shard table when it exists, falling back to the unsharded
+/// `search_records` table used by single-database installs.
+pub(super) async fn search_table(pool: &SqlitePool, account_id: &FizzyId) -> Result<String, DomainError> {
+    let shard_table = format!("search_records_{}", search_shard(account_id));
+
+    let exists = sqlx::query_scalar::<_, i64>(
+        "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = ?",
+    )
+    .bind(&shard_table)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+    if exists > 0 {
+        Ok(shard_table)
+    } else {
+        Ok("search_records".to_string())
+    }
+}
+
+/// Statement indexing a new card or comment in `table`, taking the board from
+/// its card. Binds account_id, searchable_type, searchable_id, title, content
+/// and card_id, in that order.
+pub(super) fn insert_record_query(table: &str) -> String {
+    format!(
+        r#"
+        INSERT INTO {} (
+            account_id, searchable_type, searchable_id, card_id, board_id, title, content, created_at
+        )
+        SELECT ?, ?, ?, c.id, c.board_id, ?, ?, datetime('now')
+        FROM cards c
+        WHERE c.id = ?
+        "#,
+        table
+    )
+}
+
+/// Plain text of ActionText HTML, as kept in the search index
+pub(super) fn search_text(html: &str) -> String {
+    html2text::from_read(html.as_bytes(), SEARCH_TEXT_WIDTH).trim().to_string()
+}
+
+/// Compute the search shard for an account the same way Fizzy does:
+/// `Zlib.crc32(account_id.to_s) % SHARD_COUNT`
+pub fn search_shard(account_id: &FizzyId) -> u32 {
+    crc32fast::hash(account_id.as_str().as_bytes()) % SEARCH_SHARD_COUNT
+}
+
+/// Escape LIKE wildcards so user input matches literally; use with `ESCAPE '\\'`
+pub(super) fn escape_like(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+/// Match a term anywhere in the text
+fn like_pattern(term: &str) -> String {
+    format!("%{}%", escape_like(term))
+}
+
+#[async_trait]
+impl SearchRepository for SqliteSearchRepository {
+    async fn search(
+        &self,
+        account_id: &FizzyId,
+        terms: &[String],
+        board_ids: &[FizzyId],
+        limit: i64,
+    ) -> Result<Vec<SearchRecord>, DomainError> {
+        if terms.is_empty() || board_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let table = search_table(&self.pool, account_id).await?;
+
+        // Numbered parameters: ?1 account, ?2 limit, then one pattern per
+        // term (each used several times), then the boards
+        let term_matches: Vec<String> = (0..terms.len())
+            .map(|i| {
+                let p = i + 3;
+                format!("(sr.title LIKE ?{p} ESCAPE '\\' OR sr.content LIKE ?{p} ESCAPE '\\')")
+            })
+            .collect();
+        let title_matches: Vec<String> = (0..terms.len())
+            .map(|i| format!("MAX(sr.title LIKE ?{} ESCAPE '\\')", i + 3))
+            .collect();
+        let content_matches: Vec<String> = (0..terms.len())
+            .map(|i| format!("MAX(sr.content LIKE ?{} ESCAPE '\\')", i + 3))
+            .collect();
+        let board_placeholders = (0..board_ids.len())
+            .map(|i| format!("?{}", i + 3 + terms.len()))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let any_term = term_matches.join(" OR ");
+
+        // Rank whole cards before limiting, so the best matches are never cut:
+        // a term in any of the card's titles counts 3, in any content 1, and
+        // each extra matching record (e.g. another comment) adds 1
+        let query = format!(
+            r#"
+            WITH candidates AS (
+                SELECT sr.card_id
+                FROM {table} sr
+                WHERE sr.account_id = ?1
+                  AND sr.board_id IN ({board_placeholders})
+                  AND ({any_term})
+                GROUP BY sr.card_id
+                HAVING {all_terms}
+                ORDER BY 3 * ({title_score}) + ({content_score}) + COUNT(*) DESC,
+                         MAX(sr.created_at) DESC
+                LIMIT ?2
+            )
+            SELECT
+                sr.card_id,
+                sr.searchable_type,
+                sr.title,
+                sr.content,
+                sr.created_at
+            FROM {table} sr
+            JOIN candidates ON candidates.card_id = sr.card_id
+            WHERE sr.account_id = ?1
+              AND ({any_term})
+            ORDER BY sr.created_at DESC
+            "#,
+            all_terms = term_matches
+                .iter()
+                .map(|m| format!("MAX({}) = 1", m))
+                .collect::<Vec<_>>()
+                .join(" AND "),
+            title_score = title_matches.join(" + "),
+            content_score = content_matches.join(" + "),
+        );
+
+        let mut query_builder = sqlx::query_as::<_, SearchRecordRow>(&query)
+            .bind(account_id)
+            .bind(limit);
+
+        for term in terms {
+            query_builder = query_builder.bind(like_pattern(term));
+        }
+
+        for board_id in board_ids {
+            query_builder = query_builder.bind(board_id);
+        }
+
+        let rows = query_builder
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(SearchRecord::from).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::persistence::test_support::fizzy_pool;
+
+    #[test]
+    fn test_search_shard_matches_zlib_crc32() {
+        // Zlib.crc32("03f5vvmj8fk3aoqsk7yeyqzxm") == 3953291300
+        let account_id = FizzyId::from("03f5vvmj8fk3aoqsk7yeyqzxm");
+        assert_eq!(search_shard(&account_id), 3953291300 % 16);
+    }
+
+    #[tokio::test]
+    async fn test_search_groups_records_by_card() {
+        let pool = fizzy_pool().await;
+        let account_id = FizzyId::generate();
+        let board_id = FizzyId::generate();
+        let (split, single, other) = (FizzyId::generate(), FizzyId::generate(), FizzyId::generate());
+
+        let records = [
+            (&split, "Card", "Login bug", "", "2026-01-01 10:00:00"),
+            (&split, "Comment", "", "still slow today", "2026-01-01 11:00:00"),
+            (&single, "Card", "Slow login page", "", "2026-01-01 09:00:00"),
+            (&other, "Card", "Login copy", "", "2026-01-01 12:00:00"),
+        ];
+        for (card_id, kind, title, content, at) in records {
+            sqlx::query(
+                "INSERT INTO search_records (account_id, searchable_type, searchable_id, card_id, board_id, title, content, created_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(&account_id).bind(kind).bind(FizzyId::generate()).bind(card_id)
+            .bind(&board_id).bind(title).bind(content).bind(at)
+            .execute(&pool).await.unwrap();
+        }
+
+        let repo = SqliteSearchRepository::new(pool);
+        let terms = vec!["login".to_string(), "slow".to_string()];
+
+        // Both terms only appear together across the split card's records
+        let boards = vec![board_id];
+        let found = repo.search(&account_id, &terms, &boards, 10).await.unwrap();
+        let mut cards: Vec<&FizzyId> = found.iter().map(|r| &r.card_id).collect();
+        cards.dedup();
+        assert_eq!(cards.len(), 2);
+        assert!(cards.contains(&&split) && cards.contains(&&single));
+        assert!(!cards.contains(&&other));
+
+        // The limit counts cards, picked by relevance rather than recency
+        let found = repo.search(&account_id, &terms, &boards, 1).await.unwrap();
+        assert!(found.iter().all(|r| r.card_id == single));
+    }
+
+    #[test]
+    fn test_like_pattern_escapes_wildcards() {
+        assert_eq!(like_pattern("50%_off"), "%50\\%\\_off%");
+    }
+}
@@ -0,0 +1,198 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, SqlitePool};
+use crate::domain::entities::Tag;
+use crate::domain::ports::TagRepository;
+use crate::domain::value_objects::FizzyId;
+use crate::domain::errors::DomainError;
+
+pub struct SqliteTagRepository {
+    pool: SqlitePool,
+}
+
+/// Raw row from the tags table
+#[derive(Debug, FromRow)]
+struct TagRow {
+    id: FizzyId,
+    account_id: FizzyId,
+    title: String,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<TagRow> for Tag {
+    fn from(row: TagRow) -> Self {
+        Tag {
+            id: row.id,
+            account_id: row.account_id,
+            title: row.title,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+impl SqliteTagRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    async fn find_by_title(&self, account_id: &FizzyId, title: &str) -> Result<Option<Tag>, DomainError> {
+        let row = sqlx::query_as::<_, TagRow>(
+            r#"
+            SELECT id, account_id, title, created_at, updated_at
+            FROM tags
+            WHERE account_id = ? AND LOWER(title) = LOWER(?)
+            "#,
+        )
+        .bind(account_id)
+        .bind(title)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(row.map(Tag::from))
+    }
+
+    /// Bump a card's activity timestamps
+    async fn touch_card(&self, account_id: &FizzyId, card_id: &FizzyId) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            UPDATE cards
+            SET last_active_at = datetime('now'), updated_at = datetime('now')
+            WHERE id = ? AND account_id = ?
+            "#,
+        )
+        .bind(card_id)
+        .bind(account_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TagRepository for SqliteTagRepository {
+    async fn list(&self, account_id: &FizzyId) -> Result<Vec<Tag>, DomainError> {
+        let rows = sqlx::query_as::<_, TagRow>(
+            r#"
+            SELECT id, account_id, title, created_at, updated_at
+            FROM tags
+            WHERE account_id = ?
+            ORDER BY title ASC
+            "#,
+        )
+        .bind(account_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(Tag::from).collect())
+    }
+
+    async fn list_for_card(
+        &self,
+        account_id: &FizzyId,
+        card_id: &FizzyId,
+    ) -> Result<Vec<Tag>, DomainError> {
+        let rows = sqlx::query_as::<_, TagRow>(
+            r#"
+            SELECT t.id, t.account_id, t.title, t.created_at, t.updated_at
+            FROM taggings tg
+            JOIN tags t ON tg.tag_id = t.id
+            WHERE tg.account_id = ? AND tg.card_id = ?
+            ORDER BY t.title ASC
+            "#,
+        )
+        .bind(account_id)
+        .bind(card_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(Tag::from).collect())
+    }
+
+    async fn find_or_create(&self, account_id: &FizzyId, title: &str) -> Result<Tag, DomainError> {
+        if let Some(tag) = self.find_by_title(account_id, title).await? {
+            return Ok(tag);
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO tags (id, account_id, title, created_at, updated_at)
+            VALUES (?, ?, ?, datetime('now'), datetime('now'))
+            "#,
+        )
+        .bind(FizzyId::generate())
+        .bind(account_id)
+        .bind(title)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        self.find_by_title(account_id, title)
+            .await?
+            .ok_or_else(|| DomainError::InfrastructureError("Failed to create tag".to_string()))
+    }
+
+    async fn add_to_card(
+        &self,
+        account_id: &FizzyId,
+        card_id: &FizzyId,
+        tag_id: &FizzyId,
+    ) -> Result<bool, DomainError> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO taggings (id, account_id, card_id, tag_id, created_at, updated_at)
+            SELECT ?, ?, ?, ?, datetime('now'), datetime('now')
+            WHERE NOT EXISTS (
+                SELECT 1 FROM taggings WHERE account_id = ? AND card_id = ? AND tag_id = ?
+            )
+            "#,
+        )
+        .bind(FizzyId::generate())
+        .bind(account_id)
+        .bind(card_id)
+        .bind(tag_id)
+        .bind(account_id)
+        .bind(card_id)
+        .bind(tag_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Ok(false);
+        }
+
+        self.touch_card(account_id, card_id).await?;
+        Ok(true)
+    }
+
+    async fn remove_from_card(
+        &self,
+        account_id: &FizzyId,
+        card_id: &FizzyId,
+        tag_id: &FizzyId,
+    ) -> Result<bool, DomainError> {
+        let result = sqlx::query(
+            "DELETE FROM taggings WHERE account_id = ? AND card_id = ? AND tag_id = ?",
+        )
+        .bind(account_id)
+        .bind(card_id)
+        .bind(tag_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Ok(false);
+        }
+
+        self.touch_card(account_id, card_id).await?;
+        Ok(true)
+    }
+}
@@ -0,0 +1,158 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, SqlitePool};
+use crate::domain::entities::{User, UserRole};
+use crate::domain::ports::UserRepository;
+use crate::domain::value_objects::FizzyId;
+use crate::domain::errors::DomainError;
+
+pub struct SqliteUserRepository {
+    pool: SqlitePool,
+}
+
+/// Raw row from database query for users
+#[derive(Debug, FromRow)]
+struct UserRow {
+    id: FizzyId,
+    account_id: FizzyId,
+    identity_id: Option<FizzyId>,
+    name: String,
+    role: String,
+    active: bool,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    email: Option<String>,
+}
+
+impl UserRow {
+    fn into_user(self) -> Result<User, DomainError> {
+        let role = self
+            .role
+            .parse::<UserRole>()
+            .map_err(|_| DomainError::InvalidState {
+                message: format!("Invalid user role: {}", self.role),
+            })?;
+
+        Ok(User {
+            id: self.id,
+            account_id: self.account_id,
+            identity_id: self.identity_id,
+            name: self.name,
+            role,
+            active: self.active,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            email: self.email,
+        })
+    }
+}
+
+impl SqliteUserRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Base query for loading users with their identity email
+    fn base_user_query() -> &'static str {
+        r#"
+        SELECT
+            u.id,
+            u.account_id,
+            u.identity_id,
+            u.name,
+            u.role,
+            u.active,
+            u.created_at,
+            u.updated_at,
+            i.email_address as email
+        FROM users u
+        LEFT JOIN identities i ON u.identity_id = i.id
+        "#
+    }
+}
+
+#[async_trait]
+impl UserRepository for SqliteUserRepository {
+    async fn find_by_id(
+        &self,
+        account_id: &FizzyId,
+        id: &FizzyId,
+    ) -> Result<Option<User>, DomainError> {
+        let query = format!(
+            "{} WHERE u.account_id = ? AND u.id = ?",
+            Self::base_user_query()
+        );
+
+        let row = sqlx::query_as::<_, UserRow>(&query)
+            .bind(account_id)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        row.map(UserRow::into_user).transpose()
+    }
+
+    async fn find_by_email(
+        &self,
+        account_id: &FizzyId,
+        email: &str,
+    ) -> Result<Option<User>, DomainError> {
+        let query = format!(
+            "{} WHERE u.account_id = ? AND LOWER(i.email_address) = LOWER(?) AND u.active = true",
+            Self::base_user_query()
+        );
+
+        let row = sqlx::query_as::<_, UserRow>(&query)
+            .bind(account_id)
+            .bind(email.trim())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        row.map(UserRow::into_user).transpose()
+    }
+
+    async fn list_active(&self, account_id: &FizzyId) -> Result<Vec<User>, DomainError> {
+        let query = format!(
+            "{} WHERE u.account_id = ? AND u.active = true AND u.role != 'system' ORDER BY u.name ASC",
+            Self::base_user_query()
+        );
+
+        let rows = sqlx::query_as::<_, UserRow>(&query)
+            .bind(account_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        rows.into_iter().map(UserRow::into_user).collect()
+    }
+
+    async fn list_board_members(
+        &self,
+        account_id: &FizzyId,
+        board_id: &FizzyId,
+    ) -> Result<Vec<User>, DomainError> {
+        // Same rule as board access: all_access boards include everyone
+        let query = format!(
+            r#"{}
+            JOIN boards b ON b.id = ? AND b.account_id = u.account_id
+            WHERE u.account_id = ?
+              AND u.active = true
+              AND u.role != 'system'
+              AND (b.all_access = true
+                   OR EXISTS (SELECT 1 FROM accesses a WHERE a.board_id = b.id AND a.user_id = u.id))
+            ORDER BY u.name ASC"#,
+            Self::base_user_query()
+        );
+
+        let rows = sqlx::query_as::<_, UserRow>(&query)
+            .bind(board_id)
+            .bind(account_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        rows.into_iter().map(UserRow::into_user).collect()
+    }
+}
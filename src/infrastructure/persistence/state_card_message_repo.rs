@@ -0,0 +1,60 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::SqlitePool;
+use crate::domain::ports::CardMessageRepository;
+use crate::domain::errors::DomainError;
+
+pub struct SqliteCardMessageRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteCardMessageRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CardMessageRepository for SqliteCardMessageRepository {
+    async fn remember(&self, chat_id: i64, message_id: i32, card_number: i64) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO card_messages (chat_id, message_id, card_number, created_at)
+            VALUES (?, ?, ?, ?)
+            ON CONFLICT (chat_id, message_id) DO UPDATE SET
+                card_number = excluded.card_number,
+                created_at = excluded.created_at
+            "#,
+        )
+        .bind(chat_id)
+        .bind(message_id)
+        .bind(card_number)
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find(&self, chat_id: i64, message_id: i32) -> Result<Option<i64>, DomainError> {
+        sqlx::query_scalar::<_, i64>(
+            "SELECT card_number FROM card_messages WHERE chat_id = ? AND message_id = ?",
+        )
+        .bind(chat_id)
+        .bind(message_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))
+    }
+
+    async fn prune_before(&self, before: DateTime<Utc>) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM card_messages WHERE created_at < ?")
+            .bind(before)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+use async_trait::async_trait;
+use chrono::{NaiveDate, NaiveTime, Utc};
+use sqlx::{FromRow, SqlitePool};
+use crate::domain::entities::DigestSchedule;
+use crate::domain::ports::DigestScheduleRepository;
+use crate::domain::value_objects::FizzyId;
+use crate::domain::errors::DomainError;
+
+pub struct SqliteDigestScheduleRepository {
+    pool: SqlitePool,
+}
+
+/// Raw row from the digest_schedules table
+#[derive(Debug, FromRow)]
+struct DigestScheduleRow {
+    chat_id: i64,
+    user_id: FizzyId,
+    send_at: NaiveTime,
+    timezone: String,
+    last_sent_on: Option<NaiveDate>,
+}
+
+impl DigestScheduleRow {
+    fn into_schedule(self) -> Result<DigestSchedule, DomainError> {
+        let timezone = self.timezone.parse().map_err(|_| {
+            DomainError::InfrastructureError(format!("Invalid digest timezone: {}", self.timezone))
+        })?;
+
+        Ok(DigestSchedule {
+            chat_id: self.chat_id,
+            user_id: self.user_id,
+            send_at: self.send_at,
+            timezone,
+            last_sent_on: self.last_sent_on,
+        })
+    }
+}
+
+impl SqliteDigestScheduleRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl DigestScheduleRepository for SqliteDigestScheduleRepository {
+    async fn find(&self, chat_id: i64) -> Result<Option<DigestSchedule>, DomainError> {
+        let row = sqlx::query_as::<_, DigestScheduleRow>(
+            r#"
+            SELECT chat_id, user_id, send_at, timezone, last_sent_on
+            FROM digest_schedules
+            WHERE chat_id = ?
+            "#,
+        )
+        .bind(chat_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        row.map(DigestScheduleRow::into_schedule).transpose()
+    }
+
+    async fn list(&self) -> Result<Vec<DigestSchedule>, DomainError> {
+        let rows = sqlx::query_as::<_, DigestScheduleRow>(
+            "SELECT chat_id, user_id, send_at, timezone, last_sent_on FROM digest_schedules",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        rows.into_iter().map(DigestScheduleRow::into_schedule).collect()
+    }
+
+    async fn save(&self, schedule: &DigestSchedule) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO digest_schedules (chat_id, user_id, send_at, timezone, last_sent_on, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?)
+            ON CONFLICT (chat_id) DO UPDATE SET
+                user_id = excluded.user_id,
+                send_at = excluded.send_at,
+                timezone = excluded.timezone,
+                last_sent_on = excluded.last_sent_on,
+                updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(schedule.chat_id)
+        .bind(&schedule.user_id)
+        .bind(schedule.send_at)
+        .bind(schedule.timezone.name())
+        .bind(schedule.last_sent_on)
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, chat_id: i64) -> Result<bool, DomainError> {
+        let result = sqlx::query("DELETE FROM digest_schedules WHERE chat_id = ?")
+            .bind(chat_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn mark_sent(&self, chat_id: i64, sent_on: NaiveDate) -> Result<(), DomainError> {
+        sqlx::query("UPDATE digest_schedules SET last_sent_on = ? WHERE chat_id = ?")
+            .bind(sent_on)
+            .bind(chat_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, SqlitePool};
+use crate::domain::entities::LinkRequest;
+use crate::domain::ports::LinkRequestRepository;
+use crate::domain::value_objects::FizzyId;
+use crate::domain::errors::DomainError;
+
+pub struct SqliteLinkRequestRepository {
+    pool: SqlitePool,
+}
+
+/// Raw row from the link_requests table
+#[derive(Debug, FromRow)]
+struct LinkRequestRow {
+    code: String,
+    telegram_user_id: i64,
+    telegram_name: Option<String>,
+    chat_id: i64,
+    user_id: FizzyId,
+    created_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+}
+
+impl From<LinkRequestRow> for LinkRequest {
+    fn from(row: LinkRequestRow) -> Self {
+        LinkRequest {
+            code: row.code,
+            telegram_user_id: row.telegram_user_id,
+            telegram_name: row.telegram_name,
+            chat_id: row.chat_id,
+            user_id: row.user_id,
+            created_at: row.created_at,
+            expires_at: row.expires_at,
+        }
+    }
+}
+
+impl SqliteLinkRequestRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl LinkRequestRepository for SqliteLinkRequestRepository {
+    async fn create(&self, request: &LinkRequest) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT OR REPLACE INTO link_requests (
+                code, telegram_user_id, telegram_name, chat_id, user_id, created_at, expires_at
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(&request.code)
+        .bind(request.telegram_user_id)
+        .bind(&request.telegram_name)
+        .bind(request.chat_id)
+        .bind(&request.user_id)
+        .bind(request.created_at)
+        .bind(request.expires_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_by_code(&self, code: &str) -> Result<Option<LinkRequest>, DomainError> {
+        let row = sqlx::query_as::<_, LinkRequestRow>(
+            r#"
+            SELECT code, telegram_user_id, telegram_name, chat_id, user_id, created_at, expires_at
+            FROM link_requests
+            WHERE code = ?
+            "#,
+        )
+        .bind(code)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(row.map(LinkRequest::from))
+    }
+
+    async fn find_by_telegram_user(
+        &self,
+        telegram_user_id: i64,
+    ) -> Result<Option<LinkRequest>, DomainError> {
+        let row = sqlx::query_as::<_, LinkRequestRow>(
+            r#"
+            SELECT code, telegram_user_id, telegram_name, chat_id, user_id, created_at, expires_at
+            FROM link_requests
+            WHERE telegram_user_id = ?
+            "#,
+        )
+        .bind(telegram_user_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(row.map(LinkRequest::from))
+    }
+
+    async fn delete(&self, code: &str) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM link_requests WHERE code = ?")
+            .bind(code)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,68 @@
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use sqlx::SqlitePool;
+use crate::domain::ports::ReminderRepository;
+use crate::domain::value_objects::{FizzyId, ReminderThreshold};
+use crate::domain::errors::DomainError;
+
+pub struct SqliteReminderRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteReminderRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ReminderRepository for SqliteReminderRepository {
+    async fn was_sent(
+        &self,
+        card_id: &FizzyId,
+        telegram_user_id: i64,
+        due_on: NaiveDate,
+        threshold: ReminderThreshold,
+    ) -> Result<bool, DomainError> {
+        let count: i64 = sqlx::query_scalar(
+            r#"
+            SELECT COUNT(*) FROM sent_reminders
+            WHERE card_id = ? AND telegram_user_id = ? AND due_on = ? AND threshold = ?
+            "#,
+        )
+        .bind(card_id)
+        .bind(telegram_user_id)
+        .bind(due_on)
+        .bind(threshold.as_str())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(count > 0)
+    }
+
+    async fn mark_sent(
+        &self,
+        card_id: &FizzyId,
+        telegram_user_id: i64,
+        due_on: NaiveDate,
+        threshold: ReminderThreshold,
+    ) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT OR IGNORE INTO sent_reminders (card_id, telegram_user_id, due_on, threshold, sent_at)
+            VALUES (?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(card_id)
+        .bind(telegram_user_id)
+        .bind(due_on)
+        .bind(threshold.as_str())
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+//! Schema for the bot's own SQLite database.
+//!
+//! Fizzy's database belongs to the Rails app, so anything only the bot needs
+//! (user links, reminder bookkeeping, subscriptions...) lives in a separate
+//! file. Statements are idempotent and run on every startup.
+
+pub const STATE_SCHEMA: &[&str] = &[
+    r#"
+    CREATE TABLE IF NOT EXISTS user_links (
+        telegram_user_id INTEGER PRIMARY KEY,
+        user_id BLOB NOT NULL,
+        created_at DATETIME NOT NULL
+    )
+    "#,
+    r#"
+    CREATE TABLE IF NOT EXISTS link_requests (
+        code TEXT PRIMARY KEY,
+        telegram_user_id INTEGER NOT NULL UNIQUE,
+        telegram_name TEXT,
+        chat_id INTEGER NOT NULL,
+        user_id BLOB NOT NULL,
+        created_at DATETIME NOT NULL,
+        expires_at DATETIME NOT NULL
+    )
+    "#,
+    r#"
+    CREATE TABLE IF NOT EXISTS user_settings (
+        telegram_user_id INTEGER PRIMARY KEY,
+        timezone TEXT,
+        updated_at DATETIME NOT NULL
+    )
+    "#,
+    r#"
+    CREATE TABLE IF NOT EXISTS last_boards (
+        telegram_user_id INTEGER PRIMARY KEY,
+        board_id BLOB NOT NULL,
+        updated_at DATETIME NOT NULL
+    )
+    "#,
+    r#"
+    CREATE TABLE IF NOT EXISTS sent_reminders (
+        card_id BLOB NOT NULL,
+        telegram_user_id INTEGER NOT NULL,
+        due_on DATE NOT NULL,
+        threshold TEXT NOT NULL,
+        sent_at DATETIME NOT NULL,
+        PRIMARY KEY (card_id, telegram_user_id, due_on, threshold)
+    )
+    "#,
+    r#"
+    CREATE TABLE IF NOT EXISTS subscriptions (
+        chat_id INTEGER NOT NULL,
+        target_type TEXT NOT NULL,
+        target_id BLOB NOT NULL,
+        created_at DATETIME NOT NULL,
+        PRIMARY KEY (chat_id, target_type, target_id)
+    )
+    "#,
+    r#"
+    CREATE TABLE IF NOT EXISTS event_positions (
+        name TEXT PRIMARY KEY,
+        created_at DATETIME NOT NULL,
+        updated_at DATETIME NOT NULL
+    )
+    "#,
+    r#"
+    CREATE TABLE IF NOT EXISTS handled_events (
+        event_id BLOB PRIMARY KEY,
+        created_at DATETIME NOT NULL
+    )
+    "#,
+    r#"
+    CREATE TABLE IF NOT EXISTS digest_schedules (
+        chat_id INTEGER PRIMARY KEY,
+        user_id BLOB NOT NULL,
+        send_at TEXT NOT NULL,
+        timezone TEXT NOT NULL,
+        last_sent_on DATE,
+        updated_at DATETIME NOT NULL
+    )
+    "#,
+    r#"
+    CREATE TABLE IF NOT EXISTS card_messages (
+        chat_id INTEGER NOT NULL,
+        message_id INTEGER NOT NULL,
+        card_number INTEGER NOT NULL,
+        created_at DATETIME NOT NULL,
+        PRIMARY KEY (chat_id, message_id)
+    )
+    "#,
+];
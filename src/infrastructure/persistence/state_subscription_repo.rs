@@ -0,0 +1,179 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, SqlitePool};
+use crate::domain::entities::{Subscription, SubscriptionTarget};
+use crate::domain::ports::SubscriptionRepository;
+use crate::domain::value_objects::FizzyId;
+use crate::domain::errors::DomainError;
+
+/// Watermark row used for subscription notifications
+const WATERMARK_NAME: &str = "subscriptions";
+
+pub struct SqliteSubscriptionRepository {
+    pool: SqlitePool,
+}
+
+/// Raw row from the subscriptions table
+#[derive(Debug, FromRow)]
+struct SubscriptionRow {
+    chat_id: i64,
+    target_type: String,
+    target_id: FizzyId,
+    created_at: DateTime<Utc>,
+}
+
+impl SubscriptionRow {
+    fn into_subscription(self) -> Result<Subscription, DomainError> {
+        let target = match self.target_type.as_str() {
+            "board" => SubscriptionTarget::Board(self.target_id),
+            "card" => SubscriptionTarget::Card(self.target_id),
+            other => {
+                return Err(DomainError::InfrastructureError(format!(
+                    "Unknown subscription target type: {}",
+                    other
+                )))
+            }
+        };
+
+        Ok(Subscription {
+            chat_id: self.chat_id,
+            target,
+            created_at: self.created_at,
+        })
+    }
+}
+
+impl SqliteSubscriptionRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SubscriptionRepository for SqliteSubscriptionRepository {
+    async fn subscribe(&self, chat_id: i64, target: &SubscriptionTarget) -> Result<bool, DomainError> {
+        let result = sqlx::query(
+            r#"
+            INSERT OR IGNORE INTO subscriptions (chat_id, target_type, target_id, created_at)
+            VALUES (?, ?, ?, ?)
+            "#,
+        )
+        .bind(chat_id)
+        .bind(target.type_str())
+        .bind(target.id())
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn unsubscribe(&self, chat_id: i64, target: &SubscriptionTarget) -> Result<bool, DomainError> {
+        let result = sqlx::query(
+            "DELETE FROM subscriptions WHERE chat_id = ? AND target_type = ? AND target_id = ?",
+        )
+        .bind(chat_id)
+        .bind(target.type_str())
+        .bind(target.id())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn list_for_chat(&self, chat_id: i64) -> Result<Vec<Subscription>, DomainError> {
+        let rows = sqlx::query_as::<_, SubscriptionRow>(
+            r#"
+            SELECT chat_id, target_type, target_id, created_at
+            FROM subscriptions
+            WHERE chat_id = ?
+            ORDER BY created_at ASC
+            "#,
+        )
+        .bind(chat_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        rows.into_iter().map(SubscriptionRow::into_subscription).collect()
+    }
+
+    async fn list_chats_for(
+        &self,
+        board_id: &FizzyId,
+        card_id: &FizzyId,
+    ) -> Result<Vec<i64>, DomainError> {
+        sqlx::query_scalar::<_, i64>(
+            r#"
+            SELECT DISTINCT chat_id
+            FROM subscriptions
+            WHERE (target_type = 'board' AND target_id = ?)
+                OR (target_type = 'card' AND target_id = ?)
+            "#,
+        )
+        .bind(board_id)
+        .bind(card_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))
+    }
+
+    async fn get_watermark(&self) -> Result<Option<DateTime<Utc>>, DomainError> {
+        sqlx::query_scalar::<_, DateTime<Utc>>("SELECT created_at FROM event_positions WHERE name = ?")
+            .bind(WATERMARK_NAME)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))
+    }
+
+    async fn set_watermark(&self, created_at: DateTime<Utc>) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO event_positions (name, created_at, updated_at)
+            VALUES (?, ?, ?)
+            ON CONFLICT (name) DO UPDATE SET
+                created_at = excluded.created_at,
+                updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(WATERMARK_NAME)
+        .bind(created_at)
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn list_handled_since(&self, since: DateTime<Utc>) -> Result<Vec<FizzyId>, DomainError> {
+        sqlx::query_scalar::<_, FizzyId>("SELECT event_id FROM handled_events WHERE created_at >= ?")
+            .bind(since)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))
+    }
+
+    async fn mark_handled(&self, event_id: &FizzyId, created_at: DateTime<Utc>) -> Result<(), DomainError> {
+        sqlx::query("INSERT OR IGNORE INTO handled_events (event_id, created_at) VALUES (?, ?)")
+            .bind(event_id)
+            .bind(created_at)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn prune_handled(&self, before: DateTime<Utc>) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM handled_events WHERE created_at < ?")
+            .bind(before)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(())
+    }
+}
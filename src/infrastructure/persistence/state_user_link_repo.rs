@@ -0,0 +1,89 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, SqlitePool};
+use crate::domain::entities::UserLink;
+use crate::domain::ports::UserLinkRepository;
+use crate::domain::value_objects::FizzyId;
+use crate::domain::errors::DomainError;
+
+pub struct SqliteUserLinkRepository {
+    pool: SqlitePool,
+}
+
+/// Raw row from the user_links table
+#[derive(Debug, FromRow)]
+struct UserLinkRow {
+    telegram_user_id: i64,
+    user_id: FizzyId,
+    created_at: DateTime<Utc>,
+}
+
+impl From<UserLinkRow> for UserLink {
+    fn from(row: UserLinkRow) -> Self {
+        UserLink {
+            telegram_user_id: row.telegram_user_id,
+            user_id: row.user_id,
+            created_at: row.created_at,
+        }
+    }
+}
+
+impl SqliteUserLinkRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl UserLinkRepository for SqliteUserLinkRepository {
+    async fn list(&self) -> Result<Vec<UserLink>, DomainError> {
+        let rows = sqlx::query_as::<_, UserLinkRow>(
+            "SELECT telegram_user_id, user_id, created_at FROM user_links",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(UserLink::from).collect())
+    }
+
+    async fn save(
+        &self,
+        telegram_user_id: i64,
+        user_id: &FizzyId,
+    ) -> Result<UserLink, DomainError> {
+        let now = Utc::now();
+
+        sqlx::query(
+            r#"
+            INSERT INTO user_links (telegram_user_id, user_id, created_at)
+            VALUES (?, ?, ?)
+            ON CONFLICT (telegram_user_id) DO UPDATE SET
+                user_id = excluded.user_id,
+                created_at = excluded.created_at
+            "#,
+        )
+        .bind(telegram_user_id)
+        .bind(user_id)
+        .bind(now)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(UserLink {
+            telegram_user_id,
+            user_id: user_id.clone(),
+            created_at: now,
+        })
+    }
+
+    async fn delete(&self, telegram_user_id: i64) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM user_links WHERE telegram_user_id = ?")
+            .bind(telegram_user_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(())
+    }
+}
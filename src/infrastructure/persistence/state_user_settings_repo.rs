@@ -0,0 +1,81 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::SqlitePool;
+use crate::domain::ports::UserSettingsRepository;
+use crate::domain::value_objects::FizzyId;
+use crate::domain::errors::DomainError;
+
+pub struct SqliteUserSettingsRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteUserSettingsRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl UserSettingsRepository for SqliteUserSettingsRepository {
+    async fn get_timezone(&self, telegram_user_id: i64) -> Result<Option<String>, DomainError> {
+        let timezone = sqlx::query_scalar::<_, Option<String>>(
+            "SELECT timezone FROM user_settings WHERE telegram_user_id = ?",
+        )
+        .bind(telegram_user_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(timezone.flatten())
+    }
+
+    async fn set_timezone(&self, telegram_user_id: i64, timezone: &str) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO user_settings (telegram_user_id, timezone, updated_at)
+            VALUES (?, ?, ?)
+            ON CONFLICT (telegram_user_id) DO UPDATE SET
+                timezone = excluded.timezone,
+                updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(telegram_user_id)
+        .bind(timezone)
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn get_last_board(&self, telegram_user_id: i64) -> Result<Option<FizzyId>, DomainError> {
+        sqlx::query_scalar::<_, FizzyId>(
+            "SELECT board_id FROM last_boards WHERE telegram_user_id = ?",
+        )
+        .bind(telegram_user_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))
+    }
+
+    async fn set_last_board(&self, telegram_user_id: i64, board_id: &FizzyId) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO last_boards (telegram_user_id, board_id, updated_at)
+            VALUES (?, ?, ?)
+            ON CONFLICT (telegram_user_id) DO UPDATE SET
+                board_id = excluded.board_id,
+                updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(telegram_user_id)
+        .bind(board_id)
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+//! In-memory stand-in for Fizzy's database, for repository tests.
+//!
+//! Only the tables and columns the repositories touch are created; the real
+//! schema belongs to the Rails app.
+
+use chrono::Utc;
+use sqlx::sqlite::{SqlitePool, SqlitePoolOptions};
+use crate::domain::value_objects::FizzyId;
+
+const FIZZY_TEST_SCHEMA: &[&str] = &[
+    "CREATE TABLE accounts (id BLOB PRIMARY KEY, name TEXT, cards_count INTEGER DEFAULT 0, created_at DATETIME, updated_at DATETIME)",
+    "CREATE TABLE identities (id BLOB PRIMARY KEY, email_address TEXT NOT NULL, created_at DATETIME, updated_at DATETIME)",
+    "CREATE TABLE users (id BLOB PRIMARY KEY, account_id BLOB, identity_id BLOB, name TEXT NOT NULL, role TEXT DEFAULT 'member', active BOOLEAN DEFAULT 1, created_at DATETIME, updated_at DATETIME)",
+    "CREATE TABLE boards (id BLOB PRIMARY KEY, account_id BLOB, creator_id BLOB, name TEXT, all_access BOOLEAN DEFAULT 0, created_at DATETIME, updated_at DATETIME)",
+    "CREATE TABLE accesses (id BLOB PRIMARY KEY, account_id BLOB, board_id BLOB, user_id BLOB, involvement TEXT DEFAULT 'access_only', accessed_at DATETIME, created_at DATETIME, updated_at DATETIME)",
+    "CREATE TABLE columns (id BLOB PRIMARY KEY, account_id BLOB, board_id BLOB, name TEXT, color TEXT, position INTEGER, created_at DATETIME, updated_at DATETIME)",
+    "CREATE TABLE cards (id BLOB PRIMARY KEY, account_id BLOB, board_id BLOB, column_id BLOB, creator_id BLOB, number INTEGER, title TEXT, status TEXT DEFAULT 'drafted', due_on DATE, last_active_at DATETIME, created_at DATETIME, updated_at DATETIME)",
+    "CREATE TABLE card_goldnesses (id BLOB PRIMARY KEY, account_id BLOB, card_id BLOB, created_at DATETIME, updated_at DATETIME)",
+    "CREATE TABLE card_not_nows (id BLOB PRIMARY KEY, account_id BLOB, card_id BLOB, user_id BLOB, created_at DATETIME, updated_at DATETIME)",
+    "CREATE TABLE closures (id BLOB PRIMARY KEY, account_id BLOB, card_id BLOB, user_id BLOB, created_at DATETIME, updated_at DATETIME)",
+    "CREATE TABLE comments (id BLOB PRIMARY KEY, account_id BLOB, card_id BLOB, creator_id BLOB, created_at DATETIME, updated_at DATETIME)",
+    "CREATE TABLE action_text_rich_texts (id BLOB PRIMARY KEY, account_id BLOB, record_type TEXT, record_id BLOB, name TEXT, body TEXT, created_at DATETIME, updated_at DATETIME)",
+    "CREATE TABLE assignments (id BLOB PRIMARY KEY, account_id BLOB, card_id BLOB, assignee_id BLOB, assigner_id BLOB, created_at DATETIME, updated_at DATETIME)",
+    "CREATE TABLE tags (id BLOB PRIMARY KEY, account_id BLOB, title TEXT, created_at DATETIME, updated_at DATETIME)",
+    "CREATE TABLE taggings (id BLOB PRIMARY KEY, account_id BLOB, card_id BLOB, tag_id BLOB, created_at DATETIME, updated_at DATETIME)",
+    "CREATE TABLE events (id BLOB PRIMARY KEY, account_id BLOB, board_id BLOB, eventable_id BLOB, eventable_type TEXT, creator_id BLOB, action TEXT, particulars JSON DEFAULT '{}', created_at DATETIME, updated_at DATETIME)",
+    "CREATE TABLE search_records (id INTEGER PRIMARY KEY, account_id BLOB, searchable_type TEXT, searchable_id BLOB, card_id BLOB, board_id BLOB, title TEXT, content TEXT, created_at DATETIME)",
+    "CREATE TABLE active_storage_blobs (id BLOB PRIMARY KEY, account_id BLOB, key TEXT, filename TEXT, content_type TEXT, metadata TEXT, service_name TEXT, byte_size INTEGER, checksum TEXT, created_at DATETIME)",
+    "CREATE TABLE active_storage_attachments (id BLOB PRIMARY KEY, account_id BLOB, name TEXT, record_type TEXT, record_id BLOB, blob_id BLOB, created_at DATETIME)",
+];
+
+/// A fresh in-memory database with the Fizzy tables
+pub async fn fizzy_pool() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    for statement in FIZZY_TEST_SCHEMA {
+        sqlx::query(statement).execute(&pool).await.unwrap();
+    }
+
+    pool
+}
+
+/// IDs of the rows created by [`seed_board`]
+pub struct Seed {
+    pub account_id: FizzyId,
+    pub user_id: FizzyId,
+    pub board_id: FizzyId,
+    pub column_id: FizzyId,
+}
+
+/// Create an account with one user, one all-access board and one column
+pub async fn seed_board(pool: &SqlitePool) -> Seed {
+    let seed = Seed {
+        account_id: FizzyId::generate(),
+        user_id: FizzyId::generate(),
+        board_id: FizzyId::generate(),
+        column_id: FizzyId::generate(),
+    };
+    let now = Utc::now();
+
+    sqlx::query("INSERT INTO accounts (id, name, created_at, updated_at) VALUES (?, 'Acme', ?, ?)")
+        .bind(&seed.account_id).bind(now).bind(now)
+        .execute(pool).await.unwrap();
+    sqlx::query("INSERT INTO users (id, account_id, name, created_at, updated_at) VALUES (?, ?, 'Alice', ?, ?)")
+        .bind(&seed.user_id).bind(&seed.account_id).bind(now).bind(now)
+        .execute(pool).await.unwrap();
+    sqlx::query("INSERT INTO boards (id, account_id, creator_id, name, all_access, created_at, updated_at) VALUES (?, ?, ?, 'Roadmap', 1, ?, ?)")
+        .bind(&seed.board_id).bind(&seed.account_id).bind(&seed.user_id).bind(now).bind(now)
+        .execute(pool).await.unwrap();
+    sqlx::query("INSERT INTO columns (id, account_id, board_id, name, color, position, created_at, updated_at) VALUES (?, ?, ?, 'Doing', 'blue', 0, ?, ?)")
+        .bind(&seed.column_id).bind(&seed.account_id).bind(&seed.board_id).bind(now).bind(now)
+        .execute(pool).await.unwrap();
+
+    seed
+}
@@ -0,0 +1,73 @@
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::{NaiveDate, Utc};
+use teloxide::prelude::*;
+use teloxide::types::ParseMode;
+
+use super::Scheduler;
+use crate::application::errors::ApplicationError;
+use crate::application::use_cases::BuildDigestInput;
+use crate::domain::entities::DigestSchedule;
+use crate::infrastructure::telegram::formatters::DigestFormatter;
+
+/// Schedules are minute-precise, so check once a minute
+const CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Send each chat its daily digest once its local send time has passed
+pub async fn run(scheduler: Arc<Scheduler>) {
+    let mut interval = tokio::time::interval(CHECK_INTERVAL);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    loop {
+        interval.tick().await;
+        if let Err(e) = send_due_digests(&scheduler).await {
+            tracing::error!("Error sending daily digests: {:?}", e);
+        }
+    }
+}
+
+async fn send_due_digests(scheduler: &Scheduler) -> Result<(), ApplicationError> {
+    let state = &scheduler.state;
+    let now = Utc::now();
+
+    for schedule in state.digest_schedules.list().await? {
+        let Some(today) = schedule.due_on(now) else {
+            continue;
+        };
+
+        if let Err(e) = send_digest(scheduler, &schedule, today).await {
+            tracing::warn!("Failed to build digest for chat {}: {:?}", schedule.chat_id, e);
+        }
+
+        // Mark it sent even if sending failed so a broken chat doesn't get retried every minute
+        state.digest_schedules.mark_sent(schedule.chat_id, today).await?;
+    }
+
+    Ok(())
+}
+
+async fn send_digest(
+    scheduler: &Scheduler,
+    schedule: &DigestSchedule,
+    today: NaiveDate,
+) -> Result<(), ApplicationError> {
+    let state = &scheduler.state;
+    let input = BuildDigestInput::for_day(
+        state.account_id(),
+        schedule.user_id.clone(),
+        today,
+        schedule.timezone,
+    );
+    let digest = state.build_digest.execute(input).await?;
+
+    if let Err(e) = scheduler
+        .bot
+        .send_message(ChatId(schedule.chat_id), DigestFormatter::format_digest(&digest))
+        .parse_mode(ParseMode::Html)
+        .await
+    {
+        tracing::warn!("Failed to send digest to chat {}: {}", schedule.chat_id, e);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use std::sync::Arc;
+use chrono::{Days, Utc};
+use teloxide::prelude::*;
+use teloxide::types::ParseMode;
+
+use super::Scheduler;
+use crate::application::errors::ApplicationError;
+use crate::application::use_cases::ListDueCardsInput;
+use crate::domain::value_objects::ReminderThreshold;
+use crate::infrastructure::telegram::formatters::CardFormatter;
+use crate::infrastructure::telegram::keyboards::card_actions_keyboard;
+
+/// Periodically push due-date reminders to the mapped Telegram users
+pub async fn run(scheduler: Arc<Scheduler>) {
+    let mut interval = tokio::time::interval(scheduler.state.config.scheduler.reminder_interval);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    loop {
+        interval.tick().await;
+        if let Err(e) = send_due_reminders(&scheduler).await {
+            tracing::error!("Error sending due-date reminders: {:?}", e);
+        }
+    }
+}
+
+async fn send_due_reminders(scheduler: &Scheduler) -> Result<(), ApplicationError> {
+    let state = &scheduler.state;
+
+    // Users ahead of UTC may already be a day further, so look one extra day out
+    // and decide per recipient, in their own timezone, which threshold was reached
+    let horizon = Utc::now().date_naive() + Days::new(2);
+    let output = state
+        .list_due_cards
+        .execute(ListDueCardsInput {
+            account_id: state.account_id(),
+            due_on_or_before: horizon,
+        })
+        .await?;
+
+    for due_card in output.cards {
+        let card = &due_card.card;
+        let Some(due_on) = card.due_on else {
+            continue;
+        };
+
+        for user_id in &due_card.recipient_ids {
+            for telegram_user_id in state.users.telegram_user_ids(user_id) {
+                let today = state.today_for(telegram_user_id).await;
+                let Some(threshold) = ReminderThreshold::reached(due_on, today) else {
+                    continue;
+                };
+
+                if scheduler
+                    .reminder_repository
+                    .was_sent(&card.id, telegram_user_id, due_on, threshold)
+                    .await?
+                {
+                    continue;
+                }
+
+                let sent = scheduler
+                    .bot
+                    .send_message(
+                        ChatId(telegram_user_id),
+                        CardFormatter::format_reminder(card, threshold, state.base_url()),
+                    )
+                    .parse_mode(ParseMode::Html)
+                    .reply_markup(card_actions_keyboard(card, CardFormatter::has_long_description(card), &[]))
+                    .await;
+
+                match sent {
+                    Ok(sent) => {
+                        state.remember_card_message(&sent, card.number).await;
+                        scheduler
+                            .reminder_repository
+                            .mark_sent(&card.id, telegram_user_id, due_on, threshold)
+                            .await?;
+                    }
+                    // Try again on the next tick (e.g. the user hasn't started a chat with the bot)
+                    Err(e) => tracing::warn!(
+                        "Failed to send reminder for card #{} to {}: {}",
+                        card.number,
+                        telegram_user_id,
+                        e
+                    ),
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
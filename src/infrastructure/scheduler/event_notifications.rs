@@ -0,0 +1,89 @@
+use std::sync::Arc;
+use chrono::{DateTime, Utc};
+use teloxide::prelude::*;
+use teloxide::types::ParseMode;
+use teloxide::RequestError;
+
+use super::Scheduler;
+use crate::application::errors::ApplicationError;
+use crate::application::use_cases::PollEventsInput;
+use crate::infrastructure::telegram::formatters::SubscriptionFormatter;
+
+/// Most events handled per tick; a backlog is worked off over several ticks
+const EVENTS_PER_POLL: i64 = 100;
+
+/// Periodically tail Fizzy's events table and notify subscribed chats
+pub async fn run(scheduler: Arc<Scheduler>) {
+    let mut interval = tokio::time::interval(scheduler.state.config.scheduler.event_poll_interval);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    loop {
+        interval.tick().await;
+        if let Err(e) = send_event_notifications(&scheduler).await {
+            tracing::error!("Error sending subscription notifications: {:?}", e);
+        }
+    }
+}
+
+async fn send_event_notifications(scheduler: &Scheduler) -> Result<(), ApplicationError> {
+    let state = &scheduler.state;
+
+    let output = state
+        .poll_events
+        .execute(PollEventsInput {
+            account_id: state.account_id(),
+            limit: EVENTS_PER_POLL,
+        })
+        .await?;
+
+    let Some(tailed_to) = output.tailed_to else {
+        return Ok(());
+    };
+
+    // Events whose notifications failed for a reason that may pass are read
+    // again on the next tick
+    let mut oldest_undelivered: Option<DateTime<Utc>> = None;
+
+    for notification in output.notifications {
+        let text = SubscriptionFormatter::format_notification(&notification, state.base_url());
+        let mut delivered = true;
+
+        for chat_id in &notification.chat_ids {
+            // Don't echo people's own actions back to them in their private chat
+            if state.users.resolve(*chat_id).as_ref() == Some(&notification.event.creator_id) {
+                continue;
+            }
+
+            match scheduler
+                .bot
+                .send_message(ChatId(*chat_id), text.clone())
+                .parse_mode(ParseMode::Html)
+                .await
+            {
+                Ok(sent) => state.remember_card_message(&sent, notification.card.number).await,
+                Err(e) => {
+                    tracing::warn!("Failed to notify chat {}: {}", chat_id, e);
+                    delivered &= !is_transient(&e);
+                }
+            }
+        }
+
+        if delivered {
+            state.poll_events.mark_delivered(&notification.event).await?;
+        } else {
+            let created_at = notification.event.created_at;
+            oldest_undelivered = Some(oldest_undelivered.map_or(created_at, |oldest| oldest.min(created_at)));
+        }
+    }
+
+    state.poll_events.advance(tailed_to, oldest_undelivered).await
+}
+
+/// Whether a failed send is worth retrying. A chat that blocked the bot or
+/// no longer exists won't start accepting messages on the next tick.
+fn is_transient(error: &RequestError) -> bool {
+    matches!(
+        error,
+        RequestError::Network(_) | RequestError::RetryAfter(_) | RequestError::Io(_)
+    )
+}
@@ -0,0 +1,38 @@
+//! Background jobs running alongside the Telegram dispatcher.
+//!
+//! Each job is a loop spawned on the Tokio runtime. A failed run is logged
+//! and retried on the next tick; it never takes the bot down.
+
+mod digests;
+mod due_reminders;
+mod event_notifications;
+
+use std::sync::Arc;
+use teloxide::Bot;
+
+use crate::domain::ports::ReminderRepository;
+use crate::infrastructure::telegram::bot::BotState;
+
+pub struct Scheduler {
+    bot: Bot,
+    state: Arc<BotState>,
+    reminder_repository: Arc<dyn ReminderRepository>,
+}
+
+impl Scheduler {
+    pub fn new(
+        bot: Bot,
+        state: Arc<BotState>,
+        reminder_repository: Arc<dyn ReminderRepository>,
+    ) -> Self {
+        Self { bot, state, reminder_repository }
+    }
+
+    /// Start all background jobs
+    pub fn spawn(self) {
+        let scheduler = Arc::new(self);
+        tokio::spawn(due_reminders::run(scheduler.clone()));
+        tokio::spawn(event_notifications::run(scheduler.clone()));
+        tokio::spawn(digests::run(scheduler));
+    }
+}
@@ -1,53 +1,201 @@
+use chrono::{Duration, NaiveDate, Utc};
+use chrono_tz::Tz;
 use std::sync::Arc;
 use teloxide::prelude::*;
 
 use crate::application::use_cases::{
-    AddCommentUseCase, CloseCardUseCase, CreateCardUseCase, GetCardDetailsUseCase,
-    ListBoardCardsUseCase, ListBoardsUseCase, ListMyCardsUseCase, MoveCardUseCase,
-    ReopenCardUseCase,
+    AddCommentUseCase, ApproveLinkUseCase, AssignCardUseCase, AttachFileUseCase,
+    BuildDigestUseCase, CloseCardUseCase, CreateCardUseCase, DeleteCommentUseCase,
+    FindCardsUseCase, GetBoardOverviewUseCase, GetCardDetailsUseCase, GetCardHistoryUseCase,
+    GetTriageCardUseCase, GildCardUseCase, ListBoardCardsUseCase, ListBoardsUseCase,
+    ListCommentsUseCase, ListDueCardsUseCase, ListGoldenCardsUseCase, ListMyCardsUseCase,
+    ListSubscriptionsUseCase, ListTagsUseCase, MoveCardUseCase, PollEventsUseCase,
+    PostponeCardUseCase, ReadAttachmentUseCase, ReopenCardUseCase, RequestLinkUseCase, SearchCardsUseCase,
+    SetDueDateUseCase, SubscribeUseCase, TagCardUseCase, ToggleCardTagUseCase,
+    UnassignCardUseCase, UntagCardUseCase, UpdateCardUseCase, UpdateCommentUseCase,
+    VerifyLinkUseCase,
+};
+use crate::domain::ports::{
+    AssignmentRepository, AttachmentRepository, BoardRepository, CardMessageRepository,
+    CardRepository, CommentRepository, DigestScheduleRepository, EventRepository,
+    LinkRequestRepository, SearchRepository, SubscriptionRepository, TagRepository,
+    UserRepository, UserSettingsRepository,
 };
-use crate::domain::ports::{BoardRepository, CardRepository, CommentRepository, EventRepository};
 use crate::domain::value_objects::FizzyId;
 use crate::infrastructure::config::AppConfig;
+use crate::infrastructure::telegram::user_directory::UserDirectory;
+
+/// How long replies to a card message keep acting on the card
+const CARD_MESSAGE_RETENTION_DAYS: i64 = 30;
 
 /// Shared state for the bot handlers
 #[derive(Clone)]
 pub struct BotState {
     pub config: Arc<AppConfig>,
+    // Telegram -> Fizzy user mappings
+    pub users: Arc<UserDirectory>,
     // Use cases for Phase 2 (Read)
     pub list_my_cards: Arc<ListMyCardsUseCase>,
     pub get_card_details: Arc<GetCardDetailsUseCase>,
+    pub get_card_history: Arc<GetCardHistoryUseCase>,
+    pub list_comments: Arc<ListCommentsUseCase>,
     pub list_boards: Arc<ListBoardsUseCase>,
     pub list_board_cards: Arc<ListBoardCardsUseCase>,
+    pub get_board_overview: Arc<GetBoardOverviewUseCase>,
+    pub search_cards: Arc<SearchCardsUseCase>,
+    pub find_cards: Arc<FindCardsUseCase>,
+    pub list_golden_cards: Arc<ListGoldenCardsUseCase>,
+    pub get_triage_card: Arc<GetTriageCardUseCase>,
+    pub list_due_cards: Arc<ListDueCardsUseCase>,
+    pub subscribe: Arc<SubscribeUseCase>,
+    pub list_subscriptions: Arc<ListSubscriptionsUseCase>,
+    pub poll_events: Arc<PollEventsUseCase>,
+    pub build_digest: Arc<BuildDigestUseCase>,
     // Use cases for Phase 3 (Write)
     pub create_card: Arc<CreateCardUseCase>,
     pub close_card: Arc<CloseCardUseCase>,
     pub reopen_card: Arc<ReopenCardUseCase>,
     pub add_comment: Arc<AddCommentUseCase>,
+    pub update_comment: Arc<UpdateCommentUseCase>,
+    pub delete_comment: Arc<DeleteCommentUseCase>,
+    pub attach_file: Arc<AttachFileUseCase>,
+    pub read_attachment: Arc<ReadAttachmentUseCase>,
+    pub update_card: Arc<UpdateCardUseCase>,
     // Use cases for Phase 4 (Interactive UX)
     pub move_card: Arc<MoveCardUseCase>,
+    pub assign_card: Arc<AssignCardUseCase>,
+    pub unassign_card: Arc<UnassignCardUseCase>,
+    pub list_tags: Arc<ListTagsUseCase>,
+    pub tag_card: Arc<TagCardUseCase>,
+    pub untag_card: Arc<UntagCardUseCase>,
+    pub toggle_card_tag: Arc<ToggleCardTagUseCase>,
+    pub set_due_date: Arc<SetDueDateUseCase>,
+    pub gild_card: Arc<GildCardUseCase>,
+    pub postpone_card: Arc<PostponeCardUseCase>,
+    // Self-service account linking
+    pub request_link: Arc<RequestLinkUseCase>,
+    pub approve_link: Arc<ApproveLinkUseCase>,
+    pub verify_link: Arc<VerifyLinkUseCase>,
     // Repository for Phase 4 callbacks (to fetch columns)
     pub board_repository: Arc<dyn BoardRepository>,
+    // Listing and reading back files attached to cards
+    pub attachments: Arc<dyn AttachmentRepository>,
+    // Per-user preferences such as timezone
+    pub user_settings: Arc<dyn UserSettingsRepository>,
+    // Per-chat daily digest schedules
+    pub digest_schedules: Arc<dyn DigestScheduleRepository>,
+    // Which card each message sent by the bot refers to
+    pub card_messages: Arc<dyn CardMessageRepository>,
+}
+
+/// Repositories the bot's use cases are built from
+pub struct Repositories {
+    pub card: Arc<dyn CardRepository>,
+    pub board: Arc<dyn BoardRepository>,
+    pub comment: Arc<dyn CommentRepository>,
+    pub event: Arc<dyn EventRepository>,
+    pub search: Arc<dyn SearchRepository>,
+    pub user: Arc<dyn UserRepository>,
+    pub assignment: Arc<dyn AssignmentRepository>,
+    pub tag: Arc<dyn TagRepository>,
+    pub attachment: Arc<dyn AttachmentRepository>,
+    pub user_settings: Arc<dyn UserSettingsRepository>,
+    pub link_request: Arc<dyn LinkRequestRepository>,
+    pub subscription: Arc<dyn SubscriptionRepository>,
+    pub digest_schedule: Arc<dyn DigestScheduleRepository>,
+    pub card_messages: Arc<dyn CardMessageRepository>,
 }
 
 impl BotState {
-    pub fn new(
-        config: AppConfig,
-        card_repository: Arc<dyn CardRepository>,
-        board_repository: Arc<dyn BoardRepository>,
-        comment_repository: Arc<dyn CommentRepository>,
-        event_repository: Arc<dyn EventRepository>,
-    ) -> Self {
+    pub fn new(config: AppConfig, users: Arc<UserDirectory>, repositories: Repositories) -> Self {
+        let Repositories {
+            card: card_repository,
+            board: board_repository,
+            comment: comment_repository,
+            event: event_repository,
+            search: search_repository,
+            user: user_repository,
+            assignment: assignment_repository,
+            tag: tag_repository,
+            attachment: attachments,
+            user_settings,
+            link_request: link_request_repository,
+            subscription: subscription_repository,
+            digest_schedule: digest_schedules,
+            card_messages,
+        } = repositories;
+
         Self {
             config: Arc::new(config),
+            users,
             // Read use cases
             list_my_cards: Arc::new(ListMyCardsUseCase::new(card_repository.clone())),
             get_card_details: Arc::new(GetCardDetailsUseCase::new(card_repository.clone())),
+            list_comments: Arc::new(ListCommentsUseCase::new(
+                card_repository.clone(),
+                board_repository.clone(),
+                comment_repository.clone(),
+                user_repository.clone(),
+            )),
+            get_card_history: Arc::new(GetCardHistoryUseCase::new(
+                card_repository.clone(),
+                board_repository.clone(),
+                event_repository.clone(),
+                comment_repository.clone(),
+                user_repository.clone(),
+            )),
             list_boards: Arc::new(ListBoardsUseCase::new(board_repository.clone())),
             list_board_cards: Arc::new(ListBoardCardsUseCase::new(
                 card_repository.clone(),
                 board_repository.clone(),
             )),
+            get_board_overview: Arc::new(GetBoardOverviewUseCase::new(
+                card_repository.clone(),
+                board_repository.clone(),
+            )),
+            search_cards: Arc::new(SearchCardsUseCase::new(
+                search_repository,
+                card_repository.clone(),
+                board_repository.clone(),
+            )),
+            find_cards: Arc::new(FindCardsUseCase::new(
+                card_repository.clone(),
+                board_repository.clone(),
+            )),
+            list_golden_cards: Arc::new(ListGoldenCardsUseCase::new(
+                card_repository.clone(),
+                board_repository.clone(),
+            )),
+            get_triage_card: Arc::new(GetTriageCardUseCase::new(
+                card_repository.clone(),
+                board_repository.clone(),
+            )),
+            list_due_cards: Arc::new(ListDueCardsUseCase::new(
+                card_repository.clone(),
+                assignment_repository.clone(),
+            )),
+            subscribe: Arc::new(SubscribeUseCase::new(
+                subscription_repository.clone(),
+                board_repository.clone(),
+                card_repository.clone(),
+            )),
+            list_subscriptions: Arc::new(ListSubscriptionsUseCase::new(
+                subscription_repository.clone(),
+                board_repository.clone(),
+                card_repository.clone(),
+            )),
+            build_digest: Arc::new(BuildDigestUseCase::new(
+                card_repository.clone(),
+                board_repository.clone(),
+                event_repository.clone(),
+            )),
+            poll_events: Arc::new(PollEventsUseCase::new(
+                event_repository.clone(),
+                subscription_repository,
+                card_repository.clone(),
+                comment_repository.clone(),
+                board_repository.clone(),
+            )),
             // Write use cases
             create_card: Arc::new(CreateCardUseCase::new(
                 card_repository.clone(),
@@ -63,23 +211,121 @@ impl BotState {
                 event_repository.clone(),
             )),
             add_comment: Arc::new(AddCommentUseCase::new(
-                comment_repository,
+                comment_repository.clone(),
+                card_repository.clone(),
+                event_repository.clone(),
+            )),
+            update_comment: Arc::new(UpdateCommentUseCase::new(
+                comment_repository.clone(),
+                card_repository.clone(),
+                board_repository.clone(),
+                user_repository.clone(),
+                event_repository.clone(),
+            )),
+            delete_comment: Arc::new(DeleteCommentUseCase::new(
+                comment_repository.clone(),
+                card_repository.clone(),
+                board_repository.clone(),
+                user_repository.clone(),
+                event_repository.clone(),
+            )),
+            attach_file: Arc::new(AttachFileUseCase::new(
+                card_repository.clone(),
+                board_repository.clone(),
+                attachments.clone(),
+                event_repository.clone(),
+            )),
+            read_attachment: Arc::new(ReadAttachmentUseCase::new(
+                card_repository.clone(),
+                board_repository.clone(),
+                attachments.clone(),
+            )),
+            update_card: Arc::new(UpdateCardUseCase::new(
                 card_repository.clone(),
                 event_repository.clone(),
             )),
             // Phase 4 use cases
             move_card: Arc::new(MoveCardUseCase::new(
+                card_repository.clone(),
+                board_repository.clone(),
+                event_repository.clone(),
+            )),
+            assign_card: Arc::new(AssignCardUseCase::new(
+                card_repository.clone(),
+                board_repository.clone(),
+                user_repository.clone(),
+                assignment_repository.clone(),
+                event_repository.clone(),
+            )),
+            unassign_card: Arc::new(UnassignCardUseCase::new(
+                card_repository.clone(),
+                board_repository.clone(),
+                user_repository.clone(),
+                assignment_repository,
+                event_repository.clone(),
+            )),
+            list_tags: Arc::new(ListTagsUseCase::new(
+                card_repository.clone(),
+                tag_repository.clone(),
+            )),
+            tag_card: Arc::new(TagCardUseCase::new(
+                card_repository.clone(),
+                board_repository.clone(),
+                tag_repository.clone(),
+                event_repository.clone(),
+            )),
+            untag_card: Arc::new(UntagCardUseCase::new(
+                card_repository.clone(),
+                board_repository.clone(),
+                tag_repository.clone(),
+                event_repository.clone(),
+            )),
+            toggle_card_tag: Arc::new(ToggleCardTagUseCase::new(
+                card_repository.clone(),
+                board_repository.clone(),
+                tag_repository,
+                event_repository.clone(),
+            )),
+            set_due_date: Arc::new(SetDueDateUseCase::new(
+                card_repository.clone(),
+                board_repository.clone(),
+                event_repository.clone(),
+            )),
+            gild_card: Arc::new(GildCardUseCase::new(
+                card_repository.clone(),
+                board_repository.clone(),
+                event_repository.clone(),
+            )),
+            postpone_card: Arc::new(PostponeCardUseCase::new(
                 card_repository,
                 board_repository.clone(),
                 event_repository,
             )),
+            // Linking use cases
+            request_link: Arc::new(RequestLinkUseCase::new(
+                user_repository.clone(),
+                link_request_repository.clone(),
+            )),
+            approve_link: Arc::new(ApproveLinkUseCase::new(
+                user_repository.clone(),
+                link_request_repository.clone(),
+            )),
+            verify_link: Arc::new(VerifyLinkUseCase::new(
+                user_repository,
+                comment_repository,
+                link_request_repository,
+            )),
             board_repository,
+            attachments,
+            user_settings,
+            digest_schedules,
+            card_messages,
         }
     }
 
     /// Check if a Telegram user is authorized to use the bot
-    pub fn is_authorized(&self, user_id: i64) -> bool {
-        self.config.telegram.is_user_allowed(user_id)
+    pub fn is_authorized(&self, telegram_user_id: i64) -> bool {
+        self.users.resolve(telegram_user_id).is_some()
     }
 
     /// Get the configured account ID
@@ -87,9 +333,16 @@ impl BotState {
         FizzyId::new(self.config.fizzy.account_id.clone())
     }
 
-    /// Get the configured user ID (for single-user mode)
-    pub fn user_id(&self) -> FizzyId {
-        FizzyId::new(self.config.fizzy.user_id.clone())
+    /// Get the Fizzy user a Telegram user acts as
+    pub fn user_id(&self, telegram_user_id: i64) -> Option<FizzyId> {
+        self.users.resolve(telegram_user_id)
+    }
+
+    /// Get the Fizzy user for the sender of a message
+    pub fn user_for_message(&self, msg: &Message) -> Option<FizzyId> {
+        msg.from
+            .as_ref()
+            .and_then(|user| self.user_id(user.id.0 as i64))
     }
 
     /// Get the base URL for Fizzy web UI
@@ -97,7 +350,46 @@ impl BotState {
         self.config.fizzy.base_url.as_deref()
     }
 
-    /// Get the default board ID for card creation
+    /// Get the timezone a Telegram user has chosen, falling back to the account default
+    pub async fn timezone_for(&self, telegram_user_id: i64) -> Tz {
+        match self.user_settings.get_timezone(telegram_user_id).await {
+            Ok(Some(timezone)) => timezone.parse().unwrap_or(self.config.fizzy.timezone),
+            Ok(None) => self.config.fizzy.timezone,
+            Err(e) => {
+                tracing::warn!("Failed to load timezone for {}: {}", telegram_user_id, e);
+                self.config.fizzy.timezone
+            }
+        }
+    }
+
+    /// Get today's date in a Telegram user's timezone
+    pub async fn today_for(&self, telegram_user_id: i64) -> NaiveDate {
+        Utc::now().with_timezone(&self.timezone_for(telegram_user_id).await).date_naive()
+    }
+
+    /// Get today's date for the sender of a message
+    pub async fn today_for_message(&self, msg: &Message) -> NaiveDate {
+        let telegram_user_id = msg.from.as_ref().map(|u| u.id.0 as i64).unwrap_or(0);
+        self.today_for(telegram_user_id).await
+    }
+
+    /// Remember that a message sent by the bot is about a card, so replies to it act on the card
+    pub async fn remember_card_message(&self, message: &Message, card_number: i64) {
+        if let Err(e) = self
+            .card_messages
+            .remember(message.chat.id.0, message.id.0, card_number)
+            .await
+        {
+            tracing::warn!("Failed to remember message for card #{}: {}", card_number, e);
+        }
+
+        let cutoff = Utc::now() - Duration::days(CARD_MESSAGE_RETENTION_DAYS);
+        if let Err(e) = self.card_messages.prune_before(cutoff).await {
+            tracing::warn!("Failed to forget old card messages: {}", e);
+        }
+    }
+
+    /// Get the default board ID, used by /triage when no board is named
     pub fn default_board_id(&self) -> FizzyId {
         FizzyId::new(self.config.fizzy.default_board_id.clone())
     }
@@ -112,7 +404,7 @@ pub fn create_bot(config: &AppConfig) -> Bot {
 #[command(rename_rule = "lowercase", description = "Available commands:")]
 pub enum Command {
     #[command(description = "Start the bot and show welcome message")]
-    Start,
+    Start { payload: String },
 
     #[command(description = "Show this help message")]
     Help,
@@ -126,13 +418,13 @@ pub enum Command {
     #[command(description = "List accessible boards")]
     Boards,
 
-    #[command(description = "Show cards in a board")]
+    #[command(description = "Show a board's cards by column")]
     Board { name: String },
 
     #[command(description = "Show card details")]
     Card { number: i64 },
 
-    #[command(description = "Create a new card")]
+    #[command(description = "Create a card: /create [@Board] <title>")]
     Create { title: String },
 
     #[command(description = "Close a card")]
@@ -143,6 +435,75 @@ pub enum Command {
 
     #[command(description = "Add a comment to a card", parse_with = parse_comment_args)]
     Comment { number: i64, text: String },
+
+    #[command(description = "Show a card's comments")]
+    Comments { number: i64 },
+
+    #[command(description = "Show a card's activity timeline")]
+    History { number: i64 },
+
+    #[command(description = "Search cards by title, description and comments")]
+    Search { terms: String },
+
+    #[command(description = "Edit a card's title or description")]
+    Edit { number: i64 },
+
+    #[command(description = "Cancel the current edit")]
+    Cancel,
+
+    #[command(description = "Assign someone to a card: /assign <number> <name>", parse_with = parse_card_args)]
+    Assign { number: i64, name: String },
+
+    #[command(description = "Remove an assignee: /unassign <number> [name]", parse_with = parse_card_args)]
+    Unassign { number: i64, name: String },
+
+    #[command(description = "Tag a card: /tag <number> <tag...>", parse_with = parse_card_args)]
+    Tag { number: i64, tags: String },
+
+    #[command(description = "Remove tags from a card: /untag <number> <tag...>", parse_with = parse_card_args)]
+    Untag { number: i64, tags: String },
+
+    #[command(description = "List tags, or toggle a card's tags: /tags [number]")]
+    Tags { args: String },
+
+    #[command(description = "Set a due date: /due <number> <tomorrow|fri|next week|in 3 days|2026-11-02|none>", parse_with = parse_card_args)]
+    Due { number: i64, when: String },
+
+    #[command(description = "Show or set your timezone: /timezone [Europe/Berlin]")]
+    Timezone { zone: String },
+
+    #[command(description = "Mark a card as golden")]
+    Gild { number: i64 },
+
+    #[command(description = "Remove a card's golden mark")]
+    Ungild { number: i64 },
+
+    #[command(description = "List golden cards")]
+    Golden,
+
+    #[command(description = "Move a card to Not now")]
+    NotNow { number: i64 },
+
+    #[command(description = "Bring a postponed card back")]
+    Resume { number: i64 },
+
+    #[command(description = "Show postponed cards in a board")]
+    Postponed { name: String },
+
+    #[command(description = "Triage cards awaiting a column (default board if none given)")]
+    Triage { name: String },
+
+    #[command(description = "Get updates in this chat: /subscribe board <name> | card <number>")]
+    Subscribe { args: String },
+
+    #[command(description = "Stop updates: /unsubscribe board <name> | card <number>")]
+    Unsubscribe { args: String },
+
+    #[command(description = "Daily digest: /digest <HH:MM> [timezone] | off | now")]
+    Digest { args: String },
+
+    #[command(description = "Link your Telegram account: /link <email> or /link verify")]
+    Link { args: String },
 }
 
 /// Custom parser for /comment command: takes number and rest of text
@@ -181,3 +542,25 @@ fn parse_comment_args(input: String) -> Result<(i64, String), teloxide::utils::c
 
     Ok((number, text.to_string()))
 }
+
+/// Parser for commands taking a card number and optional trailing text
+fn parse_card_args(input: String) -> Result<(i64, String), teloxide::utils::command::ParseError> {
+    let mut parts = input.trim().splitn(2, ' ');
+
+    let number: i64 = parts
+        .next()
+        .filter(|s| !s.is_empty())
+        .ok_or_else(|| teloxide::utils::command::ParseError::TooFewArguments {
+            expected: 1,
+            found: 0,
+            message: "A card number is required".to_string(),
+        })?
+        .parse()
+        .map_err(|_| {
+            teloxide::utils::command::ParseError::IncorrectFormat(
+                "Card number must be a valid integer".into(),
+            )
+        })?;
+
+    Ok((number, parts.next().unwrap_or_default().trim().to_string()))
+}
@@ -1,4 +1,10 @@
+use chrono::NaiveDate;
+
+use crate::application::use_cases::{BoardSection, GetBoardOverviewOutput, SectionOverview};
 use crate::domain::entities::Board;
+use crate::infrastructure::text::escape_html;
+
+use super::CardFormatter;
 
 pub struct BoardFormatter;
 
@@ -29,10 +35,59 @@ impl BoardFormatter {
 
         lines.join("\n")
     }
-}
 
-fn escape_html(s: &str) -> String {
-    s.replace('&', "&amp;")
-        .replace('<', "&lt;")
-        .replace('>', "&gt;")
+    /// Format a board's cards grouped by column, kanban style
+    pub fn format_overview(overview: &GetBoardOverviewOutput, today: NaiveDate) -> String {
+        let open: i64 = overview
+            .sections
+            .iter()
+            .filter(|s| matches!(s.section, BoardSection::AwaitingTriage | BoardSection::Column(_)))
+            .map(|s| s.count)
+            .sum();
+
+        let mut parts = vec![format!(
+            "🗂 <b>{}</b> · {} open card{}",
+            escape_html(&overview.board.name),
+            open,
+            if open == 1 { "" } else { "s" }
+        )];
+
+        // Not now and Done share a footer line
+        let mut footer = Vec::new();
+
+        for section in &overview.sections {
+            let heading = format!("<b>{}</b> ({})", escape_html(&Self::section_label(section)), section.count);
+
+            match section.section {
+                BoardSection::NotNow | BoardSection::Done => footer.push(heading),
+                _ if section.is_collapsed() => parts.push(format!(
+                    "{}\n<i>Too many to list — tap below to expand</i>",
+                    heading
+                )),
+                _ if section.cards.is_empty() => parts.push(heading),
+                _ => parts.push(format!(
+                    "{}\n{}",
+                    heading,
+                    CardFormatter::format_card_list(&section.cards, today)
+                )),
+            }
+        }
+
+        if !footer.is_empty() {
+            parts.push(footer.join(" · "));
+        }
+
+        parts.join("\n\n")
+    }
+
+    /// Name of a board section, with its column color or an icon
+    pub fn section_label(section: &SectionOverview) -> String {
+        match (&section.section, &section.column) {
+            (BoardSection::AwaitingTriage, _) => "📥 Awaiting triage".to_string(),
+            (BoardSection::Column(_), Some(column)) => column.formatted_name(),
+            (BoardSection::NotNow, _) => "⏸️ Not now".to_string(),
+            (BoardSection::Done, _) => "✅ Done".to_string(),
+            _ => "📁 Open".to_string(),
+        }
+    }
 }
@@ -1,16 +1,23 @@
-use crate::domain::entities::Card;
+use chrono::NaiveDate;
+
+use crate::application::use_cases::SearchResult;
+use crate::domain::entities::{Attachment, Card, DueStatus};
+use crate::domain::value_objects::ReminderThreshold;
+use crate::infrastructure::telegram::rich_text;
+use crate::infrastructure::text::escape_html;
+
+/// Description characters shown in the card view before "Expand" is needed
+const DESCRIPTION_PREVIEW_CHARS: usize = 300;
+
+/// Description characters shown when expanded, keeping within Telegram's message limit
+const DESCRIPTION_MAX_CHARS: usize = 3500;
 
 pub struct CardFormatter;
 
 impl CardFormatter {
     /// Format a single card for display
     pub fn format_card(card: &Card, base_url: Option<&str>) -> String {
-        let mut lines = vec![format!(
-            "{} <b>#{}</b> {}",
-            card.status.emoji(),
-            card.number,
-            escape_html(&card.title)
-        )];
+        let mut lines = vec![Self::format_card_heading(card)];
 
         if let Some(board_name) = &card.board_name {
             lines.push(format!("Board: {}", escape_html(board_name)));
@@ -23,11 +30,13 @@ impl CardFormatter {
         lines.push(format!("Status: {}", card.status.display_name()));
 
         if !card.assignee_names.is_empty() {
-            lines.push(format!("Assignees: {}", card.assignee_names.join(", ")));
+            let names: Vec<String> = card.assignee_names.iter().map(|name| escape_html(name)).collect();
+            lines.push(format!("Assignees: {}", names.join(", ")));
         }
 
         if !card.tag_titles.is_empty() {
-            lines.push(format!("Tags: {}", card.tag_titles.join(", ")));
+            let titles: Vec<String> = card.tag_titles.iter().map(|title| escape_html(title)).collect();
+            lines.push(format!("Tags: {}", titles.join(", ")));
         }
 
         if let Some(due) = card.due_on {
@@ -38,6 +47,13 @@ impl CardFormatter {
             lines.push("⭐ Golden".to_string());
         }
 
+        if let Some(description) = &card.description {
+            let (preview, _) = rich_text::to_telegram_html(description, DESCRIPTION_PREVIEW_CHARS);
+            if !preview.is_empty() {
+                lines.push(format!("\n{}", preview));
+            }
+        }
+
         if let Some(url) = card.web_url(base_url) {
             lines.push(format!("\n<a href=\"{}\">Open in Fizzy</a>", url));
         }
@@ -45,8 +61,60 @@ impl CardFormatter {
         lines.join("\n")
     }
 
-    /// Format a list of cards
-    pub fn format_card_list(cards: &[Card]) -> String {
+    /// Format the list of files attached to a card
+    pub fn format_attachments(attachments: &[Attachment]) -> Option<String> {
+        if attachments.is_empty() {
+            return None;
+        }
+
+        let lines: Vec<String> = attachments
+            .iter()
+            .map(|attachment| {
+                let place = if attachment.comment_id.is_some() { ", in a comment" } else { "" };
+                format!(
+                    "• {} <i>({}{})</i>",
+                    escape_html(&attachment.filename),
+                    attachment.display_size(),
+                    place
+                )
+            })
+            .collect();
+
+        Some(format!("📎 <b>Attachments</b>\n{}", lines.join("\n")))
+    }
+
+    /// Whether the card view cuts the description short
+    pub fn has_long_description(card: &Card) -> bool {
+        card.description
+            .as_deref()
+            .is_some_and(|description| rich_text::to_telegram_html(description, DESCRIPTION_PREVIEW_CHARS).1)
+    }
+
+    /// Format a card's full description
+    pub fn format_description(card: &Card, base_url: Option<&str>) -> String {
+        let (description, truncated) = card
+            .description
+            .as_deref()
+            .map(|description| rich_text::to_telegram_html(description, DESCRIPTION_MAX_CHARS))
+            .unwrap_or_default();
+
+        let mut response = Self::format_card_heading(card);
+
+        if description.is_empty() {
+            response.push_str("\n\n<i>No description.</i>");
+        } else {
+            response.push_str(&format!("\n\n{}", description));
+        }
+
+        if let Some(url) = card.web_url(base_url).filter(|_| truncated) {
+            response.push_str(&format!("\n\n<a href=\"{}\">Read the rest in Fizzy</a>", url));
+        }
+
+        response
+    }
+
+    /// Format a list of cards, flagging overdue and due-soon ones
+    pub fn format_card_list(cards: &[Card], today: NaiveDate) -> String {
         if cards.is_empty() {
             return "No cards found.".to_string();
         }
@@ -54,9 +122,8 @@ impl CardFormatter {
         let lines: Vec<String> = cards
             .iter()
             .map(|card| {
-                let due_str = card
-                    .due_on
-                    .map(|d| format!(" 📅 {}", d))
+                let due_str = Self::format_due(card, today)
+                    .map(|due| format!(" {}", due))
                     .unwrap_or_default();
 
                 format!(
@@ -71,11 +138,134 @@ impl CardFormatter {
 
         lines.join("\n")
     }
+
+    /// Format search results, showing a matching excerpt under each card
+    pub fn format_search_results(query: &str, results: &[SearchResult], total: usize) -> String {
+        if results.is_empty() {
+            return format!("🔍 No cards match \"{}\".", escape_html(query));
+        }
+
+        let lines: Vec<String> = results
+            .iter()
+            .map(|result| {
+                let mut line = Self::format_card_heading(&result.card);
+                if let Some(board_name) = &result.card.board_name {
+                    line.push_str(&format!(" <i>({})</i>", escape_html(board_name)));
+                }
+                if let Some(snippet) = &result.snippet {
+                    line.push_str(&format!("\n    └ {}", escape_html(snippet)));
+                }
+                line
+            })
+            .collect();
+
+        format!(
+            "🔍 <b>Results for \"{}\"</b> ({})\n\n{}",
+            escape_html(query),
+            total,
+            lines.join("\n")
+        )
+    }
+
+    /// Format a card's due date relative to today
+    pub fn format_due(card: &Card, today: NaiveDate) -> Option<String> {
+        let due = card.due_on?;
+        let days_left = (due - today).num_days();
+
+        Some(match card.due_status(today) {
+            Some(DueStatus::Overdue) => format!("🔴 overdue since {}", due.format("%b %-d")),
+            Some(DueStatus::DueSoon) if days_left == 0 => "⏰ due today".to_string(),
+            Some(DueStatus::DueSoon) if days_left == 1 => "⏰ due tomorrow".to_string(),
+            Some(DueStatus::DueSoon) => format!("⏰ due {}", due.format("%a %b %-d")),
+            _ => format!("📅 {}", due),
+        })
+    }
+
+    /// Format a due-date reminder pushed by the scheduler
+    pub fn format_reminder(
+        card: &Card,
+        threshold: ReminderThreshold,
+        base_url: Option<&str>,
+    ) -> String {
+        let heading = match (threshold, card.due_on) {
+            (ReminderThreshold::DayBefore, _) => "⏰ <b>Due tomorrow</b>".to_string(),
+            (ReminderThreshold::DueToday, _) => "⏰ <b>Due today</b>".to_string(),
+            (ReminderThreshold::Overdue, Some(due)) => {
+                format!("🔴 <b>Overdue</b> since {}", due.format("%b %-d"))
+            }
+            (ReminderThreshold::Overdue, None) => "🔴 <b>Overdue</b>".to_string(),
+        };
+
+        format!("{}\n\n{}", heading, Self::format_card(card, base_url))
+    }
+
+    /// Format the one-line card heading (status, number and title)
+    pub fn format_card_heading(card: &Card) -> String {
+        format!(
+            "{} <b>#{}</b> {}",
+            card.status.emoji(),
+            card.number,
+            escape_html(&card.title)
+        )
+    }
+
+    /// Format a before/after preview of a pending field edit
+    pub fn format_edit_preview(
+        card: &Card,
+        field_name: &str,
+        before: Option<&str>,
+        after: &str,
+    ) -> String {
+        let before = match before {
+            Some(text) if !text.trim().is_empty() => escape_html(text),
+            _ => "<i>(empty)</i>".to_string(),
+        };
+
+        format!(
+            "{}\n\n<b>Current {}:</b>\n{}\n\n<b>New {}:</b>\n{}\n\nSave this change?",
+            Self::format_card_heading(card),
+            field_name,
+            before,
+            field_name,
+            escape_html(after)
+        )
+    }
 }
 
-/// Escape HTML special characters for Telegram
-fn escape_html(s: &str) -> String {
-    s.replace('&', "&amp;")
-        .replace('<', "&lt;")
-        .replace('>', "&gt;")
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use crate::domain::value_objects::{CardStatus, FizzyId};
+
+    #[test]
+    fn test_format_card_escapes_assignees_and_tags() {
+        let now = Utc::now();
+        let card = Card {
+            id: FizzyId::generate(),
+            account_id: FizzyId::generate(),
+            board_id: FizzyId::generate(),
+            column_id: None,
+            creator_id: FizzyId::generate(),
+            number: 12,
+            title: "Fix login".to_string(),
+            description: None,
+            status: CardStatus::Published,
+            due_on: None,
+            last_active_at: now,
+            created_at: now,
+            updated_at: now,
+            board_name: None,
+            column_name: None,
+            column_color: None,
+            creator_name: None,
+            assignee_names: vec!["Bob <ops>".to_string()],
+            tag_titles: vec!["a<b".to_string(), "r&d".to_string()],
+            is_golden: false,
+        };
+
+        let text = CardFormatter::format_card(&card, None);
+        assert!(text.contains("Assignees: Bob &lt;ops&gt;"));
+        assert!(text.contains("Tags: a&lt;b, r&amp;d"));
+    }
 }
@@ -0,0 +1,61 @@
+use chrono::{DateTime, Utc};
+
+use crate::application::use_cases::ListCommentsOutput;
+use crate::domain::entities::Comment;
+use crate::infrastructure::text::{escape_html, truncate};
+
+use super::{CardFormatter, HistoryFormatter};
+
+/// Longest comment text shown per comment, keeping a page under Telegram's message limit
+const MAX_COMMENT_CHARS: usize = 600;
+
+pub struct CommentFormatter;
+
+impl CommentFormatter {
+    /// Format one page of a card's comments, numbered so the edit and delete
+    /// buttons can refer to them
+    pub fn format_comments(output: &ListCommentsOutput, now: DateTime<Utc>) -> String {
+        let heading = format!("💬 <b>Comments</b> · {}", CardFormatter::format_card_heading(&output.card));
+
+        if output.comments.is_empty() {
+            return if output.page == 0 {
+                format!(
+                    "{}\n\nNo comments yet.\nAdd one with <code>/comment {} your text</code>",
+                    heading, output.card.number
+                )
+            } else {
+                format!("{}\n\nNo older comments.", heading)
+            };
+        }
+
+        let comments: Vec<String> = output
+            .comments
+            .iter()
+            .enumerate()
+            .map(|(i, comment)| format!("{}. {}", i + 1, Self::format_comment(comment, now)))
+            .collect();
+
+        format!("{}\n\n{}", heading, comments.join("\n\n"))
+    }
+
+    /// Format a comment that is about to be deleted
+    pub fn format_delete_prompt(card_number: i64, comment: &Comment, now: DateTime<Utc>) -> String {
+        format!(
+            "🗑 Delete this comment from card #{}?\n\n{}",
+            card_number,
+            Self::format_comment(comment, now)
+        )
+    }
+
+    fn format_comment(comment: &Comment, now: DateTime<Utc>) -> String {
+        let author = escape_html(comment.creator_name.as_deref().unwrap_or("Someone"));
+        let text = html2text::from_read(comment.content.as_bytes(), 1000);
+
+        format!(
+            "<b>{}</b> · {}\n{}",
+            author,
+            HistoryFormatter::format_relative(comment.created_at, now),
+            escape_html(&truncate(text.trim(), MAX_COMMENT_CHARS))
+        )
+    }
+}
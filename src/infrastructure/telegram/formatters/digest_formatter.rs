@@ -0,0 +1,161 @@
+use crate::application::use_cases::Digest;
+use crate::domain::entities::{Card, DigestSchedule};
+use crate::infrastructure::text::truncate;
+
+use super::CardFormatter;
+
+/// Most cards listed per digest section
+const MAX_SECTION_CARDS: usize = 10;
+/// Longest card title shown in the digest
+const MAX_TITLE_CHARS: usize = 80;
+/// Length budget for the whole digest, below Telegram's 4096-character limit
+const MAX_DIGEST_CHARS: usize = 3800;
+/// Room kept free for a trailing "…and N more" line
+const MORE_LINE_CHARS: usize = 24;
+
+pub struct DigestFormatter;
+
+impl DigestFormatter {
+    /// Format the daily digest, leaving out empty sections
+    pub fn format_digest(digest: &Digest) -> String {
+        let heading = format!("☀️ <b>Daily digest</b> · {}", digest.today.format("%a %b %-d"));
+
+        if digest.is_empty() {
+            return format!("{}\n\nNothing to report. Enjoy your day!", heading);
+        }
+
+        let sections = [
+            ("🔴 Overdue", &digest.overdue),
+            ("⏰ Due today", &digest.due_today),
+            ("⭐ Golden", &digest.golden),
+            ("📥 Awaiting triage", &digest.awaiting_triage),
+            ("🆕 Created yesterday", &digest.created_yesterday),
+            ("✅ Closed yesterday", &digest.closed_yesterday),
+        ];
+
+        let mut used = heading.chars().count();
+        let mut parts = vec![heading];
+        let mut left_out = 0;
+        for (title, cards) in sections {
+            if cards.is_empty() {
+                continue;
+            }
+
+            let budget = MAX_DIGEST_CHARS.saturating_sub(used + 2 + MORE_LINE_CHARS);
+            match Self::format_section(title, cards, digest, budget) {
+                Some(section) if left_out == 0 => {
+                    used += section.chars().count() + 2;
+                    parts.push(section);
+                }
+                _ => left_out += cards.len(),
+            }
+        }
+
+        if left_out > 0 {
+            parts.push(format!("…and {} more cards", left_out));
+        }
+
+        parts.join("\n\n")
+    }
+
+    /// Format a chat's digest schedule
+    pub fn format_schedule(schedule: &DigestSchedule) -> String {
+        format!(
+            "📬 Daily digest at <b>{}</b> ({}).",
+            schedule.send_at.format("%H:%M"),
+            schedule.timezone.name()
+        )
+    }
+
+    /// Format one section within `budget` characters, or `None` if not even
+    /// its first card fits
+    fn format_section(
+        title: &str,
+        cards: &[Card],
+        digest: &Digest,
+        budget: usize,
+    ) -> Option<String> {
+        let mut section = format!("<b>{}</b> ({})", title, cards.len());
+        let mut shown = 0;
+
+        for card in cards.iter().take(MAX_SECTION_CARDS) {
+            let card = Card {
+                title: truncate(&card.title, MAX_TITLE_CHARS),
+                ..card.clone()
+            };
+            let line = CardFormatter::format_card_list(std::slice::from_ref(&card), digest.today);
+            if section.chars().count() + 1 + line.chars().count() + MORE_LINE_CHARS > budget {
+                break;
+            }
+
+            section.push('\n');
+            section.push_str(&line);
+            shown += 1;
+        }
+
+        if shown == 0 {
+            return None;
+        }
+
+        if cards.len() > shown {
+            section.push_str(&format!("\n…and {} more", cards.len() - shown));
+        }
+
+        Some(section)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, Utc};
+    use crate::domain::value_objects::{CardStatus, FizzyId};
+
+    fn card(number: i64, title: &str) -> Card {
+        let now = Utc::now();
+        Card {
+            id: FizzyId::generate(),
+            account_id: FizzyId::generate(),
+            board_id: FizzyId::generate(),
+            column_id: None,
+            creator_id: FizzyId::generate(),
+            number,
+            title: title.to_string(),
+            description: None,
+            status: CardStatus::Published,
+            due_on: None,
+            last_active_at: now,
+            created_at: now,
+            updated_at: now,
+            board_name: None,
+            column_name: None,
+            column_color: None,
+            creator_name: None,
+            assignee_names: Vec::new(),
+            tag_titles: Vec::new(),
+            is_golden: false,
+        }
+    }
+
+    #[test]
+    fn test_format_digest_stays_within_message_limit() {
+        let title = "Refactor <everything> & more ".repeat(20);
+        let cards: Vec<Card> = (1..=15).map(|n| card(n, &title)).collect();
+        let digest = Digest {
+            today: NaiveDate::from_ymd_opt(2026, 10, 17).unwrap(),
+            overdue: cards.clone(),
+            due_today: cards.clone(),
+            golden: cards.clone(),
+            created_yesterday: cards.clone(),
+            closed_yesterday: cards.clone(),
+            awaiting_triage: cards,
+        };
+
+        let text = DigestFormatter::format_digest(&digest);
+        assert!(text.chars().count() <= MAX_DIGEST_CHARS);
+        assert!(text.starts_with("☀️ <b>Daily digest</b>"));
+        assert!(text.contains("<b>🔴 Overdue</b> (15)"));
+        assert!(text.contains("…and"));
+        assert!(text.ends_with("more cards"));
+    }
+}
@@ -0,0 +1,124 @@
+use chrono::{DateTime, Utc};
+
+use crate::application::use_cases::{Activity, GetCardHistoryOutput, HistoryEntry};
+use crate::infrastructure::text::{escape_html, truncate};
+
+use super::CardFormatter;
+
+/// Longest comment excerpt shown in the timeline
+const MAX_COMMENT_CHARS: usize = 150;
+
+/// Longest name (person, column, board, card title) shown in an entry
+const MAX_NAME_CHARS: usize = 60;
+
+pub struct HistoryFormatter;
+
+impl HistoryFormatter {
+    /// Format one page of a card's activity timeline
+    pub fn format_history(history: &GetCardHistoryOutput, now: DateTime<Utc>) -> String {
+        let heading = format!("🕘 <b>History</b> · {}", CardFormatter::format_card_heading(&history.card));
+
+        if history.entries.is_empty() {
+            return if history.page == 0 {
+                format!("{}\n\nNo activity recorded yet.", heading)
+            } else {
+                format!("{}\n\nNo more activity.", heading)
+            };
+        }
+
+        let lines: Vec<String> = history
+            .entries
+            .iter()
+            .map(|entry| Self::format_entry(entry, now))
+            .collect();
+
+        format!("{}\n\n{}", heading, lines.join("\n\n"))
+    }
+
+    fn format_entry(entry: &HistoryEntry, now: DateTime<Utc>) -> String {
+        let who = Self::format_name(entry.event.creator_name.as_deref().unwrap_or("Someone"));
+        let when = Self::format_relative(entry.event.created_at, now);
+
+        let description = match &entry.activity {
+            Activity::Created => "🆕 created the card".to_string(),
+            Activity::Moved { column: Some(column) } => format!("📁 moved it to <b>{}</b>", Self::format_name(column)),
+            Activity::Moved { column: None } => "📁 moved it to another column".to_string(),
+            Activity::SentBackToTriage => "📥 sent it back to triage".to_string(),
+            Activity::BoardChanged { board: Some(board) } => {
+                format!("📋 moved it to board <b>{}</b>", Self::format_name(board))
+            }
+            Activity::BoardChanged { board: None } => "📋 moved it to another board".to_string(),
+            Activity::Closed => "✅ closed it".to_string(),
+            Activity::Reopened => "🔄 reopened it".to_string(),
+            Activity::Postponed => "💤 moved it to Not now".to_string(),
+            Activity::Resumed => "▶️ brought it back from Not now".to_string(),
+            Activity::AutoPostponed => "💤 it was moved to Not now after a period of inactivity".to_string(),
+            Activity::Commented { content: Some(_) } => "💬 commented".to_string(),
+            Activity::Commented { content: None } => "💬 commented (since deleted)".to_string(),
+            Activity::CommentEdited => "💬 edited a comment".to_string(),
+            Activity::CommentDeleted => "🗑 deleted a comment".to_string(),
+            Activity::Assigned(names) => format!("👤 assigned {}", Self::format_names(names)),
+            Activity::Unassigned(names) => format!("👤 unassigned {}", Self::format_names(names)),
+            Activity::DueDateChanged(Some(due_on)) => {
+                format!("⏰ set the due date to {}", due_on.format("%a %b %-d, %Y"))
+            }
+            Activity::DueDateChanged(None) => "⏰ removed the due date".to_string(),
+            Activity::TitleChanged { title: Some(title) } => {
+                format!("✏️ renamed it to <b>{}</b>", Self::format_name(title))
+            }
+            Activity::TitleChanged { title: None } => "✏️ renamed it".to_string(),
+            Activity::Updated => "✏️ edited it".to_string(),
+            Activity::Gilded => "⭐ marked it golden".to_string(),
+            Activity::Ungilded => "☆ removed golden".to_string(),
+            Activity::Tagged(titles) => format!("🏷 tagged it {}", Self::format_names(titles)),
+            Activity::Untagged(titles) => format!("🏷 removed {}", Self::format_names(titles)),
+            Activity::Other(action) => {
+                format!("• {}", escape_html(&action.trim_start_matches("card_").replace('_', " ")))
+            }
+        };
+
+        // Automatic actions have no meaningful actor
+        let mut line = if entry.activity == Activity::AutoPostponed {
+            format!("{} · {}", description, when)
+        } else {
+            format!("<b>{}</b> {} · {}", who, description, when)
+        };
+
+        if let Activity::Commented { content: Some(content) } = &entry.activity {
+            let text = html2text::from_read(content.as_bytes(), 1000);
+            line.push_str(&format!("\n<i>{}</i>", escape_html(&truncate(text.trim(), MAX_COMMENT_CHARS))));
+        }
+
+        line
+    }
+
+    fn format_names(names: &[String]) -> String {
+        if names.is_empty() {
+            return "someone".to_string();
+        }
+        escape_html(&truncate(&names.join(", "), MAX_NAME_CHARS))
+    }
+
+    fn format_name(name: &str) -> String {
+        escape_html(&truncate(name, MAX_NAME_CHARS))
+    }
+
+    /// Format a past time relative to now, e.g. "5 min ago" or "yesterday"
+    pub fn format_relative(at: DateTime<Utc>, now: DateTime<Utc>) -> String {
+        let elapsed = now.signed_duration_since(at);
+
+        if elapsed.num_minutes() < 1 {
+            "just now".to_string()
+        } else if elapsed.num_hours() < 1 {
+            format!("{} min ago", elapsed.num_minutes())
+        } else if elapsed.num_days() < 1 {
+            format!("{} h ago", elapsed.num_hours())
+        } else if elapsed.num_days() < 2 {
+            "yesterday".to_string()
+        } else if elapsed.num_days() < 30 {
+            format!("{} days ago", elapsed.num_days())
+        } else {
+            at.format("%b %-d, %Y").to_string()
+        }
+    }
+}
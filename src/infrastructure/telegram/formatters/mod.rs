@@ -1,7 +1,17 @@
 mod card_formatter;
 mod board_formatter;
+mod comment_formatter;
+mod digest_formatter;
 mod error_formatter;
+mod history_formatter;
+mod subscription_formatter;
+mod tag_formatter;
 
 pub use card_formatter::CardFormatter;
 pub use board_formatter::BoardFormatter;
+pub use comment_formatter::CommentFormatter;
+pub use digest_formatter::DigestFormatter;
 pub use error_formatter::ErrorFormatter;
+pub use history_formatter::HistoryFormatter;
+pub use subscription_formatter::SubscriptionFormatter;
+pub use tag_formatter::TagFormatter;
@@ -0,0 +1,78 @@
+use crate::application::use_cases::{EventNotification, SubscribedTarget};
+use crate::domain::entities::NotificationKind;
+use crate::domain::ports::event_actions;
+use crate::infrastructure::text::{escape_html, truncate};
+
+use super::CardFormatter;
+
+/// Longest comment excerpt included in a notification
+const MAX_COMMENT_CHARS: usize = 300;
+
+pub struct SubscriptionFormatter;
+
+impl SubscriptionFormatter {
+    /// Format a board or card as the object of a subscription message
+    pub fn format_target(target: &SubscribedTarget) -> String {
+        match target {
+            SubscribedTarget::Board(board) => format!("board <b>{}</b>", escape_html(&board.name)),
+            SubscribedTarget::Card(card) => format!(
+                "card <b>#{}</b> {}",
+                card.number,
+                escape_html(&card.title)
+            ),
+        }
+    }
+
+    /// Format the list of a chat's subscriptions
+    pub fn format_subscriptions(targets: &[SubscribedTarget]) -> String {
+        if targets.is_empty() {
+            return "🔕 This chat has no subscriptions.\n\nUse /subscribe board <name> or /subscribe card <number>.".to_string();
+        }
+
+        let lines: Vec<String> = targets
+            .iter()
+            .map(|target| format!("• {}", Self::format_target(target)))
+            .collect();
+
+        format!("🔔 <b>Subscriptions</b> ({})\n\n{}", targets.len(), lines.join("\n"))
+    }
+
+    /// Format a notification about card activity
+    pub fn format_notification(notification: &EventNotification, base_url: Option<&str>) -> String {
+        let card = &notification.card;
+        let who = escape_html(notification.event.creator_name.as_deref().unwrap_or("Someone"));
+        let board = escape_html(card.board_name.as_deref().unwrap_or("a board"));
+
+        let action = match notification.kind {
+            NotificationKind::Created => format!("🆕 <b>{}</b> added a card to {}", who, board),
+            NotificationKind::Moved => match (notification.event.action.as_str(), &notification.moved_to) {
+                (event_actions::CARD_SENT_BACK_TO_TRIAGE, _) => {
+                    format!("📥 <b>{}</b> sent a card back to triage", who)
+                }
+                (event_actions::CARD_BOARD_CHANGED, None) => {
+                    format!("📁 <b>{}</b> moved a card to {}", who, board)
+                }
+                (_, Some(destination)) => {
+                    format!("📁 <b>{}</b> moved a card to {}", who, escape_html(destination))
+                }
+                (_, None) => format!("📁 <b>{}</b> moved a card", who),
+            },
+            NotificationKind::Closed => format!("✅ <b>{}</b> closed a card", who),
+            NotificationKind::Reopened => format!("🔄 <b>{}</b> reopened a card", who),
+            NotificationKind::Commented => format!("💬 <b>{}</b> commented", who),
+        };
+
+        let mut lines = vec![action, CardFormatter::format_card_heading(card)];
+
+        if let Some(comment) = &notification.comment {
+            let text = html2text::from_read(comment.content.as_bytes(), 1000);
+            lines.push(format!("\n<i>{}</i>", escape_html(&truncate(text.trim(), MAX_COMMENT_CHARS))));
+        }
+
+        if let Some(url) = card.web_url(base_url) {
+            lines.push(format!("\n<a href=\"{}\">Open in Fizzy</a>", url));
+        }
+
+        lines.join("\n")
+    }
+}
@@ -0,0 +1,33 @@
+use crate::domain::entities::Tag;
+use crate::infrastructure::text::escape_html;
+
+pub struct TagFormatter;
+
+impl TagFormatter {
+    /// Format the account's tags
+    pub fn format_tag_list(tags: &[Tag]) -> String {
+        if tags.is_empty() {
+            return "No tags yet. Add one with /tag <number> <tag>.".to_string();
+        }
+
+        let titles: Vec<String> = tags
+            .iter()
+            .map(|tag| format!("#{}", escape_html(&tag.title)))
+            .collect();
+
+        format!("🏷 <b>Tags</b> ({})\n\n{}", tags.len(), titles.join("  "))
+    }
+
+    /// Format a card's tags as a short inline list
+    pub fn format_titles(titles: &[String]) -> String {
+        if titles.is_empty() {
+            return "none".to_string();
+        }
+
+        titles
+            .iter()
+            .map(|title| format!("#{}", escape_html(title)))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
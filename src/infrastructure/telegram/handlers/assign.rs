@@ -0,0 +1,159 @@
+use std::sync::Arc;
+use teloxide::prelude::*;
+
+use crate::application::use_cases::{
+    AssignCardInput, AssignCardOutput, AssigneeRef, UnassignCardInput, UnassignCardOutput,
+};
+use crate::domain::value_objects::FizzyId;
+use crate::infrastructure::telegram::bot::BotState;
+use crate::infrastructure::telegram::keyboards::user_picker_keyboard;
+
+/// Most people offered in a picker
+const MAX_CANDIDATES: usize = 20;
+
+/// Handle /assign <number> [name]
+pub async fn handle_assign(
+    bot: Bot,
+    msg: Message,
+    state: Arc<BotState>,
+    number: i64,
+    name: String,
+) -> ResponseResult<()> {
+    let Some(user_id) = state.user_for_message(&msg) else {
+        bot.send_message(
+            msg.chat.id,
+            "Sorry, you are not authorized to use this bot.",
+        )
+        .await?;
+        return Ok(());
+    };
+
+    let response = assign(&state, &user_id, number, AssigneeRef::Name(name)).await;
+    send(&bot, msg.chat.id, response).await
+}
+
+/// Handle /unassign <number> [name]
+pub async fn handle_unassign(
+    bot: Bot,
+    msg: Message,
+    state: Arc<BotState>,
+    number: i64,
+    name: String,
+) -> ResponseResult<()> {
+    let Some(user_id) = state.user_for_message(&msg) else {
+        bot.send_message(
+            msg.chat.id,
+            "Sorry, you are not authorized to use this bot.",
+        )
+        .await?;
+        return Ok(());
+    };
+
+    let assignee = (!name.is_empty()).then_some(AssigneeRef::Name(name));
+    let response = unassign(&state, &user_id, number, assignee).await;
+    send(&bot, msg.chat.id, response).await
+}
+
+/// Handle "assign_to:<number>:<user_id>" and "unassign_from:<number>:<user_id>"
+/// callbacks from the user picker
+pub async fn handle_pick(
+    bot: &Bot,
+    query: &CallbackQuery,
+    state: &Arc<BotState>,
+    user_id: &FizzyId,
+    card_number_str: &str,
+    assignee_id: &str,
+    assigning: bool,
+) -> ResponseResult<()> {
+    let Ok(card_number) = card_number_str.parse::<i64>() else {
+        bot.answer_callback_query(query.id.clone())
+            .text("Invalid card number")
+            .show_alert(true)
+            .await?;
+        return Ok(());
+    };
+
+    let assignee = AssigneeRef::Id(FizzyId::new(assignee_id.to_string()));
+    let (text, keyboard) = if assigning {
+        assign(state, user_id, card_number, assignee).await
+    } else {
+        unassign(state, user_id, card_number, Some(assignee)).await
+    };
+
+    bot.answer_callback_query(query.id.clone()).await?;
+
+    // Replace the picker with the outcome
+    if let Some(message) = &query.message {
+        let mut request = bot.edit_message_text(message.chat().id, message.id(), text);
+        if let Some(keyboard) = keyboard {
+            request = request.reply_markup(keyboard);
+        }
+        request.await?;
+    }
+
+    Ok(())
+}
+
+type Response = (String, Option<teloxide::types::InlineKeyboardMarkup>);
+
+async fn assign(state: &Arc<BotState>, user_id: &FizzyId, number: i64, assignee: AssigneeRef) -> Response {
+    let input = AssignCardInput {
+        account_id: state.account_id(),
+        user_id: user_id.clone(),
+        card_number: number,
+        assignee,
+    };
+
+    match state.assign_card.execute(input).await {
+        Ok(AssignCardOutput::Assigned { card, assignee }) => {
+            (format!("👤 {} assigned to card #{}.", assignee.name, card.number), None)
+        }
+        Ok(AssignCardOutput::AlreadyAssigned { card, assignee }) => {
+            (format!("{} is already assigned to card #{}.", assignee.name, card.number), None)
+        }
+        Ok(AssignCardOutput::Ambiguous { card, mut candidates }) => {
+            if candidates.is_empty() {
+                return (format!("No one on this board to assign to card #{}.", card.number), None);
+            }
+            candidates.truncate(MAX_CANDIDATES);
+            let keyboard = user_picker_keyboard(&candidates, &format!("assign_to:{}", card.number));
+            (format!("👤 Who should be assigned to card #{}?", card.number), Some(keyboard))
+        }
+        Err(e) => (format!("Failed to assign: {}", e), None),
+    }
+}
+
+async fn unassign(
+    state: &Arc<BotState>,
+    user_id: &FizzyId,
+    number: i64,
+    assignee: Option<AssigneeRef>,
+) -> Response {
+    let input = UnassignCardInput {
+        account_id: state.account_id(),
+        user_id: user_id.clone(),
+        card_number: number,
+        assignee,
+    };
+
+    match state.unassign_card.execute(input).await {
+        Ok(UnassignCardOutput::Unassigned { card, assignee }) => {
+            (format!("👤 {} removed from card #{}.", assignee.name, card.number), None)
+        }
+        Ok(UnassignCardOutput::Ambiguous { card, mut candidates }) => {
+            candidates.truncate(MAX_CANDIDATES);
+            let keyboard = user_picker_keyboard(&candidates, &format!("unassign_from:{}", card.number));
+            (format!("👤 Who should be removed from card #{}?", card.number), Some(keyboard))
+        }
+        Err(e) => (format!("Failed to unassign: {}", e), None),
+    }
+}
+
+async fn send(bot: &Bot, chat_id: ChatId, (text, keyboard): Response) -> ResponseResult<()> {
+    let mut request = bot.send_message(chat_id, text);
+    if let Some(keyboard) = keyboard {
+        request = request.reply_markup(keyboard);
+    }
+    request.await?;
+    Ok(())
+}
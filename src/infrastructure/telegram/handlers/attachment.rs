@@ -0,0 +1,151 @@
+use std::sync::Arc;
+use teloxide::net::Download;
+use teloxide::prelude::*;
+use teloxide::types::{FileMeta, ReplyParameters};
+
+use crate::application::use_cases::AttachFileInput;
+use crate::infrastructure::telegram::bot::BotState;
+use crate::infrastructure::telegram::rich_text;
+
+/// Largest file the Bot API lets bots download
+const MAX_DOWNLOAD_BYTES: u32 = 20 * 1024 * 1024;
+
+/// Handle a photo or document: attach it to the card named in the caption
+/// (`#123`) or to the card whose message it replies to
+pub async fn handle(bot: Bot, msg: Message, state: Arc<BotState>) -> ResponseResult<()> {
+    let caption = msg.caption().unwrap_or_default();
+
+    let (card_number, text) = match parse_card_reference(caption) {
+        Some((number, rest)) => (Some(number), rest),
+        None => {
+            let replied_card = match msg.reply_to_message() {
+                Some(replied_to) => state
+                    .card_messages
+                    .find(msg.chat.id.0, replied_to.id.0)
+                    .await
+                    .unwrap_or_else(|e| {
+                        tracing::error!("Failed to look up replied-to message: {:?}", e);
+                        None
+                    }),
+                None => None,
+            };
+            (replied_card, caption.trim())
+        }
+    };
+
+    let Some(card_number) = card_number else {
+        // In groups, files not meant for the bot are none of our business
+        if msg.chat.is_private() {
+            bot.send_message(
+                msg.chat.id,
+                "To attach this file to a card, send it with a caption like #123 or as a reply to a card message.",
+            )
+            .await?;
+        }
+        return Ok(());
+    };
+
+    let Some(user_id) = state.user_for_message(&msg) else {
+        bot.send_message(
+            msg.chat.id,
+            "Sorry, you are not authorized to use this bot.",
+        )
+        .await?;
+        return Ok(());
+    };
+
+    let Some((meta, filename, content_type)) = file_of(&msg) else {
+        return Ok(());
+    };
+
+    if meta.size > MAX_DOWNLOAD_BYTES {
+        bot.send_message(msg.chat.id, "Sorry, files over 20 MB can't be attached from Telegram.")
+            .reply_parameters(ReplyParameters::new(msg.id))
+            .await?;
+        return Ok(());
+    }
+
+    let file = bot.get_file(meta.id.clone()).await?;
+    let mut data = Vec::with_capacity(meta.size as usize);
+    if let Err(e) = bot.download_file(&file.path, &mut data).await {
+        tracing::error!("Failed to download file: {:?}", e);
+        bot.send_message(msg.chat.id, "Failed to download the file from Telegram.")
+            .reply_parameters(ReplyParameters::new(msg.id))
+            .await?;
+        return Ok(());
+    }
+
+    let comment = (!text.is_empty()).then(|| rich_text::message_tail_to_action_text(&msg, text));
+    let input = AttachFileInput {
+        account_id: state.account_id(),
+        user_id,
+        card_number,
+        filename,
+        content_type,
+        data,
+        comment,
+    };
+
+    let response = match state.attach_file.execute(input).await {
+        Ok(output) if output.attachment.comment_id.is_some() => format!(
+            "📎 {} added to card #{} with your comment.",
+            output.attachment.filename, card_number
+        ),
+        Ok(output) => format!(
+            "📎 {} attached to the description of card #{}.",
+            output.attachment.filename, card_number
+        ),
+        Err(e) => format!("Failed to attach file: {}", e),
+    };
+
+    let sent = bot
+        .send_message(msg.chat.id, response)
+        .reply_parameters(ReplyParameters::new(msg.id))
+        .await?;
+    state.remember_card_message(&sent, card_number).await;
+
+    Ok(())
+}
+
+/// The file in a message with the name and content type to store it under
+fn file_of(msg: &Message) -> Option<(FileMeta, String, Option<String>)> {
+    if let Some(document) = msg.document() {
+        let filename = document.file_name.clone().unwrap_or_else(|| "file".to_string());
+        let content_type = document.mime_type.as_ref().map(|mime| mime.to_string());
+        return Some((document.file.clone(), filename, content_type));
+    }
+
+    // Telegram sends each photo in several sizes; keep the largest
+    let photo = msg.photo()?.iter().max_by_key(|size| size.width * size.height)?;
+    let filename = format!("photo_{}.jpg", msg.date.format("%Y-%m-%d_%H-%M-%S"));
+    Some((photo.file.clone(), filename, Some("image/jpeg".to_string())))
+}
+
+/// Split a leading card reference off a caption: "#123 looks broken" -> (123, "looks broken")
+fn parse_card_reference(caption: &str) -> Option<(i64, &str)> {
+    let rest = caption.trim_start().strip_prefix('#')?;
+    let digits = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+    let (number, rest) = rest.split_at(digits);
+
+    // "#123abc" is a hashtag, not a card
+    if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+
+    Some((number.parse().ok()?, rest.trim()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_card_reference() {
+        assert_eq!(parse_card_reference("#123"), Some((123, "")));
+        assert_eq!(parse_card_reference(" #42 login screen\nafter update"), Some((42, "login screen\nafter update")));
+        assert_eq!(parse_card_reference("#bug"), None);
+        assert_eq!(parse_card_reference("#12ab"), None);
+        assert_eq!(parse_card_reference("see #12"), None);
+        assert_eq!(parse_card_reference(""), None);
+    }
+}
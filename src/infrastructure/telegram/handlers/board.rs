@@ -1,52 +1,53 @@
 use std::sync::Arc;
+use chrono::NaiveDate;
 use teloxide::prelude::*;
+use teloxide::types::{InlineKeyboardMarkup, ParseMode};
 
-use crate::application::use_cases::ListBoardCardsInput;
+use crate::application::errors::ApplicationError;
+use crate::application::use_cases::{BoardRef, GetBoardOverviewInput};
+use crate::domain::value_objects::FizzyId;
 use crate::infrastructure::telegram::bot::BotState;
-use crate::infrastructure::telegram::formatters::CardFormatter;
+use crate::infrastructure::telegram::formatters::BoardFormatter;
+use crate::infrastructure::telegram::handlers::card_list;
+use crate::infrastructure::telegram::keyboards::board_overview_keyboard;
 
+/// Sections with more cards than this collapse to a count
+const MAX_CARDS_PER_SECTION: usize = 5;
+
+/// Handle /board <name>: show the board's cards grouped by column
 pub async fn handle(
     bot: Bot,
     msg: Message,
     state: Arc<BotState>,
     name: String,
 ) -> ResponseResult<()> {
-    let user_id = msg.from.as_ref().map(|u| u.id.0 as i64).unwrap_or(0);
-
-    if !state.is_authorized(user_id) {
+    let Some(user_id) = state.user_for_message(&msg) else {
         bot.send_message(
             msg.chat.id,
             "Sorry, you are not authorized to use this bot.",
         )
         .await?;
         return Ok(());
+    };
+
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        bot.send_message(msg.chat.id, "Usage: /board <board name>")
+            .await?;
+        return Ok(());
     }
 
-    let input = ListBoardCardsInput {
-        account_id: state.account_id(),
-        user_id: state.user_id(),
-        board_name: name.clone(),
-        limit: Some(20),
-    };
+    let today = state.today_for_message(&msg).await;
 
-    match state.list_board_cards.execute(input).await {
-        Ok(output) => {
-            let response = if output.cards.is_empty() {
-                format!("📁 <b>{}</b>\n\nNo active cards.", output.board_name)
-            } else {
-                format!(
-                    "📁 <b>{}</b> ({} cards)\n\n{}",
-                    output.board_name,
-                    output.cards.len(),
-                    CardFormatter::format_card_list(&output.cards)
-                )
-            };
+    match overview(&state, user_id, BoardRef::Name(name), today).await {
+        Ok((response, keyboard)) => {
             bot.send_message(msg.chat.id, response)
-                .parse_mode(teloxide::types::ParseMode::Html)
+                .parse_mode(ParseMode::Html)
+                .reply_markup(keyboard)
                 .await?;
         }
         Err(e) => {
-            tracing::error!("Error listing board cards: {:?}", e);
+            tracing::error!("Error loading board overview: {:?}", e);
             bot.send_message(msg.chat.id, format!("Error: {}", e))
                 .await?;
         }
@@ -54,3 +55,61 @@ pub async fn handle(
 
     Ok(())
 }
+
+/// Handle a "board:<board_id>" callback by showing the overview in place
+pub async fn handle_overview(
+    bot: &Bot,
+    query: &CallbackQuery,
+    state: &Arc<BotState>,
+    user_id: &FizzyId,
+    board_id: &str,
+) -> ResponseResult<()> {
+    let Some(message) = &query.message else {
+        bot.answer_callback_query(query.id.clone()).await?;
+        return Ok(());
+    };
+
+    let board = BoardRef::Id(FizzyId::new(board_id.to_string()));
+    let today = state.today_for(query.from.id.0 as i64).await;
+
+    match overview(state, user_id.clone(), board, today).await {
+        Ok((response, keyboard)) => {
+            bot.answer_callback_query(query.id.clone()).await?;
+            bot.edit_message_text(message.chat().id, message.id(), response)
+                .parse_mode(ParseMode::Html)
+                .reply_markup(keyboard)
+                .await?;
+        }
+        Err(e) => {
+            bot.answer_callback_query(query.id.clone())
+                .text(format!("Error: {}", e))
+                .show_alert(true)
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Load a board's overview and render it with buttons to expand its sections
+async fn overview(
+    state: &Arc<BotState>,
+    user_id: FizzyId,
+    board: BoardRef,
+    today: NaiveDate,
+) -> Result<(String, InlineKeyboardMarkup), ApplicationError> {
+    let input = GetBoardOverviewInput {
+        account_id: state.account_id(),
+        user_id,
+        board,
+        max_cards_per_section: MAX_CARDS_PER_SECTION,
+    };
+
+    let output = state.get_board_overview.execute(input).await?;
+    let response = BoardFormatter::format_overview(&output, today);
+    let keyboard = board_overview_keyboard(&output.sections, |section| {
+        card_list::section_callback_data(&output.board.id, section)
+    });
+
+    Ok((response, keyboard))
+}
@@ -6,20 +6,18 @@ use crate::infrastructure::telegram::bot::BotState;
 use crate::infrastructure::telegram::formatters::BoardFormatter;
 
 pub async fn handle(bot: Bot, msg: Message, state: Arc<BotState>) -> ResponseResult<()> {
-    let user_id = msg.from.as_ref().map(|u| u.id.0 as i64).unwrap_or(0);
-
-    if !state.is_authorized(user_id) {
+    let Some(user_id) = state.user_for_message(&msg) else {
         bot.send_message(
             msg.chat.id,
             "Sorry, you are not authorized to use this bot.",
         )
         .await?;
         return Ok(());
-    }
+    };
 
     let input = ListBoardsInput {
         account_id: state.account_id(),
-        user_id: state.user_id(),
+        user_id,
     };
 
     match state.list_boards.execute(input).await {
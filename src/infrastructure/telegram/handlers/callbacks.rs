@@ -1,9 +1,13 @@
 use std::sync::Arc;
 use teloxide::prelude::*;
+use teloxide::types::ForceReply;
 
 use crate::application::use_cases::{CloseCardInput, GetCardDetailsInput, MoveCardInput};
 use crate::domain::value_objects::FizzyId;
 use crate::infrastructure::telegram::bot::BotState;
+use crate::infrastructure::telegram::handlers::{
+    assign, board, card, card_list, comments, create, golden, history, link, search, tags, triage,
+};
 use crate::infrastructure::telegram::keyboards::column_selector_keyboard;
 
 /// Handle callback queries from inline keyboard buttons
@@ -12,16 +16,14 @@ pub async fn handle_callback(
     query: CallbackQuery,
     state: Arc<BotState>,
 ) -> ResponseResult<()> {
-    let user_id = query.from.id.0 as i64;
-
     // Authorization check
-    if !state.is_authorized(user_id) {
+    let Some(user_id) = state.user_id(query.from.id.0 as i64) else {
         bot.answer_callback_query(query.id.clone())
             .text("You are not authorized to use this bot.")
             .show_alert(true)
             .await?;
         return Ok(());
-    }
+    };
 
     let Some(data) = &query.data else {
         bot.answer_callback_query(query.id.clone()).await?;
@@ -33,16 +35,98 @@ pub async fn handle_callback(
 
     match parts.as_slice() {
         ["close", card_number] => {
-            handle_close(&bot, &query, &state, card_number).await?;
+            handle_close(&bot, &query, &state, &user_id, card_number).await?;
         }
         ["comment", card_number] => {
-            handle_comment_prompt(&bot, &query, card_number).await?;
+            handle_comment_prompt(&bot, &query, &state, card_number).await?;
+        }
+        ["comments", card_number] => {
+            comments::handle_show(&bot, &query, &state, &user_id, card_number).await?;
+        }
+        ["comments", card_number, page] => {
+            comments::handle_page(&bot, &query, &state, &user_id, card_number, page).await?;
+        }
+        ["cmt_del", card_number, comment_id] => {
+            comments::handle_delete(&bot, &query, &state, &user_id, card_number, comment_id).await?;
+        }
+        ["cmt_del_yes", card_number, comment_id] => {
+            comments::handle_delete_confirm(&bot, &query, &state, &user_id, card_number, comment_id).await?;
+        }
+        ["att", card_number, attachment_id] => {
+            card::handle_send_attachment(&bot, &query, &state, &user_id, card_number, attachment_id).await?;
+        }
+        ["expand", card_number] => {
+            card::handle_expand(&bot, &query, &state, card_number).await?;
+        }
+        ["gild", card_number] => {
+            golden::handle_toggle(&bot, &query, &state, &user_id, card_number, true).await?;
+        }
+        ["ungild", card_number] => {
+            golden::handle_toggle(&bot, &query, &state, &user_id, card_number, false).await?;
         }
         ["move", card_number] => {
             handle_move_select_column(&bot, &query, &state, card_number).await?;
         }
         ["move_to", card_number, column_id] => {
-            handle_move_to_column(&bot, &query, &state, card_number, column_id).await?;
+            handle_move_to_column(&bot, &query, &state, &user_id, card_number, column_id).await?;
+        }
+        ["board", board_id] => {
+            board::handle_overview(&bot, &query, &state, &user_id, board_id).await?;
+        }
+        ["cl", list, page, cursor] => {
+            card_list::handle_page(&bot, &query, &state, &user_id, list, page, cursor).await?;
+        }
+        ["hist", card_number, page] => {
+            history::handle_page(&bot, &query, &state, &user_id, card_number, page).await?;
+        }
+        ["search", page, ..] => {
+            // Search terms may themselves contain ':', so take everything after the page
+            let terms = data.splitn(3, ':').nth(2).unwrap_or_default();
+            match page.parse() {
+                Ok(page) => search::handle_page(&bot, &query, &state, &user_id, page, terms).await?,
+                Err(_) => {
+                    bot.answer_callback_query(query.id.clone())
+                        .text("Invalid page")
+                        .await?;
+                }
+            }
+        }
+        ["assign_to", card_number, assignee_id] => {
+            assign::handle_pick(&bot, &query, &state, &user_id, card_number, assignee_id, true).await?;
+        }
+        ["unassign_from", card_number, assignee_id] => {
+            assign::handle_pick(&bot, &query, &state, &user_id, card_number, assignee_id, false).await?;
+        }
+        ["tag_toggle", card_number, tag_id] => {
+            tags::handle_toggle(&bot, &query, &state, &user_id, card_number, tag_id).await?;
+        }
+        ["tag_done", card_number] => {
+            tags::handle_done(&bot, &query, &state, card_number).await?;
+        }
+        ["tri_col", card_number, column_id, skip] => {
+            let action = triage::TriageAction::Move(column_id);
+            triage::handle_action(&bot, &query, &state, &user_id, card_number, action, skip).await?;
+        }
+        ["tri_notnow", card_number, skip] => {
+            let action = triage::TriageAction::NotNow;
+            triage::handle_action(&bot, &query, &state, &user_id, card_number, action, skip).await?;
+        }
+        ["tri_close", card_number, skip] => {
+            let action = triage::TriageAction::Close;
+            triage::handle_action(&bot, &query, &state, &user_id, card_number, action, skip).await?;
+        }
+        ["tri_skip", card_number, skip] => {
+            let action = triage::TriageAction::Skip;
+            triage::handle_action(&bot, &query, &state, &user_id, card_number, action, skip).await?;
+        }
+        ["create_in", board_id] => {
+            create::handle_board_choice(&bot, &query, &state, &user_id, board_id).await?;
+        }
+        ["link_approve", code] => {
+            link::handle_decision(&bot, &query, &state, &user_id, code, true).await?;
+        }
+        ["link_deny", code] => {
+            link::handle_decision(&bot, &query, &state, &user_id, code, false).await?;
         }
         _ => {
             bot.answer_callback_query(query.id.clone())
@@ -59,6 +143,7 @@ async fn handle_close(
     bot: &Bot,
     query: &CallbackQuery,
     state: &Arc<BotState>,
+    user_id: &FizzyId,
     card_number_str: &str,
 ) -> ResponseResult<()> {
     let card_number: i64 = match card_number_str.parse() {
@@ -74,7 +159,7 @@ async fn handle_close(
 
     let input = CloseCardInput {
         account_id: state.account_id(),
-        user_id: state.user_id(),
+        user_id: user_id.clone(),
         card_number,
     };
 
@@ -86,8 +171,10 @@ async fn handle_close(
 
             // Send confirmation message
             if let Some(msg) = &query.message {
-                bot.send_message(msg.chat().id, format!("✅ Card #{} has been closed.", card_number))
+                let sent = bot
+                    .send_message(msg.chat().id, format!("✅ Card #{} has been closed.", card_number))
                     .await?;
+                state.remember_card_message(&sent, card_number).await;
             }
         }
         Err(e) => {
@@ -101,24 +188,35 @@ async fn handle_close(
     Ok(())
 }
 
-/// Handle comment prompt - direct user to use /comment command
+/// Handle comment prompt - ask for the comment as a reply to the prompt
 async fn handle_comment_prompt(
     bot: &Bot,
     query: &CallbackQuery,
+    state: &Arc<BotState>,
     card_number_str: &str,
 ) -> ResponseResult<()> {
+    let Ok(card_number) = card_number_str.parse::<i64>() else {
+        bot.answer_callback_query(query.id.clone())
+            .text("Invalid card number")
+            .show_alert(true)
+            .await?;
+        return Ok(());
+    };
+
     bot.answer_callback_query(query.id.clone()).await?;
 
     if let Some(msg) = &query.message {
-        bot.send_message(
-            msg.chat().id,
-            format!(
-                "💬 To add a comment to card #{}, use:\n<code>/comment {} your comment text</code>",
-                card_number_str, card_number_str
-            ),
-        )
-        .parse_mode(teloxide::types::ParseMode::Html)
-        .await?;
+        let prompt = ForceReply::new()
+            .input_field_placeholder(format!("Comment on #{}", card_number))
+            .selective();
+        let sent = bot
+            .send_message(
+                msg.chat().id,
+                format!("💬 Reply to this message with your comment for card #{}.", card_number),
+            )
+            .reply_markup(prompt)
+            .await?;
+        state.remember_card_message(&sent, card_number).await;
     }
 
     Ok(())
@@ -206,6 +304,7 @@ async fn handle_move_to_column(
     bot: &Bot,
     query: &CallbackQuery,
     state: &Arc<BotState>,
+    user_id: &FizzyId,
     card_number_str: &str,
     column_id_str: &str,
 ) -> ResponseResult<()> {
@@ -222,7 +321,7 @@ async fn handle_move_to_column(
 
     let input = MoveCardInput {
         account_id: state.account_id(),
-        user_id: state.user_id(),
+        user_id: user_id.clone(),
         card_number,
         column_id: FizzyId::new(column_id_str.to_string()),
     };
@@ -235,11 +334,13 @@ async fn handle_move_to_column(
                 .await?;
 
             if let Some(msg) = &query.message {
-                bot.send_message(
-                    msg.chat().id,
-                    format!("📁 Card #{} moved to {}.", card_number, column_name),
-                )
-                .await?;
+                let sent = bot
+                    .send_message(
+                        msg.chat().id,
+                        format!("📁 Card #{} moved to {}.", card_number, column_name),
+                    )
+                    .await?;
+                state.remember_card_message(&sent, card_number).await;
             }
         }
         Err(e) => {
@@ -1,9 +1,13 @@
 use std::sync::Arc;
 use teloxide::prelude::*;
+use teloxide::types::{InlineKeyboardMarkup, InputFile};
 
-use crate::application::use_cases::GetCardDetailsInput;
+use crate::application::errors::ApplicationError;
+use crate::application::use_cases::{GetCardDetailsInput, ReadAttachmentInput, ReadAttachmentOutput};
+use crate::domain::entities::Card;
+use crate::domain::value_objects::FizzyId;
 use crate::infrastructure::telegram::bot::BotState;
-use crate::infrastructure::telegram::formatters::CardFormatter;
+use crate::infrastructure::telegram::formatters::{CardFormatter, ErrorFormatter};
 use crate::infrastructure::telegram::keyboards::card_actions_keyboard;
 
 pub async fn handle(
@@ -30,12 +34,12 @@ pub async fn handle(
 
     match state.get_card_details.execute(input).await {
         Ok(card) => {
-            let response = CardFormatter::format_card(&card, state.base_url());
-            let keyboard = card_actions_keyboard(number);
-            bot.send_message(msg.chat.id, response)
+            let (response, keyboard) = card_view(&state, &card).await;
+            let sent = bot.send_message(msg.chat.id, response)
                 .parse_mode(teloxide::types::ParseMode::Html)
                 .reply_markup(keyboard)
                 .await?;
+            state.remember_card_message(&sent, card.number).await;
         }
         Err(e) => {
             tracing::error!("Error getting card details: {:?}", e);
@@ -46,3 +50,129 @@ pub async fn handle(
 
     Ok(())
 }
+
+/// Render a card with its attachments and action keyboard
+pub async fn card_view(state: &BotState, card: &Card) -> (String, InlineKeyboardMarkup) {
+    let attachments = state
+        .attachments
+        .list_for_card(&state.account_id(), &card.id)
+        .await
+        .unwrap_or_else(|e| {
+            tracing::warn!("Failed to load attachments for card #{}: {:?}", card.number, e);
+            Vec::new()
+        });
+
+    let mut response = CardFormatter::format_card(card, state.base_url());
+    if let Some(list) = CardFormatter::format_attachments(&attachments) {
+        response.push_str(&format!("\n\n{}", list));
+    }
+
+    let keyboard = card_actions_keyboard(card, CardFormatter::has_long_description(card), &attachments);
+
+    (response, keyboard)
+}
+
+/// Handle an "att:<number>:<attachment_id>" callback by sending the file back
+pub async fn handle_send_attachment(
+    bot: &Bot,
+    query: &CallbackQuery,
+    state: &Arc<BotState>,
+    user_id: &FizzyId,
+    card_number_str: &str,
+    attachment_id: &str,
+) -> ResponseResult<()> {
+    let Ok(card_number) = card_number_str.parse::<i64>() else {
+        bot.answer_callback_query(query.id.clone())
+            .text("Invalid card number")
+            .await?;
+        return Ok(());
+    };
+
+    let input = ReadAttachmentInput {
+        account_id: state.account_id(),
+        user_id: user_id.clone(),
+        card_number,
+        attachment_id: FizzyId::new(attachment_id.to_string()),
+    };
+
+    let ReadAttachmentOutput { attachment, data } = match state.read_attachment.execute(input).await {
+        Ok(output) => output,
+        Err(ApplicationError::DomainError(e)) => {
+            tracing::error!("Failed to read attachment {}: {:?}", attachment_id, e);
+            bot.answer_callback_query(query.id.clone())
+                .text("The file is missing from storage")
+                .show_alert(true)
+                .await?;
+            return Ok(());
+        }
+        Err(e) => {
+            bot.answer_callback_query(query.id.clone())
+                .text(ErrorFormatter::format(&e))
+                .show_alert(true)
+                .await?;
+            return Ok(());
+        }
+    };
+
+    bot.answer_callback_query(query.id.clone()).await?;
+
+    let Some(message) = &query.message else {
+        return Ok(());
+    };
+
+    let file = InputFile::memory(data).file_name(attachment.filename.clone());
+    let caption = format!("📎 {} · card #{}", attachment.filename, card_number);
+    let sent = if attachment.is_image() {
+        bot.send_photo(message.chat().id, file).caption(caption).await?
+    } else {
+        bot.send_document(message.chat().id, file).caption(caption).await?
+    };
+    state.remember_card_message(&sent, card_number).await;
+
+    Ok(())
+}
+
+/// Handle the "📖 Expand description" button by sending the full description
+pub async fn handle_expand(
+    bot: &Bot,
+    query: &CallbackQuery,
+    state: &Arc<BotState>,
+    card_number_str: &str,
+) -> ResponseResult<()> {
+    let Ok(card_number) = card_number_str.parse::<i64>() else {
+        bot.answer_callback_query(query.id.clone())
+            .text("Invalid card number")
+            .await?;
+        return Ok(());
+    };
+
+    let input = GetCardDetailsInput {
+        account_id: state.account_id(),
+        card_number,
+    };
+
+    match state.get_card_details.execute(input).await {
+        Ok(card) => {
+            bot.answer_callback_query(query.id.clone()).await?;
+
+            if let Some(message) = &query.message {
+                let sent = bot
+                    .send_message(
+                        message.chat().id,
+                        CardFormatter::format_description(&card, state.base_url()),
+                    )
+                    .parse_mode(teloxide::types::ParseMode::Html)
+                    .await?;
+                state.remember_card_message(&sent, card.number).await;
+            }
+        }
+        Err(e) => {
+            bot.answer_callback_query(query.id.clone())
+                .text(format!("Card not found: {}", e))
+                .show_alert(true)
+                .await?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,406 @@
+use std::sync::Arc;
+use chrono::{DateTime, NaiveDate};
+use teloxide::prelude::*;
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup, ParseMode};
+
+use crate::application::errors::ApplicationError;
+use crate::application::use_cases::{
+    BoardRef, BoardSection, ListBoardCardsInput, ListGoldenCardsInput, ListMyCardsInput,
+};
+use crate::domain::ports::{CardCursor, CardListPage, CardPage};
+use crate::domain::value_objects::FizzyId;
+use crate::infrastructure::telegram::bot::BotState;
+use crate::infrastructure::telegram::formatters::CardFormatter;
+use crate::infrastructure::telegram::keyboards::prev_next_keyboard;
+use crate::infrastructure::text::escape_html;
+
+/// Number of cards shown per page
+const PAGE_SIZE: usize = 20;
+/// Characters kept from the end of a column's ID in callback data
+const COLUMN_KEY_LEN: usize = 8;
+
+/// Which card list a message shows
+pub enum CardList {
+    Mine,
+    Golden,
+    Board(BoardRef, BoardSection),
+}
+
+/// Send the first page of a card list
+pub async fn send(
+    bot: &Bot,
+    msg: &Message,
+    state: &Arc<BotState>,
+    user_id: FizzyId,
+    list: CardList,
+) -> ResponseResult<()> {
+    let today = state.today_for_message(msg).await;
+
+    match list_page(state, user_id, list, CardPage::First, 0, today).await {
+        Ok((response, keyboard)) => {
+            let mut request = bot
+                .send_message(msg.chat.id, response)
+                .parse_mode(ParseMode::Html);
+            if let Some(keyboard) = keyboard {
+                request = request.reply_markup(keyboard);
+            }
+            request.await?;
+        }
+        Err(e) => {
+            tracing::error!("Error listing cards: {:?}", e);
+            bot.send_message(msg.chat.id, format!("Error: {}", e))
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle a "cl:<list>:<page>:<cursor>" callback by editing the list in place
+pub async fn handle_page(
+    bot: &Bot,
+    query: &CallbackQuery,
+    state: &Arc<BotState>,
+    user_id: &FizzyId,
+    list_str: &str,
+    page_str: &str,
+    cursor_str: &str,
+) -> ResponseResult<()> {
+    let (Some(list), Ok(page_number), Some(page)) = (
+        decode_list(list_str),
+        page_str.parse::<usize>(),
+        decode_cursor(cursor_str),
+    ) else {
+        bot.answer_callback_query(query.id.clone())
+            .text("Invalid page")
+            .await?;
+        return Ok(());
+    };
+
+    let Some(message) = &query.message else {
+        bot.answer_callback_query(query.id.clone()).await?;
+        return Ok(());
+    };
+
+    let today = state.today_for(query.from.id.0 as i64).await;
+
+    match list_page(state, user_id.clone(), list, page, page_number, today).await {
+        Ok((response, keyboard)) => {
+            bot.answer_callback_query(query.id.clone()).await?;
+            let mut request = bot
+                .edit_message_text(message.chat().id, message.id(), response)
+                .parse_mode(ParseMode::Html);
+            if let Some(keyboard) = keyboard {
+                request = request.reply_markup(keyboard);
+            }
+            request.await?;
+        }
+        Err(e) => {
+            bot.answer_callback_query(query.id.clone())
+                .text(format!("Error: {}", e))
+                .show_alert(true)
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Load one page of a card list and render it with its navigation keyboard
+async fn list_page(
+    state: &Arc<BotState>,
+    user_id: FizzyId,
+    list: CardList,
+    page: CardPage,
+    page_number: usize,
+    today: NaiveDate,
+) -> Result<(String, Option<InlineKeyboardMarkup>), ApplicationError> {
+    let account_id = state.account_id();
+
+    let view = match list {
+        CardList::Mine => {
+            let input = ListMyCardsInput {
+                account_id,
+                user_id,
+                include_closed: false,
+                page,
+                per_page: PAGE_SIZE,
+            };
+            let output = state.list_my_cards.execute(input).await?;
+            ListView {
+                cards: output.page,
+                key: "m".to_string(),
+                title: "📋 <b>Your Cards</b>".to_string(),
+                empty: "📋 You have no assigned cards.".to_string(),
+                board_id: None,
+            }
+        }
+        CardList::Golden => {
+            let input = ListGoldenCardsInput {
+                account_id,
+                user_id,
+                page,
+                per_page: PAGE_SIZE,
+            };
+            let output = state.list_golden_cards.execute(input).await?;
+            ListView {
+                cards: output.page,
+                key: "g".to_string(),
+                title: "⭐ <b>Golden Cards</b>".to_string(),
+                empty: "⭐ No golden cards.".to_string(),
+                board_id: None,
+            }
+        }
+        CardList::Board(board, section) => {
+            let input = ListBoardCardsInput {
+                account_id,
+                user_id,
+                board,
+                section: section.clone(),
+                page,
+                per_page: PAGE_SIZE,
+            };
+            let output = state.list_board_cards.execute(input).await?;
+            let name = escape_html(&output.board.name);
+            let (title, empty) = match (&section, &output.column) {
+                (BoardSection::AwaitingTriage, _) => (
+                    format!("📥 <b>{}</b> — Awaiting triage", name),
+                    "No cards awaiting triage.",
+                ),
+                (BoardSection::Column(_), Some(column)) => (
+                    format!("📁 <b>{}</b> — {}", name, escape_html(&column.formatted_name())),
+                    "No cards in this column.",
+                ),
+                (BoardSection::NotNow, _) => (
+                    format!("⏸️ <b>{}</b> — Not now", name),
+                    "No postponed cards.",
+                ),
+                (BoardSection::Done, _) => (
+                    format!("✅ <b>{}</b> — Done", name),
+                    "No closed cards.",
+                ),
+                _ => (format!("📁 <b>{}</b>", name), "No active cards."),
+            };
+            ListView {
+                cards: output.page,
+                key: format!("{}.{}", encode_section(&section), output.board.id),
+                empty: format!("{}\n\n{}", title, empty),
+                title,
+                board_id: Some(output.board.id),
+            }
+        }
+    };
+
+    Ok(render(&view, page_number, today))
+}
+
+/// A loaded page and how to present it
+struct ListView {
+    cards: CardListPage,
+    /// Identifies the list in callback data
+    key: String,
+    title: String,
+    empty: String,
+    /// Board lists link back to the board overview
+    board_id: Option<FizzyId>,
+}
+
+fn render(view: &ListView, page_number: usize, today: NaiveDate) -> (String, Option<InlineKeyboardMarkup>) {
+    let cards = &view.cards;
+    let back = view.board_id.as_ref().map(|board_id| {
+        InlineKeyboardButton::callback("🗂 Board overview", format!("board:{}", board_id))
+    });
+    let with_back = |keyboard: Option<InlineKeyboardMarkup>| match (keyboard, back.clone()) {
+        (Some(keyboard), Some(back)) => Some(keyboard.append_row(vec![back])),
+        (None, Some(back)) => Some(InlineKeyboardMarkup::new(vec![vec![back]])),
+        (keyboard, None) => keyboard,
+    };
+
+    let (Some(first), Some(last)) = (cards.cards.first(), cards.cards.last()) else {
+        // The cards around the cursor may have moved on since the page was sent
+        let response = if page_number == 0 {
+            view.empty.clone()
+        } else {
+            format!("{}\n\nNo more cards. Send the command again to start over.", view.title)
+        };
+        return (response, with_back(None));
+    };
+
+    // Walking back can reach the start sooner than the page count says
+    let page_number = if cards.has_prev { page_number } else { 0 };
+
+    let response = if cards.has_prev || cards.has_next {
+        format!(
+            "{} · page {}\n\n{}",
+            view.title,
+            page_number + 1,
+            CardFormatter::format_card_list(&cards.cards, today)
+        )
+    } else {
+        format!(
+            "{} ({})\n\n{}",
+            view.title,
+            cards.cards.len(),
+            CardFormatter::format_card_list(&cards.cards, today)
+        )
+    };
+
+    let keyboard = prev_next_keyboard(
+        cards.has_prev.then(|| {
+            let page = CardPage::Before(CardCursor::of(first));
+            callback_data(&view.key, page_number.saturating_sub(1), page)
+        }),
+        cards.has_next.then(|| {
+            let page = CardPage::After(CardCursor::of(last));
+            callback_data(&view.key, page_number + 1, page)
+        }),
+    );
+
+    (response, with_back(keyboard))
+}
+
+/// Callback data opening the first page of a board section
+pub fn section_callback_data(board_id: &FizzyId, section: BoardSection) -> String {
+    let key = format!("{}.{}", encode_section(&section), board_id);
+    callback_data(&key, 0, CardPage::First)
+}
+
+/// Encode a page request compactly enough for Telegram's 64-byte callback data
+fn callback_data(list_key: &str, page_number: usize, page: CardPage) -> String {
+    format!("cl:{}:{}:{}", list_key, page_number, encode_cursor(page))
+}
+
+fn decode_list(s: &str) -> Option<CardList> {
+    match s {
+        "m" => return Some(CardList::Mine),
+        "g" => return Some(CardList::Golden),
+        _ => {}
+    }
+
+    let (section, board_id) = s.split_once('.')?;
+    if board_id.is_empty() {
+        return None;
+    }
+
+    let board = BoardRef::Id(FizzyId::new(board_id.to_string()));
+    Some(CardList::Board(board, decode_section(section)?))
+}
+
+fn encode_section(section: &BoardSection) -> String {
+    match section {
+        BoardSection::Open => "b".to_string(),
+        BoardSection::AwaitingTriage => "t".to_string(),
+        BoardSection::Column(key) => {
+            let start = key.len().saturating_sub(COLUMN_KEY_LEN);
+            format!("c{}", key.get(start..).unwrap_or(key))
+        }
+        BoardSection::NotNow => "p".to_string(),
+        BoardSection::Done => "d".to_string(),
+    }
+}
+
+fn decode_section(s: &str) -> Option<BoardSection> {
+    match s {
+        "b" => Some(BoardSection::Open),
+        "t" => Some(BoardSection::AwaitingTriage),
+        "p" => Some(BoardSection::NotNow),
+        "d" => Some(BoardSection::Done),
+        _ => match s.strip_prefix('c')? {
+            "" => None,
+            key => Some(BoardSection::Column(key.to_string())),
+        },
+    }
+}
+
+/// "a" or "b" for after/before, then the cursor's activity time in base-36
+/// microseconds and its card number, e.g. "a1e6lq2f3k0.42"
+fn encode_cursor(page: CardPage) -> String {
+    let (direction, cursor) = match page {
+        CardPage::First => return "f".to_string(),
+        CardPage::After(cursor) => ('a', cursor),
+        CardPage::Before(cursor) => ('b', cursor),
+    };
+
+    format!(
+        "{}{}.{}",
+        direction,
+        to_base36(cursor.last_active_at.timestamp_micros()),
+        cursor.number
+    )
+}
+
+fn decode_cursor(s: &str) -> Option<CardPage> {
+    if s == "f" {
+        return Some(CardPage::First);
+    }
+
+    let (direction, rest) = s.split_at_checked(1)?;
+    let (micros, number) = rest.split_once('.')?;
+    let cursor = CardCursor {
+        last_active_at: DateTime::from_timestamp_micros(i64::from_str_radix(micros, 36).ok()?)?,
+        number: number.parse().ok()?,
+    };
+
+    match direction {
+        "a" => Some(CardPage::After(cursor)),
+        "b" => Some(CardPage::Before(cursor)),
+        _ => None,
+    }
+}
+
+fn to_base36(mut n: i64) -> String {
+    const DIGITS: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+    if n <= 0 {
+        return "0".to_string();
+    }
+
+    let mut digits = Vec::new();
+    while n > 0 {
+        digits.push(DIGITS[(n % 36) as usize]);
+        n /= 36;
+    }
+    digits.reverse();
+
+    String::from_utf8(digits).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn test_cursor_round_trip() {
+        let at = Utc.with_ymd_and_hms(2026, 3, 14, 15, 9, 26).unwrap()
+            + chrono::Duration::microseconds(535_897);
+        let page = CardPage::After(CardCursor { last_active_at: at, number: 1234 });
+
+        let encoded = encode_cursor(page);
+        assert_eq!(decode_cursor(&encoded), Some(page));
+        assert_eq!(decode_cursor("f"), Some(CardPage::First));
+        assert_eq!(decode_cursor("x12.3"), None);
+    }
+
+    #[test]
+    fn test_callback_data_fits_telegram_limit() {
+        let column_id = FizzyId::generate();
+        let column = BoardSection::Column(column_id.as_str().to_string());
+        let board_key = format!("{}.{}", encode_section(&column), FizzyId::generate());
+        let cursor = CardCursor { last_active_at: Utc::now(), number: 99_999 };
+        let data = callback_data(&board_key, 999, CardPage::Before(cursor));
+
+        assert!(data.len() <= 64, "{} is {} bytes", data, data.len());
+        match decode_list(&board_key) {
+            Some(CardList::Board(BoardRef::Id(_), BoardSection::Column(key))) => {
+                assert_eq!(key.len(), COLUMN_KEY_LEN);
+                assert!(column_id.as_str().ends_with(&key));
+            }
+            _ => panic!("{} did not decode to a column", board_key),
+        }
+        assert!(decode_list("c.abc").is_none());
+        assert!(matches!(decode_list("m"), Some(CardList::Mine)));
+        assert!(decode_list("b.").is_none());
+        assert!(decode_list("x.abc").is_none());
+    }
+}
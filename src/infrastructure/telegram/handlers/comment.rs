@@ -3,6 +3,7 @@ use teloxide::prelude::*;
 
 use crate::application::use_cases::AddCommentInput;
 use crate::infrastructure::telegram::bot::BotState;
+use crate::infrastructure::telegram::rich_text;
 
 pub async fn handle(
     bot: Bot,
@@ -11,6 +12,15 @@ pub async fn handle(
     number: i64,
     text: String,
 ) -> ResponseResult<()> {
+    let Some(user_id) = state.user_for_message(&msg) else {
+        bot.send_message(
+            msg.chat.id,
+            "Sorry, you are not authorized to use this bot.",
+        )
+        .await?;
+        return Ok(());
+    };
+
     if text.trim().is_empty() {
         bot.send_message(msg.chat.id, "Usage: /comment <number> <text>")
             .await?;
@@ -19,18 +29,19 @@ pub async fn handle(
 
     let input = AddCommentInput {
         account_id: state.account_id(),
-        user_id: state.user_id(),
+        user_id,
         card_number: number,
-        content: text.trim().to_string(),
+        content: rich_text::message_tail_to_action_text(&msg, &text),
     };
 
     match state.add_comment.execute(input).await {
         Ok(_comment) => {
-            bot.send_message(
+            let sent = bot.send_message(
                 msg.chat.id,
                 format!("Comment added to card #{}.", number),
             )
             .await?;
+            state.remember_card_message(&sent, number).await;
         }
         Err(e) => {
             bot.send_message(msg.chat.id, format!("Failed to add comment: {}", e))
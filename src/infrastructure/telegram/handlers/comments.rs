@@ -0,0 +1,266 @@
+use std::sync::Arc;
+use chrono::Utc;
+use teloxide::prelude::*;
+use teloxide::types::{InlineKeyboardMarkup, ParseMode};
+
+use crate::application::errors::ApplicationError;
+use crate::application::use_cases::{DeleteCommentInput, ListCommentsInput};
+use crate::domain::value_objects::FizzyId;
+use crate::infrastructure::telegram::bot::BotState;
+use crate::infrastructure::telegram::formatters::{CommentFormatter, ErrorFormatter};
+use crate::infrastructure::telegram::keyboards::{comment_delete_confirm_keyboard, comments_keyboard};
+
+/// Number of comments shown per page
+const PAGE_SIZE: usize = 5;
+
+/// Handle /comments <number>
+pub async fn handle(
+    bot: Bot,
+    msg: Message,
+    state: Arc<BotState>,
+    number: i64,
+) -> ResponseResult<()> {
+    let Some(user_id) = state.user_for_message(&msg) else {
+        bot.send_message(
+            msg.chat.id,
+            "Sorry, you are not authorized to use this bot.",
+        )
+        .await?;
+        return Ok(());
+    };
+
+    send_comments(&bot, msg.chat.id, &state, &user_id, number).await
+}
+
+/// Handle the "💬 Show comments" button by sending the latest comments
+pub async fn handle_show(
+    bot: &Bot,
+    query: &CallbackQuery,
+    state: &Arc<BotState>,
+    user_id: &FizzyId,
+    card_number_str: &str,
+) -> ResponseResult<()> {
+    let Ok(card_number) = card_number_str.parse::<i64>() else {
+        bot.answer_callback_query(query.id.clone())
+            .text("Invalid card number")
+            .await?;
+        return Ok(());
+    };
+
+    bot.answer_callback_query(query.id.clone()).await?;
+
+    if let Some(message) = &query.message {
+        send_comments(bot, message.chat().id, state, user_id, card_number).await?;
+    }
+
+    Ok(())
+}
+
+/// Handle a "comments:<number>:<page>" callback by editing the comments in place
+pub async fn handle_page(
+    bot: &Bot,
+    query: &CallbackQuery,
+    state: &Arc<BotState>,
+    user_id: &FizzyId,
+    card_number_str: &str,
+    page_str: &str,
+) -> ResponseResult<()> {
+    let (Ok(card_number), Ok(page)) = (card_number_str.parse::<i64>(), page_str.parse::<usize>()) else {
+        bot.answer_callback_query(query.id.clone())
+            .text("Invalid page")
+            .await?;
+        return Ok(());
+    };
+
+    let Some(message) = &query.message else {
+        bot.answer_callback_query(query.id.clone()).await?;
+        return Ok(());
+    };
+
+    match comments_page(state, user_id, card_number, page).await {
+        Ok((response, keyboard)) => {
+            bot.answer_callback_query(query.id.clone()).await?;
+            let mut request = bot
+                .edit_message_text(message.chat().id, message.id(), response)
+                .parse_mode(ParseMode::Html);
+            if let Some(keyboard) = keyboard {
+                request = request.reply_markup(keyboard);
+            }
+            request.await?;
+        }
+        Err(e) => {
+            bot.answer_callback_query(query.id.clone())
+                .text(format!("Error: {}", e))
+                .show_alert(true)
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle a "cmt_del:<number>:<comment_id>" callback by asking for confirmation in place
+pub async fn handle_delete(
+    bot: &Bot,
+    query: &CallbackQuery,
+    state: &Arc<BotState>,
+    user_id: &FizzyId,
+    card_number_str: &str,
+    comment_id: &str,
+) -> ResponseResult<()> {
+    let Ok(card_number) = card_number_str.parse::<i64>() else {
+        bot.answer_callback_query(query.id.clone())
+            .text("Invalid card number")
+            .await?;
+        return Ok(());
+    };
+
+    let Some(message) = &query.message else {
+        bot.answer_callback_query(query.id.clone()).await?;
+        return Ok(());
+    };
+
+    // Only show the comment to someone who could delete it
+    let comment_id = FizzyId::new(comment_id.to_string());
+    let comment = match state.delete_comment.find_deletable(&state.account_id(), user_id, &comment_id).await {
+        Ok((comment, card)) if card.number == card_number => comment,
+        Ok(_) => {
+            bot.answer_callback_query(query.id.clone())
+                .text("This comment no longer exists")
+                .show_alert(true)
+                .await?;
+            return Ok(());
+        }
+        Err(e) => {
+            bot.answer_callback_query(query.id.clone())
+                .text(ErrorFormatter::format(&e))
+                .show_alert(true)
+                .await?;
+            return Ok(());
+        }
+    };
+
+    bot.answer_callback_query(query.id.clone()).await?;
+    bot.edit_message_text(
+        message.chat().id,
+        message.id(),
+        CommentFormatter::format_delete_prompt(card_number, &comment, Utc::now()),
+    )
+    .parse_mode(ParseMode::Html)
+    .reply_markup(comment_delete_confirm_keyboard(card_number, &comment))
+    .await?;
+
+    Ok(())
+}
+
+/// Handle a "cmt_del_yes:<number>:<comment_id>" callback: delete the comment and
+/// show the latest comments again
+pub async fn handle_delete_confirm(
+    bot: &Bot,
+    query: &CallbackQuery,
+    state: &Arc<BotState>,
+    user_id: &FizzyId,
+    card_number_str: &str,
+    comment_id: &str,
+) -> ResponseResult<()> {
+    let Ok(card_number) = card_number_str.parse::<i64>() else {
+        bot.answer_callback_query(query.id.clone())
+            .text("Invalid card number")
+            .await?;
+        return Ok(());
+    };
+
+    let input = DeleteCommentInput {
+        account_id: state.account_id(),
+        user_id: user_id.clone(),
+        comment_id: FizzyId::new(comment_id.to_string()),
+    };
+
+    if let Err(e) = state.delete_comment.execute(input).await {
+        bot.answer_callback_query(query.id.clone())
+            .text(format!("Failed to delete comment: {}", e))
+            .show_alert(true)
+            .await?;
+        return Ok(());
+    }
+
+    bot.answer_callback_query(query.id.clone())
+        .text("Comment deleted")
+        .await?;
+
+    if let Some(message) = &query.message {
+        match comments_page(state, user_id, card_number, 0).await {
+            Ok((response, keyboard)) => {
+                let mut request = bot
+                    .edit_message_text(message.chat().id, message.id(), response)
+                    .parse_mode(ParseMode::Html);
+                if let Some(keyboard) = keyboard {
+                    request = request.reply_markup(keyboard);
+                }
+                request.await?;
+            }
+            Err(e) => tracing::error!("Error listing comments: {:?}", e),
+        }
+    }
+
+    Ok(())
+}
+
+async fn send_comments(
+    bot: &Bot,
+    chat_id: ChatId,
+    state: &Arc<BotState>,
+    user_id: &FizzyId,
+    card_number: i64,
+) -> ResponseResult<()> {
+    match comments_page(state, user_id, card_number, 0).await {
+        Ok((response, keyboard)) => {
+            let mut request = bot
+                .send_message(chat_id, response)
+                .parse_mode(ParseMode::Html);
+            if let Some(keyboard) = keyboard {
+                request = request.reply_markup(keyboard);
+            }
+            let sent = request.await?;
+            state.remember_card_message(&sent, card_number).await;
+        }
+        Err(e) => {
+            tracing::error!("Error listing comments: {:?}", e);
+            bot.send_message(chat_id, format!("Error: {}", e))
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Load one page of a card's comments and render it with its navigation keyboard
+async fn comments_page(
+    state: &Arc<BotState>,
+    user_id: &FizzyId,
+    card_number: i64,
+    page: usize,
+) -> Result<(String, Option<InlineKeyboardMarkup>), ApplicationError> {
+    let input = ListCommentsInput {
+        account_id: state.account_id(),
+        user_id: user_id.clone(),
+        card_number,
+        page,
+        per_page: PAGE_SIZE,
+    };
+
+    let output = state.list_comments.execute(input).await?;
+    let response = CommentFormatter::format_comments(&output, Utc::now());
+
+    // Authors can change their own comments; admins can change anyone's
+    let manageable: Vec<_> = output
+        .comments
+        .iter()
+        .enumerate()
+        .filter(|(_, comment)| output.viewer_is_admin || comment.creator_id == *user_id)
+        .map(|(i, comment)| (i + 1, comment))
+        .collect();
+    let keyboard = comments_keyboard(card_number, output.page, output.has_older, &manageable);
+
+    Ok((response, keyboard))
+}
@@ -1,36 +1,102 @@
 use std::sync::Arc;
 use teloxide::prelude::*;
+use teloxide::types::ReplyParameters;
 
-use crate::application::use_cases::CreateCardUseCaseInput;
+use crate::application::errors::ApplicationError;
+use crate::application::use_cases::{CreateCardUseCaseInput, ListBoardsInput};
+use crate::domain::entities::{Board, Card};
+use crate::domain::value_objects::FizzyId;
 use crate::infrastructure::telegram::bot::BotState;
+use crate::infrastructure::telegram::keyboards::board_selector_keyboard;
+use crate::infrastructure::telegram::rich_text;
 
+/// Handle /create [@Board] <title>, asking which board to use when it isn't given
 pub async fn handle(
     bot: Bot,
     msg: Message,
     state: Arc<BotState>,
-    title: String,
+    text: String,
 ) -> ResponseResult<()> {
-    if title.trim().is_empty() {
-        bot.send_message(msg.chat.id, "Usage: /create <title>")
-            .await?;
+    let Some(user_id) = state.user_for_message(&msg) else {
+        bot.send_message(
+            msg.chat.id,
+            "Sorry, you are not authorized to use this bot.",
+        )
+        .await?;
         return Ok(());
-    }
+    };
 
-    let input = CreateCardUseCaseInput {
-        account_id: state.account_id(),
-        user_id: state.user_id(),
-        board_id: state.default_board_id(),
-        title: title.trim().to_string(),
-        description: None,
+    let boards = match accessible_boards(&state, &user_id).await {
+        Ok(boards) => boards,
+        Err(e) => {
+            tracing::error!("Error listing boards: {:?}", e);
+            bot.send_message(msg.chat.id, format!("Failed to create card: {}", e))
+                .await?;
+            return Ok(());
+        }
     };
 
-    match state.create_card.execute(input).await {
-        Ok(card) => {
-            let mut response = format!("Created card #{}: {}", card.number, card.title);
-            if let Some(base_url) = state.base_url() {
-                response.push_str(&format!("\n{}/cards/{}", base_url, card.number));
+    let text = text.trim();
+    let (board, text) = match text.strip_prefix('@') {
+        Some(named) => match match_board(named, &boards) {
+            Some((board, rest)) => (Some(board), rest),
+            None => {
+                let name = named.split_whitespace().next().unwrap_or_default();
+                let names: Vec<&str> = boards.iter().map(|b| b.name.as_str()).collect();
+                bot.send_message(
+                    msg.chat.id,
+                    format!("No board called \"{}\". Your boards: {}", name, names.join(", ")),
+                )
+                .await?;
+                return Ok(());
             }
-            bot.send_message(msg.chat.id, response).await?;
+        },
+        None => (None, text),
+    };
+
+    let (title, description) = split_title(&msg, text);
+
+    if title.is_empty() {
+        bot.send_message(
+            msg.chat.id,
+            "Usage: /create [@Board] <title>\n(further lines become the description)",
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let telegram_user_id = msg.from.as_ref().map(|u| u.id.0 as i64).unwrap_or(0);
+
+    let board = match (board, boards.as_slice()) {
+        (Some(board), _) => board,
+        (None, []) => {
+            bot.send_message(msg.chat.id, "You don't have access to any boards.")
+                .await?;
+            return Ok(());
+        }
+        (None, [board]) => board,
+        (None, _) => {
+            // Stateless: the choice callback re-reads the title from this /create message
+            let last_board = match state.user_settings.get_last_board(telegram_user_id).await {
+                Ok(board_id) => board_id,
+                Err(e) => {
+                    tracing::warn!("Failed to load last board for {}: {}", telegram_user_id, e);
+                    None
+                }
+            };
+
+            bot.send_message(msg.chat.id, format!("📋 Which board should \"{}\" go on?", title))
+                .reply_parameters(ReplyParameters::new(msg.id))
+                .reply_markup(board_selector_keyboard(&boards, last_board.as_ref(), "create_in"))
+                .await?;
+            return Ok(());
+        }
+    };
+
+    match create_on_board(&state, telegram_user_id, user_id, board, title, description).await {
+        Ok((card, response)) => {
+            let sent = bot.send_message(msg.chat.id, response).await?;
+            state.remember_card_message(&sent, card.number).await;
         }
         Err(e) => {
             bot.send_message(msg.chat.id, format!("Failed to create card: {}", e))
@@ -40,3 +106,184 @@ pub async fn handle(
 
     Ok(())
 }
+
+/// Handle a "create_in:<board_id>" callback from the board picker
+pub async fn handle_board_choice(
+    bot: &Bot,
+    query: &CallbackQuery,
+    state: &Arc<BotState>,
+    user_id: &FizzyId,
+    board_id: &str,
+) -> ResponseResult<()> {
+    let Some(message) = query.message.as_ref().and_then(|m| m.regular_message()) else {
+        bot.answer_callback_query(query.id.clone()).await?;
+        return Ok(());
+    };
+
+    // The picker replies to the /create message, which still holds the title
+    let Some(original) = message.reply_to_message() else {
+        bot.answer_callback_query(query.id.clone())
+            .text("This card request has expired. Send /create again.")
+            .show_alert(true)
+            .await?;
+        return Ok(());
+    };
+
+    if original.from.as_ref().map(|u| u.id) != Some(query.from.id) {
+        bot.answer_callback_query(query.id.clone())
+            .text("Only whoever sent /create can choose the board.")
+            .show_alert(true)
+            .await?;
+        return Ok(());
+    }
+
+    let text = original.text().unwrap_or_default();
+    let args = text.split_once(char::is_whitespace).map(|(_, args)| args).unwrap_or_default();
+    let (title, description) = split_title(original, args.trim());
+
+    let boards = match accessible_boards(state, user_id).await {
+        Ok(boards) => boards,
+        Err(e) => {
+            bot.answer_callback_query(query.id.clone())
+                .text(format!("Failed to create card: {}", e))
+                .show_alert(true)
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let Some(board) = boards.iter().find(|b| b.id.as_str() == board_id) else {
+        bot.answer_callback_query(query.id.clone())
+            .text("No access to this board")
+            .show_alert(true)
+            .await?;
+        return Ok(());
+    };
+
+    let telegram_user_id = query.from.id.0 as i64;
+
+    match create_on_board(state, telegram_user_id, user_id.clone(), board, title, description).await {
+        Ok((card, response)) => {
+            bot.answer_callback_query(query.id.clone()).await?;
+            let edited = bot.edit_message_text(message.chat.id, message.id, response).await?;
+            state.remember_card_message(&edited, card.number).await;
+        }
+        Err(e) => {
+            bot.answer_callback_query(query.id.clone())
+                .text(format!("Failed to create card: {}", e))
+                .show_alert(true)
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+async fn accessible_boards(
+    state: &Arc<BotState>,
+    user_id: &FizzyId,
+) -> Result<Vec<Board>, ApplicationError> {
+    let input = ListBoardsInput {
+        account_id: state.account_id(),
+        user_id: user_id.clone(),
+    };
+
+    Ok(state.list_boards.execute(input).await?.boards)
+}
+
+/// Create the card and remember the board as the user's choice for next time
+async fn create_on_board(
+    state: &Arc<BotState>,
+    telegram_user_id: i64,
+    user_id: FizzyId,
+    board: &Board,
+    title: String,
+    description: Option<String>,
+) -> Result<(Card, String), ApplicationError> {
+    let input = CreateCardUseCaseInput {
+        account_id: state.account_id(),
+        user_id,
+        board_id: board.id.clone(),
+        title,
+        description,
+    };
+
+    let card = state.create_card.execute(input).await?;
+
+    if let Err(e) = state.user_settings.set_last_board(telegram_user_id, &board.id).await {
+        tracing::warn!("Failed to remember last board for {}: {}", telegram_user_id, e);
+    }
+
+    let mut response = format!("Created card #{} on {}: {}", card.number, board.name, card.title);
+    if let Some(base_url) = state.base_url() {
+        response.push_str(&format!("\n{}/cards/{}", base_url, card.number));
+    }
+
+    Ok((card, response))
+}
+
+/// The first line is the title; anything after it becomes the description
+fn split_title(msg: &Message, text: &str) -> (String, Option<String>) {
+    match text.trim().split_once('\n') {
+        Some((title, description)) if !description.trim().is_empty() => (
+            title.trim().to_string(),
+            Some(rich_text::message_tail_to_action_text(msg, description)),
+        ),
+        Some((title, _)) => (title.trim().to_string(), None),
+        None => (text.trim().to_string(), None),
+    }
+}
+
+/// Find the board named at the start of `text` (after the "@"), returning it
+/// with the rest of the text. Names match case-insensitively and may contain
+/// spaces; the longest match wins, so "@Ops Team" prefers "Ops Team" to "Ops".
+fn match_board<'a, 'b>(text: &'a str, boards: &'b [Board]) -> Option<(&'b Board, &'a str)> {
+    boards
+        .iter()
+        .filter_map(|board| {
+            let name = text.get(..board.name.len())?;
+            let rest = &text[board.name.len()..];
+            let ends_at_word = rest.chars().next().is_none_or(char::is_whitespace);
+            (ends_at_word && name.to_lowercase() == board.name.to_lowercase()).then_some((board, rest))
+        })
+        .max_by_key(|(board, _)| board.name.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn board(name: &str) -> Board {
+        Board {
+            id: FizzyId::generate(),
+            account_id: FizzyId::generate(),
+            creator_id: FizzyId::generate(),
+            name: name.to_string(),
+            all_access: true,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            card_count: None,
+        }
+    }
+
+    #[test]
+    fn test_match_board() {
+        let boards = vec![board("Ops"), board("Ops Team"), board("Roadmap")];
+
+        let (found, rest) = match_board("ops team Fix the pager", &boards).unwrap();
+        assert_eq!(found.name, "Ops Team");
+        assert_eq!(rest, " Fix the pager");
+
+        let (found, rest) = match_board("Ops Fix the pager", &boards).unwrap();
+        assert_eq!(found.name, "Ops");
+        assert_eq!(rest, " Fix the pager");
+
+        let (found, rest) = match_board("Roadmap", &boards).unwrap();
+        assert_eq!(found.name, "Roadmap");
+        assert_eq!(rest, "");
+
+        assert!(match_board("Opsy Fix", &boards).is_none());
+        assert!(match_board("Design Fix", &boards).is_none());
+    }
+}
@@ -0,0 +1,170 @@
+use std::sync::Arc;
+use chrono::{NaiveTime, Utc};
+use chrono_tz::Tz;
+use teloxide::prelude::*;
+use teloxide::types::ParseMode;
+
+use crate::application::use_cases::BuildDigestInput;
+use crate::domain::entities::DigestSchedule;
+use crate::domain::value_objects::FizzyId;
+use crate::infrastructure::telegram::bot::BotState;
+use crate::infrastructure::telegram::formatters::DigestFormatter;
+
+const USAGE: &str = "Usage:\n/digest 08:30 [timezone] - send a daily digest to this chat\n/digest now - send it right away\n/digest off - stop the daily digest";
+
+/// Handle /digest [HH:MM [timezone] | off | now]
+pub async fn handle(
+    bot: Bot,
+    msg: Message,
+    state: Arc<BotState>,
+    args: String,
+) -> ResponseResult<()> {
+    let Some(user_id) = state.user_for_message(&msg) else {
+        bot.send_message(
+            msg.chat.id,
+            "Sorry, you are not authorized to use this bot.",
+        )
+        .await?;
+        return Ok(());
+    };
+
+    let chat_id = msg.chat.id.0;
+    let mut parts = args.split_whitespace();
+    let first = parts.next().map(|s| s.to_lowercase());
+
+    match first.as_deref() {
+        None => {
+            let response = match state.digest_schedules.find(chat_id).await {
+                Ok(Some(schedule)) => DigestFormatter::format_schedule(&schedule),
+                Ok(None) => format!("No daily digest set for this chat.\n\n{}", USAGE),
+                Err(e) => format!("Error: {}", e),
+            };
+            bot.send_message(msg.chat.id, response)
+                .parse_mode(ParseMode::Html)
+                .await?;
+        }
+        Some("off") => {
+            let response = match state.digest_schedules.delete(chat_id).await {
+                Ok(true) => "📭 Daily digest turned off.".to_string(),
+                Ok(false) => "This chat has no daily digest.".to_string(),
+                Err(e) => format!("Error: {}", e),
+            };
+            bot.send_message(msg.chat.id, response).await?;
+        }
+        Some("now") => {
+            let telegram_user_id = msg.from.as_ref().map(|u| u.id.0 as i64).unwrap_or(0);
+            let timezone = match state.digest_schedules.find(chat_id).await {
+                Ok(Some(schedule)) => schedule.timezone,
+                _ => state.timezone_for(telegram_user_id).await,
+            };
+            send_digest(&bot, &msg, &state, user_id, timezone).await?;
+        }
+        Some(time) => {
+            let Some(send_at) = parse_time(time) else {
+                bot.send_message(msg.chat.id, USAGE).await?;
+                return Ok(());
+            };
+            set_schedule(&bot, &msg, &state, user_id, send_at, parts.next()).await?;
+        }
+    }
+
+    Ok(())
+}
+
+async fn set_schedule(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    user_id: FizzyId,
+    send_at: NaiveTime,
+    zone: Option<&str>,
+) -> ResponseResult<()> {
+    let chat_id = msg.chat.id.0;
+
+    let timezone = match zone {
+        Some(zone) => {
+            let Ok(timezone) = zone.parse::<Tz>() else {
+                bot.send_message(
+                    msg.chat.id,
+                    format!("Unknown timezone \"{}\". Use an IANA name like America/New_York.", zone),
+                )
+                .await?;
+                return Ok(());
+            };
+            timezone
+        }
+        None => match state.digest_schedules.find(chat_id).await {
+            Ok(Some(schedule)) => schedule.timezone,
+            _ => {
+                let telegram_user_id = msg.from.as_ref().map(|u| u.id.0 as i64).unwrap_or(0);
+                state.timezone_for(telegram_user_id).await
+            }
+        },
+    };
+
+    // If today's time has already passed, start tomorrow instead of sending right away
+    let local_now = Utc::now().with_timezone(&timezone);
+    let last_sent_on = (local_now.time() >= send_at).then(|| local_now.date_naive());
+
+    let schedule = DigestSchedule {
+        chat_id,
+        user_id,
+        send_at,
+        timezone,
+        last_sent_on,
+    };
+
+    match state.digest_schedules.save(&schedule).await {
+        Ok(()) => {
+            bot.send_message(
+                msg.chat.id,
+                format!(
+                    "{}\nUse /digest now to see it right away.",
+                    DigestFormatter::format_schedule(&schedule)
+                ),
+            )
+            .parse_mode(ParseMode::Html)
+            .await?;
+        }
+        Err(e) => {
+            tracing::error!("Failed to save digest schedule: {:?}", e);
+            bot.send_message(msg.chat.id, format!("Failed to save digest schedule: {}", e))
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+async fn send_digest(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    user_id: FizzyId,
+    timezone: Tz,
+) -> ResponseResult<()> {
+    let today = Utc::now().with_timezone(&timezone).date_naive();
+    let input = BuildDigestInput::for_day(state.account_id(), user_id, today, timezone);
+
+    match state.build_digest.execute(input).await {
+        Ok(digest) => {
+            bot.send_message(msg.chat.id, DigestFormatter::format_digest(&digest))
+                .parse_mode(ParseMode::Html)
+                .await?;
+        }
+        Err(e) => {
+            tracing::error!("Error building digest: {:?}", e);
+            bot.send_message(msg.chat.id, format!("Error: {}", e))
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Parse "8:30", "08:30" or "8"
+fn parse_time(input: &str) -> Option<NaiveTime> {
+    NaiveTime::parse_from_str(input, "%H:%M")
+        .ok()
+        .or_else(|| input.parse().ok().and_then(|hour| NaiveTime::from_hms_opt(hour, 0, 0)))
+}
@@ -0,0 +1,115 @@
+use std::sync::Arc;
+use chrono::Utc;
+use chrono_tz::Tz;
+use teloxide::prelude::*;
+use teloxide::types::ParseMode;
+
+use crate::application::use_cases::SetDueDateInput;
+use crate::infrastructure::telegram::bot::BotState;
+use crate::infrastructure::telegram::formatters::CardFormatter;
+
+/// Handle /due <number> <when>
+pub async fn handle(
+    bot: Bot,
+    msg: Message,
+    state: Arc<BotState>,
+    number: i64,
+    when: String,
+) -> ResponseResult<()> {
+    let Some(user_id) = state.user_for_message(&msg) else {
+        bot.send_message(
+            msg.chat.id,
+            "Sorry, you are not authorized to use this bot.",
+        )
+        .await?;
+        return Ok(());
+    };
+
+    if when.trim().is_empty() {
+        bot.send_message(
+            msg.chat.id,
+            "Usage: /due <number> <when>\nExamples: tomorrow, fri, next week, in 3 days, 2026-11-02, none",
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let today = state.today_for_message(&msg).await;
+    let input = SetDueDateInput {
+        account_id: state.account_id(),
+        user_id,
+        card_number: number,
+        when,
+        today,
+    };
+
+    match state.set_due_date.execute(input).await {
+        Ok(card) => {
+            let response = match CardFormatter::format_due(&card, today) {
+                Some(due) => format!("{}\n{}", CardFormatter::format_card_heading(&card), due),
+                None => format!("{}\nDue date removed.", CardFormatter::format_card_heading(&card)),
+            };
+            let sent = bot.send_message(msg.chat.id, response)
+                .parse_mode(ParseMode::Html)
+                .await?;
+            state.remember_card_message(&sent, card.number).await;
+        }
+        Err(e) => {
+            bot.send_message(msg.chat.id, format!("Failed to set due date: {}", e))
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle /timezone [zone]: show or change the sender's timezone
+pub async fn handle_timezone(
+    bot: Bot,
+    msg: Message,
+    state: Arc<BotState>,
+    zone: String,
+) -> ResponseResult<()> {
+    let Some(from) = msg.from.as_ref() else {
+        return Ok(());
+    };
+    let telegram_user_id = from.id.0 as i64;
+
+    let zone = zone.trim();
+    if zone.is_empty() {
+        let timezone = state.timezone_for(telegram_user_id).await;
+        bot.send_message(
+            msg.chat.id,
+            format!(
+                "🕒 Your timezone is {} (it's {} there).\nChange it with /timezone Europe/Berlin",
+                timezone,
+                Utc::now().with_timezone(&timezone).format("%a %H:%M")
+            ),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let Ok(timezone) = zone.parse::<Tz>() else {
+        bot.send_message(
+            msg.chat.id,
+            format!("Unknown timezone \"{}\". Use an IANA name like America/New_York.", zone),
+        )
+        .await?;
+        return Ok(());
+    };
+
+    match state.user_settings.set_timezone(telegram_user_id, timezone.name()).await {
+        Ok(()) => {
+            bot.send_message(msg.chat.id, format!("🕒 Timezone set to {}.", timezone))
+                .await?;
+        }
+        Err(e) => {
+            tracing::error!("Failed to save timezone: {:?}", e);
+            bot.send_message(msg.chat.id, format!("Failed to save timezone: {}", e))
+                .await?;
+        }
+    }
+
+    Ok(())
+}
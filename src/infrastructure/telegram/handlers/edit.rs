@@ -1 +1,579 @@
-// Edit handler - placeholder for Phase 3
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use std::time::Duration;
+use teloxide::dispatching::dialogue::{Dialogue, InMemStorage};
+use teloxide::prelude::*;
+use teloxide::types::ParseMode;
+
+use crate::application::use_cases::{GetCardDetailsInput, UpdateCardUseCaseInput, UpdateCommentInput};
+use crate::domain::value_objects::FizzyId;
+use crate::infrastructure::telegram::bot::BotState;
+use crate::infrastructure::telegram::formatters::CardFormatter;
+use crate::infrastructure::telegram::keyboards::{edit_confirm_keyboard, edit_field_keyboard};
+use crate::infrastructure::telegram::rich_text;
+
+/// How long an edit may sit idle before it is discarded
+const EDIT_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+pub type EditDialogue = Dialogue<EditState, InMemStorage<EditState>>;
+
+/// Card field that can be changed through /edit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditField {
+    Title,
+    Description,
+}
+
+impl EditField {
+    /// Parse from callback data representation
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "title" => Some(EditField::Title),
+            "description" => Some(EditField::Description),
+            _ => None,
+        }
+    }
+
+    /// Get human-readable display name
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            EditField::Title => "title",
+            EditField::Description => "description",
+        }
+    }
+}
+
+/// Per-chat state of the /edit dialogue.
+/// Every step records who started the edit, so other members of a group
+/// can't answer for them, and when it started so a stale step can be timed out.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum EditState {
+    #[default]
+    Idle,
+    ChoosingField {
+        card_number: i64,
+        telegram_user_id: i64,
+        started_at: DateTime<Utc>,
+    },
+    AwaitingValue {
+        card_number: i64,
+        field: EditField,
+        telegram_user_id: i64,
+        started_at: DateTime<Utc>,
+    },
+    Confirming {
+        card_number: i64,
+        field: EditField,
+        /// The title as typed, or the description as ActionText HTML
+        value: String,
+        telegram_user_id: i64,
+        started_at: DateTime<Utc>,
+    },
+    /// Waiting for the new text of a comment (started from /comments)
+    AwaitingComment {
+        card_number: i64,
+        comment_id: FizzyId,
+        telegram_user_id: i64,
+        started_at: DateTime<Utc>,
+    },
+}
+
+impl EditState {
+    fn card_number(&self) -> Option<i64> {
+        match self {
+            EditState::Idle => None,
+            EditState::ChoosingField { card_number, .. }
+            | EditState::AwaitingValue { card_number, .. }
+            | EditState::Confirming { card_number, .. }
+            | EditState::AwaitingComment { card_number, .. } => Some(*card_number),
+        }
+    }
+
+    /// The Telegram user who started the edit
+    fn started_by(&self) -> Option<i64> {
+        match self {
+            EditState::Idle => None,
+            EditState::ChoosingField { telegram_user_id, .. }
+            | EditState::AwaitingValue { telegram_user_id, .. }
+            | EditState::Confirming { telegram_user_id, .. }
+            | EditState::AwaitingComment { telegram_user_id, .. } => Some(*telegram_user_id),
+        }
+    }
+
+    fn started_at(&self) -> Option<DateTime<Utc>> {
+        match self {
+            EditState::Idle => None,
+            EditState::ChoosingField { started_at, .. }
+            | EditState::AwaitingValue { started_at, .. }
+            | EditState::Confirming { started_at, .. }
+            | EditState::AwaitingComment { started_at, .. } => Some(*started_at),
+        }
+    }
+
+    /// Whether someone other than `telegram_user_id` started this edit and
+    /// it hasn't timed out yet
+    fn held_by_other(&self, telegram_user_id: i64, now: DateTime<Utc>) -> bool {
+        self.started_by().is_some_and(|user| user != telegram_user_id)
+            && self
+                .started_at()
+                .is_some_and(|started| (now - started).to_std().is_ok_and(|age| age < EDIT_TIMEOUT))
+    }
+
+    /// Whether this step waits for a message, and that message is from the
+    /// user who started the edit
+    pub fn awaits_message_from(&self, msg: &Message) -> bool {
+        matches!(self, EditState::AwaitingValue { .. } | EditState::AwaitingComment { .. })
+            && self.started_by() == Some(sender_id(msg))
+    }
+}
+
+fn sender_id(msg: &Message) -> i64 {
+    msg.from.as_ref().map(|u| u.id.0 as i64).unwrap_or(0)
+}
+
+/// Handle /edit <number>: start the dialogue by asking which field to change
+pub async fn handle(
+    bot: Bot,
+    msg: Message,
+    state: Arc<BotState>,
+    dialogue: EditDialogue,
+    number: i64,
+) -> ResponseResult<()> {
+    let current = dialogue.get().await.ok().flatten().unwrap_or_default();
+    if current.held_by_other(sender_id(&msg), Utc::now()) {
+        bot.send_message(
+            msg.chat.id,
+            "Someone else is editing in this chat; try again once they finish.",
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let input = GetCardDetailsInput {
+        account_id: state.account_id(),
+        card_number: number,
+    };
+
+    match state.get_card_details.execute(input).await {
+        Ok(card) => {
+            bot.send_message(
+                msg.chat.id,
+                format!(
+                    "✏️ Editing {}\n\nWhat do you want to change?",
+                    CardFormatter::format_card_heading(&card)
+                ),
+            )
+            .parse_mode(ParseMode::Html)
+            .reply_markup(edit_field_keyboard())
+            .await?;
+
+            transition(
+                &bot,
+                &dialogue,
+                EditState::ChoosingField {
+                    card_number: number,
+                    telegram_user_id: sender_id(&msg),
+                    started_at: Utc::now(),
+                },
+            )
+            .await;
+        }
+        Err(e) => {
+            bot.send_message(msg.chat.id, format!("Failed to edit card: {}", e))
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle /cancel: abandon any edit in progress
+pub async fn cancel(bot: Bot, msg: Message, dialogue: EditDialogue) -> ResponseResult<()> {
+    let current = dialogue.get().await.ok().flatten().unwrap_or_default();
+
+    if current.started_by().is_some_and(|user| user != sender_id(&msg)) {
+        bot.send_message(msg.chat.id, "Only whoever started the edit can cancel it.")
+            .await?;
+        return Ok(());
+    }
+
+    let response = match current.card_number() {
+        Some(number) => format!("Edit of card #{} cancelled.", number),
+        None => "Nothing to cancel.".to_string(),
+    };
+
+    reset(&dialogue).await;
+    bot.send_message(msg.chat.id, response).await?;
+
+    Ok(())
+}
+
+/// Handle a plain message while the dialogue waits for the new value.
+/// Only messages from the user who started the edit get here
+/// (see [`EditState::awaits_message_from`]).
+pub async fn receive_value(
+    bot: Bot,
+    msg: Message,
+    state: Arc<BotState>,
+    dialogue: EditDialogue,
+    edit_state: EditState,
+) -> ResponseResult<()> {
+    if let EditState::AwaitingComment { card_number, comment_id, .. } = edit_state {
+        return receive_comment(bot, msg, state, dialogue, card_number, comment_id).await;
+    }
+
+    let EditState::AwaitingValue {
+        card_number,
+        field,
+        telegram_user_id,
+        ..
+    } = edit_state
+    else {
+        return Ok(());
+    };
+
+    let value = match msg.text().map(str::trim) {
+        Some(text) if text.starts_with('/') => {
+            bot.send_message(
+                msg.chat.id,
+                format!("Send the new {} or /cancel.", field.display_name()),
+            )
+            .await?;
+            return Ok(());
+        }
+        Some(text) if !text.is_empty() => text.to_string(),
+        _ => {
+            bot.send_message(
+                msg.chat.id,
+                format!("Please send the new {} as text.", field.display_name()),
+            )
+            .await?;
+            return Ok(());
+        }
+    };
+
+    let input = GetCardDetailsInput {
+        account_id: state.account_id(),
+        card_number,
+    };
+
+    let card = match state.get_card_details.execute(input).await {
+        Ok(card) => card,
+        Err(e) => {
+            reset(&dialogue).await;
+            bot.send_message(msg.chat.id, format!("Failed to edit card: {}", e))
+                .await?;
+            return Ok(());
+        }
+    };
+
+    // The description is stored as HTML; compare it as the text it reads as
+    let description = card
+        .description
+        .as_deref()
+        .map(|html| html2text::from_read(html.as_bytes(), 1000).trim().to_string());
+    let before = match field {
+        EditField::Title => Some(card.title.as_str()),
+        EditField::Description => description.as_deref(),
+    };
+
+    bot.send_message(
+        msg.chat.id,
+        CardFormatter::format_edit_preview(&card, field.display_name(), before, &value),
+    )
+    .parse_mode(ParseMode::Html)
+    .reply_markup(edit_confirm_keyboard())
+    .await?;
+
+    // Keep the description's formatting; titles are plain text in Fizzy
+    let value = match field {
+        EditField::Title => value,
+        EditField::Description => rich_text::message_tail_to_action_text(&msg, &value),
+    };
+
+    transition(
+        &bot,
+        &dialogue,
+        EditState::Confirming {
+            card_number,
+            field,
+            value,
+            telegram_user_id,
+            started_at: Utc::now(),
+        },
+    )
+    .await;
+
+    Ok(())
+}
+
+/// Handle the new text of a comment: there is no preview step, the comment
+/// is saved straight away
+async fn receive_comment(
+    bot: Bot,
+    msg: Message,
+    state: Arc<BotState>,
+    dialogue: EditDialogue,
+    card_number: i64,
+    comment_id: FizzyId,
+) -> ResponseResult<()> {
+    let Some(user_id) = state.user_for_message(&msg) else {
+        bot.send_message(
+            msg.chat.id,
+            "Sorry, you are not authorized to use this bot.",
+        )
+        .await?;
+        return Ok(());
+    };
+
+    let content = match msg.text().map(str::trim) {
+        Some(text) if text.starts_with('/') => {
+            bot.send_message(msg.chat.id, "Send the new comment text or /cancel.")
+                .await?;
+            return Ok(());
+        }
+        Some(text) if !text.is_empty() => rich_text::message_tail_to_action_text(&msg, text),
+        _ => {
+            bot.send_message(msg.chat.id, "Please send the new comment as text.")
+                .await?;
+            return Ok(());
+        }
+    };
+
+    reset(&dialogue).await;
+
+    let input = UpdateCommentInput {
+        account_id: state.account_id(),
+        user_id,
+        comment_id,
+        content,
+    };
+
+    let response = match state.update_comment.execute(input).await {
+        Ok(_comment) => format!("✏️ Comment on card #{} updated.", card_number),
+        Err(e) => format!("Failed to update comment: {}", e),
+    };
+
+    let sent = bot.send_message(msg.chat.id, response).await?;
+    state.remember_card_message(&sent, card_number).await;
+
+    Ok(())
+}
+
+/// Handle edit_* callback queries from the edit keyboards
+pub async fn handle_callback(
+    bot: Bot,
+    query: CallbackQuery,
+    state: Arc<BotState>,
+    dialogue: EditDialogue,
+    edit_state: EditState,
+) -> ResponseResult<()> {
+    let Some(user_id) = state.user_id(query.from.id.0 as i64) else {
+        bot.answer_callback_query(query.id.clone())
+            .text("You are not authorized to use this bot.")
+            .show_alert(true)
+            .await?;
+        return Ok(());
+    };
+
+    let data = query.data.as_deref().unwrap_or_default();
+    let chat_id = dialogue.chat_id();
+    let telegram_user_id = query.from.id.0 as i64;
+
+    // Only whoever started the edit may continue it; a new one may start
+    // once the current one is finished or timed out
+    if data.starts_with("edit_comment:") {
+        if edit_state.held_by_other(telegram_user_id, Utc::now()) {
+            bot.answer_callback_query(query.id.clone())
+                .text("Someone else is editing in this chat; try again once they finish")
+                .await?;
+            return Ok(());
+        }
+    } else if edit_state.started_by().is_some_and(|user| user != telegram_user_id) {
+        bot.answer_callback_query(query.id.clone())
+            .text("This edit belongs to someone else")
+            .await?;
+        return Ok(());
+    }
+
+    match (data.split_once(':'), edit_state) {
+        // Editing a comment replaces whatever the dialogue was doing
+        (Some(("edit_comment", target)), _) => {
+            let Some((Ok(card_number), comment_id)) = target
+                .split_once(':')
+                .map(|(number, id)| (number.parse::<i64>(), id))
+            else {
+                bot.answer_callback_query(query.id.clone())
+                    .text("Invalid comment")
+                    .await?;
+                return Ok(());
+            };
+
+            bot.answer_callback_query(query.id.clone()).await?;
+            bot.send_message(
+                chat_id,
+                format!("Send the new text for your comment on card #{} (or /cancel).", card_number),
+            )
+            .await?;
+
+            transition(
+                &bot,
+                &dialogue,
+                EditState::AwaitingComment {
+                    card_number,
+                    comment_id: FizzyId::new(comment_id.to_string()),
+                    telegram_user_id,
+                    started_at: Utc::now(),
+                },
+            )
+            .await;
+        }
+        (Some(("edit_field", field)), EditState::ChoosingField { card_number, .. }) => {
+            let Some(field) = EditField::parse(field) else {
+                bot.answer_callback_query(query.id.clone())
+                    .text("Unknown field")
+                    .await?;
+                return Ok(());
+            };
+
+            bot.answer_callback_query(query.id.clone()).await?;
+            bot.send_message(
+                chat_id,
+                format!(
+                    "Send the new {} for card #{} (or /cancel).",
+                    field.display_name(),
+                    card_number
+                ),
+            )
+            .await?;
+
+            transition(
+                &bot,
+                &dialogue,
+                EditState::AwaitingValue {
+                    card_number,
+                    field,
+                    telegram_user_id,
+                    started_at: Utc::now(),
+                },
+            )
+            .await;
+        }
+        (
+            None,
+            EditState::Confirming {
+                card_number,
+                field,
+                value,
+                ..
+            },
+        ) if data == "edit_save" => {
+            reset(&dialogue).await;
+
+            let (title, description) = match field {
+                EditField::Title => (Some(value), None),
+                EditField::Description => (None, Some(value)),
+            };
+
+            let input = UpdateCardUseCaseInput {
+                account_id: state.account_id(),
+                user_id,
+                card_number,
+                title,
+                description,
+            };
+
+            match state.update_card.execute(input).await {
+                Ok(card) => {
+                    bot.answer_callback_query(query.id.clone())
+                        .text(format!("Card #{} updated", card_number))
+                        .await?;
+                    bot.send_message(
+                        chat_id,
+                        format!("✏️ Card #{} {} updated.", card.number, field.display_name()),
+                    )
+                    .await?;
+                }
+                Err(e) => {
+                    bot.answer_callback_query(query.id.clone())
+                        .text(format!("Failed to update: {}", e))
+                        .show_alert(true)
+                        .await?;
+                }
+            }
+        }
+        (None, current) if data == "edit_cancel" => {
+            reset(&dialogue).await;
+            bot.answer_callback_query(query.id.clone())
+                .text("Edit cancelled")
+                .await?;
+            if let Some(number) = current.card_number() {
+                bot.send_message(chat_id, format!("Edit of card #{} cancelled.", number))
+                    .await?;
+            }
+        }
+        _ => {
+            bot.answer_callback_query(query.id.clone())
+                .text("This edit is no longer active")
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Move the dialogue to a new step and schedule its timeout
+async fn transition(bot: &Bot, dialogue: &EditDialogue, next: EditState) {
+    if let Err(e) = dialogue.update(next.clone()).await {
+        tracing::warn!("Failed to update edit dialogue: {:?}", e);
+        return;
+    }
+
+    let bot = bot.clone();
+    let dialogue = dialogue.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(EDIT_TIMEOUT).await;
+
+        // Only expire if the dialogue hasn't moved on since this step started
+        if dialogue.get().await.ok().flatten().as_ref() != Some(&next) {
+            return;
+        }
+
+        reset(&dialogue).await;
+        if let Some(number) = next.card_number() {
+            let _ = bot
+                .send_message(
+                    dialogue.chat_id(),
+                    format!("⌛ Edit of card #{} timed out.", number),
+                )
+                .await;
+        }
+    });
+}
+
+/// Return the dialogue to the idle state
+async fn reset(dialogue: &EditDialogue) {
+    if let Err(e) = dialogue.reset().await {
+        tracing::warn!("Failed to reset edit dialogue: {:?}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_held_by_other_until_timeout() {
+        let now = Utc::now();
+        let state = EditState::ChoosingField {
+            card_number: 7,
+            telegram_user_id: 1,
+            started_at: now,
+        };
+
+        assert!(state.held_by_other(2, now));
+        assert!(!state.held_by_other(1, now));
+        assert!(!state.held_by_other(2, now + chrono::Duration::minutes(6)));
+        assert!(!EditState::Idle.held_by_other(2, now));
+    }
+}
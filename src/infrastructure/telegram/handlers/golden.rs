@@ -0,0 +1,114 @@
+use std::sync::Arc;
+use teloxide::prelude::*;
+use teloxide::types::ParseMode;
+
+use crate::application::use_cases::GildCardInput;
+use crate::domain::value_objects::FizzyId;
+use crate::infrastructure::telegram::bot::BotState;
+use crate::infrastructure::telegram::handlers::card;
+use crate::infrastructure::telegram::handlers::card_list::{self, CardList};
+
+/// Handle /gild <number> and /ungild <number>
+pub async fn handle_gild(
+    bot: Bot,
+    msg: Message,
+    state: Arc<BotState>,
+    number: i64,
+    golden: bool,
+) -> ResponseResult<()> {
+    let Some(user_id) = state.user_for_message(&msg) else {
+        bot.send_message(
+            msg.chat.id,
+            "Sorry, you are not authorized to use this bot.",
+        )
+        .await?;
+        return Ok(());
+    };
+
+    let input = GildCardInput {
+        account_id: state.account_id(),
+        user_id,
+        card_number: number,
+        golden,
+    };
+
+    match state.gild_card.execute(input).await {
+        Ok(card) => {
+            let response = if golden {
+                format!("⭐ Card #{} is now golden.", card.number)
+            } else {
+                format!("Card #{} is no longer golden.", card.number)
+            };
+            let sent = bot.send_message(msg.chat.id, response).await?;
+            state.remember_card_message(&sent, card.number).await;
+        }
+        Err(e) => {
+            bot.send_message(msg.chat.id, format!("Failed to update card: {}", e))
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle /golden: list golden cards across accessible boards
+pub async fn handle_list(bot: Bot, msg: Message, state: Arc<BotState>) -> ResponseResult<()> {
+    let Some(user_id) = state.user_for_message(&msg) else {
+        bot.send_message(
+            msg.chat.id,
+            "Sorry, you are not authorized to use this bot.",
+        )
+        .await?;
+        return Ok(());
+    };
+
+    card_list::send(&bot, &msg, &state, user_id, CardList::Golden).await
+}
+
+/// Handle the ⭐ button on a card: toggle and redraw the card in place
+pub async fn handle_toggle(
+    bot: &Bot,
+    query: &CallbackQuery,
+    state: &Arc<BotState>,
+    user_id: &FizzyId,
+    card_number_str: &str,
+    golden: bool,
+) -> ResponseResult<()> {
+    let Ok(card_number) = card_number_str.parse::<i64>() else {
+        bot.answer_callback_query(query.id.clone())
+            .text("Invalid card number")
+            .show_alert(true)
+            .await?;
+        return Ok(());
+    };
+
+    let input = GildCardInput {
+        account_id: state.account_id(),
+        user_id: user_id.clone(),
+        card_number,
+        golden,
+    };
+
+    match state.gild_card.execute(input).await {
+        Ok(card) => {
+            let text = if golden { "⭐ Gilded" } else { "Ungilded" };
+            bot.answer_callback_query(query.id.clone()).text(text).await?;
+
+            if let Some(message) = &query.message {
+                let (response, keyboard) = card::card_view(state, &card).await;
+                bot.edit_message_text(message.chat().id, message.id(), response)
+                    .parse_mode(ParseMode::Html)
+                    .reply_markup(keyboard)
+                    .await?;
+            }
+        }
+        Err(e) => {
+            bot.answer_callback_query(query.id.clone())
+                .text(format!("Failed to update card: {}", e))
+                .show_alert(true)
+                .await?;
+        }
+    }
+
+    Ok(())
+}
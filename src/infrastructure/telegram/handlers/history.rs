@@ -0,0 +1,119 @@
+use std::sync::Arc;
+use chrono::Utc;
+use teloxide::prelude::*;
+use teloxide::types::{InlineKeyboardMarkup, ParseMode};
+
+use crate::application::errors::ApplicationError;
+use crate::application::use_cases::GetCardHistoryInput;
+use crate::domain::value_objects::FizzyId;
+use crate::infrastructure::telegram::bot::BotState;
+use crate::infrastructure::telegram::formatters::HistoryFormatter;
+use crate::infrastructure::telegram::keyboards::pagination_keyboard;
+
+/// Number of timeline entries shown per page. With the length caps in
+/// `HistoryFormatter`, a full page stays within Telegram's 4096 characters.
+const PAGE_SIZE: usize = 10;
+
+/// Handle /history <number>
+pub async fn handle(
+    bot: Bot,
+    msg: Message,
+    state: Arc<BotState>,
+    number: i64,
+) -> ResponseResult<()> {
+    let Some(user_id) = state.user_for_message(&msg) else {
+        bot.send_message(
+            msg.chat.id,
+            "Sorry, you are not authorized to use this bot.",
+        )
+        .await?;
+        return Ok(());
+    };
+
+    match history_page(&state, &user_id, number, 0).await {
+        Ok((response, keyboard)) => {
+            let mut request = bot
+                .send_message(msg.chat.id, response)
+                .parse_mode(ParseMode::Html);
+            if let Some(keyboard) = keyboard {
+                request = request.reply_markup(keyboard);
+            }
+            let sent = request.await?;
+            state.remember_card_message(&sent, number).await;
+        }
+        Err(e) => {
+            tracing::error!("Error loading card history: {:?}", e);
+            bot.send_message(msg.chat.id, format!("Error: {}", e))
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle a "hist:<number>:<page>" callback by editing the timeline in place
+pub async fn handle_page(
+    bot: &Bot,
+    query: &CallbackQuery,
+    state: &Arc<BotState>,
+    user_id: &FizzyId,
+    card_number_str: &str,
+    page_str: &str,
+) -> ResponseResult<()> {
+    let (Ok(card_number), Ok(page)) = (card_number_str.parse::<i64>(), page_str.parse::<usize>()) else {
+        bot.answer_callback_query(query.id.clone())
+            .text("Invalid page")
+            .await?;
+        return Ok(());
+    };
+
+    let Some(message) = &query.message else {
+        bot.answer_callback_query(query.id.clone()).await?;
+        return Ok(());
+    };
+
+    match history_page(state, user_id, card_number, page).await {
+        Ok((response, keyboard)) => {
+            bot.answer_callback_query(query.id.clone()).await?;
+            let mut request = bot
+                .edit_message_text(message.chat().id, message.id(), response)
+                .parse_mode(ParseMode::Html);
+            if let Some(keyboard) = keyboard {
+                request = request.reply_markup(keyboard);
+            }
+            request.await?;
+        }
+        Err(e) => {
+            bot.answer_callback_query(query.id.clone())
+                .text(format!("Error: {}", e))
+                .show_alert(true)
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Load one page of a card's timeline and render it with its navigation keyboard
+async fn history_page(
+    state: &Arc<BotState>,
+    user_id: &FizzyId,
+    card_number: i64,
+    page: usize,
+) -> Result<(String, Option<InlineKeyboardMarkup>), ApplicationError> {
+    let input = GetCardHistoryInput {
+        account_id: state.account_id(),
+        user_id: user_id.clone(),
+        card_number,
+        page,
+        per_page: PAGE_SIZE,
+    };
+
+    let output = state.get_card_history.execute(input).await?;
+    let response = HistoryFormatter::format_history(&output, Utc::now());
+    let keyboard = pagination_keyboard(output.page, output.has_more, |p| {
+        format!("hist:{}:{}", card_number, p)
+    });
+
+    Ok((response, keyboard))
+}
@@ -0,0 +1,103 @@
+use std::sync::Arc;
+use teloxide::prelude::*;
+use teloxide::types::{
+    InlineKeyboardButton, InlineKeyboardMarkup, InlineQueryResult, InlineQueryResultArticle,
+    InlineQueryResultsButton, InlineQueryResultsButtonKind, InputMessageContent,
+    InputMessageContentText, Me, ParseMode,
+};
+
+use crate::application::use_cases::FindCardsInput;
+use crate::domain::entities::Card;
+use crate::infrastructure::telegram::bot::BotState;
+use crate::infrastructure::telegram::formatters::CardFormatter;
+
+/// Number of cards offered per query
+const MAX_RESULTS: usize = 20;
+/// How long Telegram may reuse an answer for the same user and query, in seconds
+const CACHE_SECS: u32 = 30;
+
+/// Handle an inline query ("@bot login bug") by offering matching cards to share
+pub async fn handle(
+    bot: Bot,
+    query: InlineQuery,
+    state: Arc<BotState>,
+    me: Me,
+) -> ResponseResult<()> {
+    let Some(user_id) = state.user_id(query.from.id.0 as i64) else {
+        // Unlinked users get a button that opens a private chat with the bot
+        bot.answer_inline_query(query.id, Vec::<InlineQueryResult>::new())
+            .is_personal(true)
+            .cache_time(0)
+            .button(InlineQueryResultsButton {
+                text: "Link your Fizzy account to search cards".to_string(),
+                kind: InlineQueryResultsButtonKind::StartParameter("link".to_string()),
+            })
+            .await?;
+        return Ok(());
+    };
+
+    let input = FindCardsInput {
+        account_id: state.account_id(),
+        user_id,
+        query: query.query.clone(),
+        limit: MAX_RESULTS,
+    };
+
+    let cards = match state.find_cards.execute(input).await {
+        Ok(cards) => cards,
+        Err(e) => {
+            tracing::error!("Error answering inline query: {:?}", e);
+            Vec::new()
+        }
+    };
+
+    let results: Vec<InlineQueryResult> = cards
+        .iter()
+        .map(|card| InlineQueryResult::Article(card_result(card, &state, &me)))
+        .collect();
+
+    // Results depend on who is asking, so they must not be shared between users
+    bot.answer_inline_query(query.id, results)
+        .is_personal(true)
+        .cache_time(CACHE_SECS)
+        .await?;
+
+    Ok(())
+}
+
+fn card_result(card: &Card, state: &BotState, me: &Me) -> InlineQueryResultArticle {
+    let content = InputMessageContentText::new(CardFormatter::format_card(card, state.base_url()))
+        .parse_mode(ParseMode::Html);
+
+    // Opens a private chat with the bot showing the card
+    let mut deep_link = me.tme_url();
+    deep_link.set_query(Some(&format!("start=card_{}", card.number)));
+
+    InlineQueryResultArticle::new(
+        card.id.to_string(),
+        format!("#{} {}", card.number, card.title),
+        InputMessageContent::Text(content),
+    )
+    .description(describe(card))
+    .reply_markup(InlineKeyboardMarkup::new(vec![vec![InlineKeyboardButton::url(
+        "🤖 Open in bot",
+        deep_link,
+    )]]))
+}
+
+/// One-line summary shown under the result title
+fn describe(card: &Card) -> String {
+    let mut parts = vec![card.board_name.clone().unwrap_or_default()];
+
+    match &card.column_name {
+        Some(column) => parts.push(column.clone()),
+        None => parts.push(format!("{} {}", card.status.emoji(), card.status.display_name())),
+    }
+
+    if !card.assignee_names.is_empty() {
+        parts.push(card.assignee_names.join(", "));
+    }
+
+    parts.retain(|part| !part.is_empty());
+    parts.join(" · ")
+}
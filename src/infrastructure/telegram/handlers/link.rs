@@ -0,0 +1,217 @@
+use std::sync::Arc;
+use teloxide::prelude::*;
+use teloxide::types::{ParseMode, User};
+
+use crate::application::use_cases::{ApproveLinkInput, RequestLinkInput, VerifyLinkInput};
+use crate::domain::value_objects::FizzyId;
+use crate::infrastructure::telegram::bot::BotState;
+use crate::infrastructure::telegram::keyboards::link_approval_keyboard;
+use crate::infrastructure::text::escape_html;
+
+const USAGE: &str = "Usage:\n/link <your Fizzy email> - Request a link\n/link verify - Confirm a link from Fizzy";
+
+/// Handle /link, /link <email> and /link verify
+pub async fn handle(
+    bot: Bot,
+    msg: Message,
+    state: Arc<BotState>,
+    args: String,
+) -> ResponseResult<()> {
+    let Some(from) = msg.from.clone() else {
+        return Ok(());
+    };
+    let telegram_user_id = from.id.0 as i64;
+
+    if state.is_authorized(telegram_user_id) {
+        bot.send_message(msg.chat.id, "Your Telegram account is already linked to Fizzy.")
+            .await?;
+        return Ok(());
+    }
+
+    match args.trim() {
+        "" => bot.send_message(msg.chat.id, USAGE).await.map(|_| ()),
+        "verify" => verify(&bot, &msg, &state, telegram_user_id).await,
+        email => request(&bot, &msg, &state, &from, email).await,
+    }
+}
+
+/// Create a link request and notify the account admins
+async fn request(
+    bot: &Bot,
+    msg: &Message,
+    state: &Arc<BotState>,
+    from: &User,
+    email: &str,
+) -> ResponseResult<()> {
+    let telegram_name = display_name(from);
+    let input = RequestLinkInput {
+        account_id: state.account_id(),
+        telegram_user_id: from.id.0 as i64,
+        telegram_name: Some(telegram_name.clone()),
+        chat_id: msg.chat.id.0,
+        email: email.to_string(),
+    };
+
+    let output = match state.request_link.execute(input).await {
+        Ok(output) => output,
+        Err(e) => {
+            bot.send_message(msg.chat.id, format!("Failed to request link: {}", e))
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let code = &output.request.code;
+    let notice = format!(
+        "🔗 <b>{}</b> (Telegram id {}) wants to act as <b>{}</b> ({}).\nCode: <code>{}</code>",
+        escape_html(&telegram_name),
+        from.id.0,
+        escape_html(&output.user.name),
+        escape_html(email),
+        code
+    );
+
+    let mut admins_notified = 0;
+    for admin in &output.admins {
+        for admin_chat in state.users.telegram_user_ids(&admin.id) {
+            let sent = bot
+                .send_message(ChatId(admin_chat), &notice)
+                .parse_mode(ParseMode::Html)
+                .reply_markup(link_approval_keyboard(code))
+                .await;
+            match sent {
+                Ok(_) => admins_notified += 1,
+                Err(e) => tracing::warn!("Failed to notify admin {} of link request: {}", admin_chat, e),
+            }
+        }
+    }
+
+    let mut response = format!(
+        "🔗 Link request for {} created.\nYour code: <code>{}</code> (valid for 15 minutes)\n\nTo confirm it, ",
+        escape_html(email),
+        code
+    );
+    if admins_notified > 0 {
+        response.push_str("wait for an admin to approve it, or ");
+    }
+    response.push_str(&format!(
+        "add a comment containing <code>{}</code> to any card in Fizzy while signed in as yourself, then send /link verify.",
+        code
+    ));
+
+    bot.send_message(msg.chat.id, response)
+        .parse_mode(ParseMode::Html)
+        .await?;
+
+    Ok(())
+}
+
+/// Confirm a pending request via a comment posted in Fizzy
+async fn verify(
+    bot: &Bot,
+    msg: &Message,
+    state: &Arc<BotState>,
+    telegram_user_id: i64,
+) -> ResponseResult<()> {
+    let input = VerifyLinkInput {
+        account_id: state.account_id(),
+        telegram_user_id,
+    };
+
+    match state.verify_link.execute(input).await {
+        Ok(output) => {
+            complete_link(bot, state, telegram_user_id, &output.user.id, &output.user.name, msg.chat.id)
+                .await?;
+        }
+        Err(e) => {
+            bot.send_message(msg.chat.id, format!("Could not verify link: {}", e))
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle the "link_approve:<code>" and "link_deny:<code>" callbacks from admins
+pub async fn handle_decision(
+    bot: &Bot,
+    query: &CallbackQuery,
+    state: &Arc<BotState>,
+    approver_id: &FizzyId,
+    code: &str,
+    approve: bool,
+) -> ResponseResult<()> {
+    let input = ApproveLinkInput {
+        account_id: state.account_id(),
+        approver_id: approver_id.clone(),
+        code: code.to_string(),
+        approve,
+    };
+
+    let output = match state.approve_link.execute(input).await {
+        Ok(output) => output,
+        Err(e) => {
+            bot.answer_callback_query(query.id.clone())
+                .text(format!("Failed: {}", e))
+                .show_alert(true)
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let request = &output.request;
+    let requester = request.telegram_name.clone().unwrap_or_else(|| request.telegram_user_id.to_string());
+    let requester_chat = ChatId(request.chat_id);
+
+    let summary = if output.approved {
+        complete_link(bot, state, request.telegram_user_id, &output.user.id, &output.user.name, requester_chat)
+            .await?;
+        format!("✅ {} is now linked to {}.", requester, output.user.name)
+    } else {
+        bot.send_message(requester_chat, "❌ Your link request was denied.")
+            .await?;
+        format!("❌ Link request from {} denied.", requester)
+    };
+
+    bot.answer_callback_query(query.id.clone()).await?;
+    if let Some(message) = &query.message {
+        bot.edit_message_text(message.chat().id, message.id(), summary)
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// Persist the link and tell the requester they can start using the bot
+async fn complete_link(
+    bot: &Bot,
+    state: &Arc<BotState>,
+    telegram_user_id: i64,
+    user_id: &FizzyId,
+    user_name: &str,
+    chat_id: ChatId,
+) -> ResponseResult<()> {
+    if let Err(e) = state.users.link(telegram_user_id, user_id).await {
+        tracing::error!("Failed to save user link: {:?}", e);
+        bot.send_message(chat_id, "Failed to save the link. Please try again.")
+            .await?;
+        return Ok(());
+    }
+
+    tracing::info!("Telegram user {} linked to {}", telegram_user_id, user_name);
+    bot.send_message(
+        chat_id,
+        format!("✅ Linked! You now act as {} in Fizzy. Type /help to get started.", user_name),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Name shown to admins for a Telegram user
+fn display_name(user: &User) -> String {
+    match &user.username {
+        Some(username) => format!("{} (@{})", user.full_name(), username),
+        None => user.full_name(),
+    }
+}
@@ -1,13 +1,29 @@
+pub mod assign;
+pub mod attachment;
 pub mod board;
 pub mod boards;
 pub mod callbacks;
 pub mod card;
+pub mod card_list;
 pub mod close;
 pub mod comment;
+pub mod comments;
 pub mod create;
+pub mod digest;
+pub mod due;
 pub mod edit;
+pub mod golden;
 pub mod help;
+pub mod inline;
+pub mod history;
+pub mod link;
 pub mod move_card;
 pub mod my_cards;
+pub mod postpone;
 pub mod reopen;
+pub mod reply;
+pub mod search;
 pub mod start;
+pub mod subscribe;
+pub mod tags;
+pub mod triage;
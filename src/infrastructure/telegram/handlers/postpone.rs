@@ -0,0 +1,76 @@
+use std::sync::Arc;
+use teloxide::prelude::*;
+
+use crate::application::use_cases::{BoardRef, BoardSection, PostponeCardInput};
+use crate::infrastructure::telegram::bot::BotState;
+use crate::infrastructure::telegram::handlers::card_list::{self, CardList};
+
+/// Handle /notnow <number> and /resume <number>
+pub async fn handle(
+    bot: Bot,
+    msg: Message,
+    state: Arc<BotState>,
+    number: i64,
+    postpone: bool,
+) -> ResponseResult<()> {
+    let Some(user_id) = state.user_for_message(&msg) else {
+        bot.send_message(
+            msg.chat.id,
+            "Sorry, you are not authorized to use this bot.",
+        )
+        .await?;
+        return Ok(());
+    };
+
+    let input = PostponeCardInput {
+        account_id: state.account_id(),
+        user_id,
+        card_number: number,
+        postpone,
+    };
+
+    match state.postpone_card.execute(input).await {
+        Ok(card) => {
+            let response = if postpone {
+                format!("⏸️ Card #{} moved to Not now.", card.number)
+            } else {
+                format!("▶️ Card #{} is back in triage.", card.number)
+            };
+            let sent = bot.send_message(msg.chat.id, response).await?;
+            state.remember_card_message(&sent, card.number).await;
+        }
+        Err(e) => {
+            bot.send_message(msg.chat.id, format!("Failed to update card: {}", e))
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle /postponed <board>
+pub async fn handle_list(
+    bot: Bot,
+    msg: Message,
+    state: Arc<BotState>,
+    name: String,
+) -> ResponseResult<()> {
+    let Some(user_id) = state.user_for_message(&msg) else {
+        bot.send_message(
+            msg.chat.id,
+            "Sorry, you are not authorized to use this bot.",
+        )
+        .await?;
+        return Ok(());
+    };
+
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        bot.send_message(msg.chat.id, "Usage: /postponed <board name>")
+            .await?;
+        return Ok(());
+    }
+
+    let list = CardList::Board(BoardRef::Name(name), BoardSection::NotNow);
+    card_list::send(&bot, &msg, &state, user_id, list).await
+}
@@ -10,16 +10,26 @@ pub async fn handle(
     state: Arc<BotState>,
     number: i64,
 ) -> ResponseResult<()> {
+    let Some(user_id) = state.user_for_message(&msg) else {
+        bot.send_message(
+            msg.chat.id,
+            "Sorry, you are not authorized to use this bot.",
+        )
+        .await?;
+        return Ok(());
+    };
+
     let input = ReopenCardInput {
         account_id: state.account_id(),
-        user_id: state.user_id(),
+        user_id,
         card_number: number,
     };
 
     match state.reopen_card.execute(input).await {
         Ok(()) => {
-            bot.send_message(msg.chat.id, format!("Card #{} has been reopened.", number))
+            let sent = bot.send_message(msg.chat.id, format!("Card #{} has been reopened.", number))
                 .await?;
+            state.remember_card_message(&sent, number).await;
         }
         Err(e) => {
             bot.send_message(msg.chat.id, format!("Failed to reopen card: {}", e))
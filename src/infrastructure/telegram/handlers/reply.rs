@@ -0,0 +1,276 @@
+use std::sync::Arc;
+use chrono::NaiveDate;
+use teloxide::prelude::*;
+use teloxide::types::{ParseMode, ReplyParameters};
+
+use crate::application::errors::ApplicationError;
+use crate::application::use_cases::{
+    AddCommentInput, CloseCardInput, GetCardDetailsInput, MoveCardInput, ReopenCardInput,
+    SetDueDateInput,
+};
+use crate::domain::entities::Column;
+use crate::domain::value_objects::{DueDate, FizzyId};
+use crate::infrastructure::telegram::bot::BotState;
+use crate::infrastructure::telegram::formatters::CardFormatter;
+use crate::infrastructure::telegram::rich_text;
+use crate::infrastructure::text::escape_html;
+
+/// What a reply to a card message asks for
+#[derive(Debug, PartialEq)]
+enum ReplyAction<'a> {
+    Close,
+    Reopen,
+    Move(&'a Column),
+    Due(&'a str),
+    Comment(&'a str),
+}
+
+/// Handle a text reply to a message the bot sent about a card
+pub async fn handle(bot: Bot, msg: Message, state: Arc<BotState>) -> ResponseResult<()> {
+    let (Some(text), Some(replied_to)) = (msg.text(), msg.reply_to_message()) else {
+        return Ok(());
+    };
+
+    // Unknown commands sent as replies are not comments
+    if text.starts_with('/') {
+        return Ok(());
+    }
+
+    // Replies to anything else (e.g. people replying to each other in a group) are none of our business
+    let card_number = match state.card_messages.find(msg.chat.id.0, replied_to.id.0).await {
+        Ok(Some(card_number)) => card_number,
+        Ok(None) => return Ok(()),
+        Err(e) => {
+            tracing::error!("Failed to look up replied-to message: {:?}", e);
+            return Ok(());
+        }
+    };
+
+    let Some(user_id) = state.user_for_message(&msg) else {
+        bot.send_message(
+            msg.chat.id,
+            "Sorry, you are not authorized to use this bot.",
+        )
+        .await?;
+        return Ok(());
+    };
+
+    // "move" needs the card's columns to tell a command from a comment
+    let columns = match card_columns(&state, card_number).await {
+        Ok(columns) => columns,
+        Err(e) => {
+            bot.send_message(msg.chat.id, format!("Failed to update card #{}: {}", card_number, e))
+                .reply_parameters(ReplyParameters::new(msg.id))
+                .await?;
+            return Ok(());
+        }
+    };
+    let today = state.today_for_message(&msg).await;
+
+    let response = match parse_reply(text, &columns, today) {
+        ReplyAction::Close => close(&state, user_id, card_number).await,
+        ReplyAction::Reopen => reopen(&state, user_id, card_number).await,
+        ReplyAction::Move(column) => move_to(&state, user_id, card_number, column).await,
+        ReplyAction::Due(when) => set_due(&state, user_id, card_number, when, today).await,
+        ReplyAction::Comment(content) => {
+            let content = rich_text::message_tail_to_action_text(&msg, content);
+            comment(&state, user_id, card_number, content).await
+        }
+    };
+
+    let sent = bot
+        .send_message(msg.chat.id, response)
+        .parse_mode(ParseMode::Html)
+        .reply_parameters(ReplyParameters::new(msg.id))
+        .await?;
+    state.remember_card_message(&sent, card_number).await;
+
+    Ok(())
+}
+
+async fn close(state: &BotState, user_id: FizzyId, card_number: i64) -> String {
+    let input = CloseCardInput {
+        account_id: state.account_id(),
+        user_id,
+        card_number,
+    };
+
+    match state.close_card.execute(input).await {
+        Ok(()) => format!("✅ Card #{} has been closed.", card_number),
+        Err(e) => escape_html(&format!("Failed to close card: {}", e)),
+    }
+}
+
+async fn reopen(state: &BotState, user_id: FizzyId, card_number: i64) -> String {
+    let input = ReopenCardInput {
+        account_id: state.account_id(),
+        user_id,
+        card_number,
+    };
+
+    match state.reopen_card.execute(input).await {
+        Ok(()) => format!("🔄 Card #{} has been reopened.", card_number),
+        Err(e) => escape_html(&format!("Failed to reopen card: {}", e)),
+    }
+}
+
+/// The columns on the card's board
+async fn card_columns(state: &BotState, card_number: i64) -> Result<Vec<Column>, ApplicationError> {
+    let card = state
+        .get_card_details
+        .execute(GetCardDetailsInput {
+            account_id: state.account_id(),
+            card_number,
+        })
+        .await?;
+
+    state
+        .board_repository
+        .get_columns(&state.account_id(), &card.board_id)
+        .await
+        .map_err(ApplicationError::DomainError)
+}
+
+async fn move_to(state: &BotState, user_id: FizzyId, card_number: i64, column: &Column) -> String {
+    let input = MoveCardInput {
+        account_id: state.account_id(),
+        user_id,
+        card_number,
+        column_id: column.id.clone(),
+    };
+
+    match state.move_card.execute(input).await {
+        Ok(_) => format!("📁 Card #{} moved to {}.", card_number, escape_html(&column.name)),
+        Err(e) => escape_html(&format!("Failed to move card: {}", e)),
+    }
+}
+
+async fn set_due(
+    state: &BotState,
+    user_id: FizzyId,
+    card_number: i64,
+    when: &str,
+    today: NaiveDate,
+) -> String {
+    let input = SetDueDateInput {
+        account_id: state.account_id(),
+        user_id,
+        card_number,
+        when: when.to_string(),
+        today,
+    };
+
+    match state.set_due_date.execute(input).await {
+        Ok(card) => match CardFormatter::format_due(&card, today) {
+            Some(due) => format!("{}\n{}", CardFormatter::format_card_heading(&card), due),
+            None => format!("{}\nDue date removed.", CardFormatter::format_card_heading(&card)),
+        },
+        Err(e) => escape_html(&format!("Failed to set due date: {}", e)),
+    }
+}
+
+async fn comment(state: &BotState, user_id: FizzyId, card_number: i64, content: String) -> String {
+    let input = AddCommentInput {
+        account_id: state.account_id(),
+        user_id,
+        card_number,
+        content,
+    };
+
+    match state.add_comment.execute(input).await {
+        Ok(_comment) => format!("💬 Comment added to card #{}.", card_number),
+        Err(e) => escape_html(&format!("Failed to add comment: {}", e)),
+    }
+}
+
+/// Treat a reply as a command only when it is one of the short keywords
+/// followed by something it understands: "move Doing" when the board has
+/// such a column, "due friday" when it is a date. Anything else, like "due to
+/// the outage..." or "move this to next sprint", is a comment.
+fn parse_reply<'a>(text: &'a str, columns: &'a [Column], today: NaiveDate) -> ReplyAction<'a> {
+    let text = text.trim();
+    let (keyword, rest) = match text.split_once(char::is_whitespace) {
+        Some((keyword, rest)) => (keyword, rest.trim()),
+        None => (text, ""),
+    };
+
+    match (keyword.to_lowercase().as_str(), rest.is_empty()) {
+        ("close", true) => ReplyAction::Close,
+        ("reopen", true) => ReplyAction::Reopen,
+        ("move", false) => match find_column(columns, rest) {
+            Some(column) => ReplyAction::Move(column),
+            None => ReplyAction::Comment(text),
+        },
+        ("due", false) if DueDate::parse(rest, today).is_ok() => ReplyAction::Due(rest),
+        _ => ReplyAction::Comment(text),
+    }
+}
+
+/// Find a column by name, ignoring case; a unique prefix is enough
+fn find_column<'a>(columns: &'a [Column], name: &str) -> Option<&'a Column> {
+    let name = name.to_lowercase();
+
+    if let Some(column) = columns.iter().find(|c| c.name.to_lowercase() == name) {
+        return Some(column);
+    }
+
+    let mut matches = columns.iter().filter(|c| c.name.to_lowercase().starts_with(&name));
+    match (matches.next(), matches.next()) {
+        (Some(column), None) => Some(column),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column(name: &str) -> Column {
+        Column {
+            id: FizzyId::generate(),
+            account_id: FizzyId::generate(),
+            board_id: FizzyId::generate(),
+            name: name.to_string(),
+            color: "blue".to_string(),
+            position: 0,
+        }
+    }
+
+    #[test]
+    fn test_parse_reply() {
+        let columns = vec![column("Doing"), column("Done")];
+        let today = NaiveDate::from_ymd_opt(2026, 10, 17).unwrap();
+        let parse = |text| parse_reply(text, &columns, today);
+
+        assert_eq!(parse("close"), ReplyAction::Close);
+        assert_eq!(parse(" Reopen "), ReplyAction::Reopen);
+        assert_eq!(parse("move Doing"), ReplyAction::Move(&columns[0]));
+        assert_eq!(parse("Due next week"), ReplyAction::Due("next week"));
+        assert_eq!(parse("move"), ReplyAction::Comment("move"));
+        assert_eq!(parse("close this once QA signs off"), ReplyAction::Comment("close this once QA signs off"));
+    }
+
+    #[test]
+    fn test_parse_reply_falls_back_to_comment() {
+        let columns = vec![column("Doing"), column("Done")];
+        let today = NaiveDate::from_ymd_opt(2026, 10, 17).unwrap();
+        let parse = |text| parse_reply(text, &columns, today);
+
+        assert_eq!(
+            parse("due to the outage we slipped a week"),
+            ReplyAction::Comment("due to the outage we slipped a week")
+        );
+        assert_eq!(parse("move this to next sprint"), ReplyAction::Comment("move this to next sprint"));
+        assert_eq!(parse("move do"), ReplyAction::Comment("move do"));
+    }
+
+    #[test]
+    fn test_find_column() {
+        let columns = vec![column("Doing"), column("Done"), column("Review")];
+
+        assert_eq!(find_column(&columns, "doing").map(|c| c.name.as_str()), Some("Doing"));
+        assert_eq!(find_column(&columns, "rev").map(|c| c.name.as_str()), Some("Review"));
+        assert_eq!(find_column(&columns, "do").map(|c| c.name.as_str()), None);
+        assert_eq!(find_column(&columns, "Backlog").map(|c| c.name.as_str()), None);
+    }
+}
@@ -0,0 +1,140 @@
+use std::sync::Arc;
+use teloxide::prelude::*;
+use teloxide::types::{InlineKeyboardMarkup, MessageId};
+
+use crate::application::errors::ApplicationError;
+use crate::application::use_cases::SearchCardsInput;
+use crate::domain::value_objects::FizzyId;
+use crate::infrastructure::telegram::bot::BotState;
+use crate::infrastructure::telegram::formatters::CardFormatter;
+use crate::infrastructure::telegram::keyboards::pagination_keyboard;
+
+/// Number of results shown per page
+const PAGE_SIZE: usize = 10;
+/// Telegram's limit on callback data length in bytes
+const MAX_CALLBACK_DATA: usize = 64;
+
+pub async fn handle(
+    bot: Bot,
+    msg: Message,
+    state: Arc<BotState>,
+    terms: String,
+) -> ResponseResult<()> {
+    let Some(user_id) = state.user_for_message(&msg) else {
+        bot.send_message(
+            msg.chat.id,
+            "Sorry, you are not authorized to use this bot.",
+        )
+        .await?;
+        return Ok(());
+    };
+
+    let terms = terms.trim().to_string();
+    if terms.is_empty() {
+        bot.send_message(msg.chat.id, "Usage: /search <terms>")
+            .await?;
+        return Ok(());
+    }
+
+    match search_page(&state, &user_id, &terms, 0).await {
+        Ok((response, keyboard)) => {
+            let mut request = bot
+                .send_message(msg.chat.id, response)
+                .parse_mode(teloxide::types::ParseMode::Html);
+            if let Some(keyboard) = keyboard {
+                request = request.reply_markup(keyboard);
+            }
+            request.await?;
+        }
+        Err(e) => {
+            tracing::error!("Error searching cards: {:?}", e);
+            bot.send_message(msg.chat.id, format!("Search failed: {}", e))
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle a "search:<page>:<terms>" callback by editing the results in place
+pub async fn handle_page(
+    bot: &Bot,
+    query: &CallbackQuery,
+    state: &Arc<BotState>,
+    user_id: &FizzyId,
+    page: usize,
+    terms: &str,
+) -> ResponseResult<()> {
+    let Some(message) = &query.message else {
+        bot.answer_callback_query(query.id.clone()).await?;
+        return Ok(());
+    };
+
+    match search_page(state, user_id, terms, page).await {
+        Ok((response, keyboard)) => {
+            bot.answer_callback_query(query.id.clone()).await?;
+            edit_results(bot, message.chat().id, message.id(), response, keyboard).await?;
+        }
+        Err(e) => {
+            bot.answer_callback_query(query.id.clone())
+                .text(format!("Search failed: {}", e))
+                .show_alert(true)
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Run the search and render one page of results with its navigation keyboard
+async fn search_page(
+    state: &Arc<BotState>,
+    user_id: &FizzyId,
+    terms: &str,
+    page: usize,
+) -> Result<(String, Option<InlineKeyboardMarkup>), ApplicationError> {
+    let input = SearchCardsInput {
+        account_id: state.account_id(),
+        user_id: user_id.clone(),
+        query: terms.to_string(),
+        page,
+        per_page: PAGE_SIZE,
+    };
+
+    let output = state.search_cards.execute(input).await?;
+    let mut response = CardFormatter::format_search_results(terms, &output.results, output.total);
+
+    // Paging needs the terms in the callback data; very long queries only get one page
+    let fits = search_callback_data(output.page + 1, terms).len() <= MAX_CALLBACK_DATA;
+    let keyboard = if fits {
+        pagination_keyboard(output.page, output.has_more, |p| search_callback_data(p, terms))
+    } else {
+        if output.has_more {
+            response.push_str("\n\nShowing the best matches only. Try a shorter query to page through all results.");
+        }
+        None
+    };
+
+    Ok((response, keyboard))
+}
+
+fn search_callback_data(page: usize, terms: &str) -> String {
+    format!("search:{}:{}", page, terms)
+}
+
+async fn edit_results(
+    bot: &Bot,
+    chat_id: ChatId,
+    message_id: MessageId,
+    response: String,
+    keyboard: Option<InlineKeyboardMarkup>,
+) -> ResponseResult<()> {
+    let mut request = bot
+        .edit_message_text(chat_id, message_id, response)
+        .parse_mode(teloxide::types::ParseMode::Html);
+    if let Some(keyboard) = keyboard {
+        request = request.reply_markup(keyboard);
+    }
+    request.await?;
+    Ok(())
+}
@@ -2,19 +2,36 @@ use std::sync::Arc;
 use teloxide::prelude::*;
 
 use crate::infrastructure::telegram::bot::BotState;
-
-pub async fn handle(bot: Bot, msg: Message, state: Arc<BotState>) -> ResponseResult<()> {
+use crate::infrastructure::telegram::handlers::card;
+
+/// Handle /start, optionally with a deep-link payload such as "card_123"
+pub async fn handle(
+    bot: Bot,
+    msg: Message,
+    state: Arc<BotState>,
+    payload: String,
+) -> ResponseResult<()> {
     let user_id = msg.from.as_ref().map(|u| u.id.0 as i64).unwrap_or(0);
+    let payload = payload.trim();
 
     if !state.is_authorized(user_id) {
-        bot.send_message(
-            msg.chat.id,
-            "Sorry, you are not authorized to use this bot.",
-        )
-        .await?;
+        // The inline mode button offered to users who aren't linked yet
+        let text = if payload == "link" {
+            "Link your Telegram account to Fizzy to search and share cards.
+Send /link <your Fizzy email> and an admin will approve it."
+        } else {
+            "Sorry, you are not authorized to use this bot.
+Use /link <your Fizzy email> to link your account."
+        };
+        bot.send_message(msg.chat.id, text).await?;
         return Ok(());
     }
 
+    // "Open in bot" links on cards shared inline
+    if let Some(number) = payload.strip_prefix("card_").and_then(|n| n.parse().ok()) {
+        return card::handle(bot, msg, state, number).await;
+    }
+
     let welcome = r#"Welcome to Fizzy Bot!
 
 I help you manage your Fizzy cards from Telegram.
@@ -23,9 +40,13 @@ Quick commands:
 /me - Your assigned cards
 /boards - List boards
 /card 123 - View card #123
+/search login bug - Search cards
 /create My new task - Create a card
+/create @Roadmap My new task - Create it on a board
 /close 123 - Close card #123
 
+Type my @username and a card number or title in any chat to share a card.
+
 Type /help for all commands."#;
 
     bot.send_message(msg.chat.id, welcome).await?;
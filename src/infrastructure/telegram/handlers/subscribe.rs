@@ -0,0 +1,99 @@
+use std::sync::Arc;
+use teloxide::prelude::*;
+use teloxide::types::ParseMode;
+
+use crate::application::use_cases::{ListSubscriptionsInput, SubscribeInput, SubscriptionRef};
+use crate::infrastructure::telegram::bot::BotState;
+use crate::infrastructure::telegram::formatters::SubscriptionFormatter;
+
+const USAGE: &str = "Usage:\n/subscribe board <name>\n/subscribe card <number>\n/unsubscribe board <name>\n/unsubscribe card <number>";
+
+/// Handle /subscribe and /unsubscribe; /subscribe without arguments lists the chat's subscriptions
+pub async fn handle(
+    bot: Bot,
+    msg: Message,
+    state: Arc<BotState>,
+    args: String,
+    subscribe: bool,
+) -> ResponseResult<()> {
+    let Some(user_id) = state.user_for_message(&msg) else {
+        bot.send_message(
+            msg.chat.id,
+            "Sorry, you are not authorized to use this bot.",
+        )
+        .await?;
+        return Ok(());
+    };
+
+    if subscribe && args.trim().is_empty() {
+        return handle_list(bot, msg, state).await;
+    }
+
+    let Some(target) = parse_target(&args) else {
+        bot.send_message(msg.chat.id, USAGE).await?;
+        return Ok(());
+    };
+
+    let input = SubscribeInput {
+        account_id: state.account_id(),
+        user_id,
+        chat_id: msg.chat.id.0,
+        target,
+        subscribe,
+    };
+
+    match state.subscribe.execute(input).await {
+        Ok(output) => {
+            let target = SubscriptionFormatter::format_target(&output.target);
+            let response = match (subscribe, output.changed) {
+                (true, true) => format!("🔔 This chat will now get updates for {}.", target),
+                (true, false) => format!("This chat is already subscribed to {}.", target),
+                (false, true) => format!("🔕 No more updates for {}.", target),
+                (false, false) => format!("This chat wasn't subscribed to {}.", target),
+            };
+            bot.send_message(msg.chat.id, response)
+                .parse_mode(ParseMode::Html)
+                .await?;
+        }
+        Err(e) => {
+            bot.send_message(msg.chat.id, format!("Error: {}", e))
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_list(bot: Bot, msg: Message, state: Arc<BotState>) -> ResponseResult<()> {
+    let input = ListSubscriptionsInput {
+        account_id: state.account_id(),
+        chat_id: msg.chat.id.0,
+    };
+
+    match state.list_subscriptions.execute(input).await {
+        Ok(output) => {
+            bot.send_message(msg.chat.id, SubscriptionFormatter::format_subscriptions(&output.targets))
+                .parse_mode(ParseMode::Html)
+                .await?;
+        }
+        Err(e) => {
+            tracing::error!("Error listing subscriptions: {:?}", e);
+            bot.send_message(msg.chat.id, format!("Error: {}", e))
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Parse "board <name>" or "card <number>"
+fn parse_target(args: &str) -> Option<SubscriptionRef> {
+    let (kind, rest) = args.trim().split_once(char::is_whitespace)?;
+    let rest = rest.trim();
+
+    match kind.to_lowercase().as_str() {
+        "board" if !rest.is_empty() => Some(SubscriptionRef::Board(rest.to_string())),
+        "card" => rest.trim_start_matches('#').parse().ok().map(SubscriptionRef::Card),
+        _ => None,
+    }
+}
@@ -0,0 +1,299 @@
+use std::sync::Arc;
+use teloxide::prelude::*;
+use teloxide::types::ParseMode;
+
+use crate::application::use_cases::{
+    ListTagsInput, TagCardInput, ToggleCardTagInput, UntagCardInput,
+};
+use crate::domain::value_objects::FizzyId;
+use crate::infrastructure::telegram::bot::BotState;
+use crate::infrastructure::telegram::formatters::{CardFormatter, TagFormatter};
+use crate::infrastructure::telegram::keyboards::tag_toggle_keyboard;
+
+/// Handle /tag <number> <tag...>; without tags, show the toggle keyboard
+pub async fn handle_tag(
+    bot: Bot,
+    msg: Message,
+    state: Arc<BotState>,
+    number: i64,
+    tags: String,
+) -> ResponseResult<()> {
+    let Some(user_id) = state.user_for_message(&msg) else {
+        bot.send_message(
+            msg.chat.id,
+            "Sorry, you are not authorized to use this bot.",
+        )
+        .await?;
+        return Ok(());
+    };
+
+    if tags.trim().is_empty() {
+        return send_toggle_keyboard(&bot, msg.chat.id, &state, number).await;
+    }
+
+    let input = TagCardInput {
+        account_id: state.account_id(),
+        user_id,
+        card_number: number,
+        titles: tags.split_whitespace().map(str::to_string).collect(),
+    };
+
+    match state.tag_card.execute(input).await {
+        Ok(output) => {
+            let response = if output.added.is_empty() {
+                format!("Card #{} already has those tags.", output.card.number)
+            } else {
+                format!(
+                    "🏷 Tagged card #{} with {}.\nTags: {}",
+                    output.card.number,
+                    TagFormatter::format_titles(&output.added),
+                    TagFormatter::format_titles(&output.card.tag_titles)
+                )
+            };
+            bot.send_message(msg.chat.id, response)
+                .parse_mode(ParseMode::Html)
+                .await?;
+        }
+        Err(e) => {
+            bot.send_message(msg.chat.id, format!("Failed to tag card: {}", e))
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle /untag <number> <tag...>; without tags, show the toggle keyboard
+pub async fn handle_untag(
+    bot: Bot,
+    msg: Message,
+    state: Arc<BotState>,
+    number: i64,
+    tags: String,
+) -> ResponseResult<()> {
+    let Some(user_id) = state.user_for_message(&msg) else {
+        bot.send_message(
+            msg.chat.id,
+            "Sorry, you are not authorized to use this bot.",
+        )
+        .await?;
+        return Ok(());
+    };
+
+    if tags.trim().is_empty() {
+        return send_toggle_keyboard(&bot, msg.chat.id, &state, number).await;
+    }
+
+    let input = UntagCardInput {
+        account_id: state.account_id(),
+        user_id,
+        card_number: number,
+        titles: tags.split_whitespace().map(str::to_string).collect(),
+    };
+
+    match state.untag_card.execute(input).await {
+        Ok(output) => {
+            let mut lines = Vec::new();
+            if !output.removed.is_empty() {
+                lines.push(format!(
+                    "🏷 Removed {} from card #{}.",
+                    TagFormatter::format_titles(&output.removed),
+                    output.card.number
+                ));
+            }
+            if !output.missing.is_empty() {
+                lines.push(format!(
+                    "Not on the card: {}",
+                    TagFormatter::format_titles(&output.missing)
+                ));
+            }
+            lines.push(format!("Tags: {}", TagFormatter::format_titles(&output.card.tag_titles)));
+
+            bot.send_message(msg.chat.id, lines.join("\n"))
+                .parse_mode(ParseMode::Html)
+                .await?;
+        }
+        Err(e) => {
+            bot.send_message(msg.chat.id, format!("Failed to untag card: {}", e))
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle /tags [number]: list account tags, or toggle tags on a card
+pub async fn handle_list(
+    bot: Bot,
+    msg: Message,
+    state: Arc<BotState>,
+    args: String,
+) -> ResponseResult<()> {
+    let user_id = msg.from.as_ref().map(|u| u.id.0 as i64).unwrap_or(0);
+
+    if !state.is_authorized(user_id) {
+        bot.send_message(
+            msg.chat.id,
+            "Sorry, you are not authorized to use this bot.",
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let args = args.trim().trim_start_matches('#');
+    if !args.is_empty() {
+        let Ok(number) = args.parse::<i64>() else {
+            bot.send_message(msg.chat.id, "Usage: /tags [card number]")
+                .await?;
+            return Ok(());
+        };
+        return send_toggle_keyboard(&bot, msg.chat.id, &state, number).await;
+    }
+
+    let input = ListTagsInput {
+        account_id: state.account_id(),
+        card_number: None,
+    };
+
+    match state.list_tags.execute(input).await {
+        Ok(output) => {
+            bot.send_message(msg.chat.id, TagFormatter::format_tag_list(&output.tags))
+                .parse_mode(ParseMode::Html)
+                .await?;
+        }
+        Err(e) => {
+            bot.send_message(msg.chat.id, format!("Failed to list tags: {}", e))
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle "tag_toggle:<number>:<tag_id>" by flipping the tag and redrawing the keyboard
+pub async fn handle_toggle(
+    bot: &Bot,
+    query: &CallbackQuery,
+    state: &Arc<BotState>,
+    user_id: &FizzyId,
+    card_number_str: &str,
+    tag_id: &str,
+) -> ResponseResult<()> {
+    let Ok(card_number) = card_number_str.parse::<i64>() else {
+        bot.answer_callback_query(query.id.clone())
+            .text("Invalid card number")
+            .show_alert(true)
+            .await?;
+        return Ok(());
+    };
+
+    let input = ToggleCardTagInput {
+        account_id: state.account_id(),
+        user_id: user_id.clone(),
+        card_number,
+        tag_id: FizzyId::new(tag_id.to_string()),
+    };
+
+    let output = match state.toggle_card_tag.execute(input).await {
+        Ok(output) => output,
+        Err(e) => {
+            bot.answer_callback_query(query.id.clone())
+                .text(format!("Failed to toggle tag: {}", e))
+                .show_alert(true)
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let verb = if output.attached { "added" } else { "removed" };
+    bot.answer_callback_query(query.id.clone())
+        .text(format!("#{} {}", output.tag.title, verb))
+        .await?;
+
+    let list_input = ListTagsInput {
+        account_id: state.account_id(),
+        card_number: Some(card_number),
+    };
+
+    if let (Some(message), Ok(list)) = (&query.message, state.list_tags.execute(list_input).await) {
+        bot.edit_message_reply_markup(message.chat().id, message.id())
+            .reply_markup(tag_toggle_keyboard(card_number, &list.tags, &list.card_tag_ids))
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// Handle "tag_done:<number>" by replacing the keyboard with the final tags
+pub async fn handle_done(
+    bot: &Bot,
+    query: &CallbackQuery,
+    state: &Arc<BotState>,
+    card_number_str: &str,
+) -> ResponseResult<()> {
+    bot.answer_callback_query(query.id.clone()).await?;
+
+    let Ok(card_number) = card_number_str.parse::<i64>() else {
+        return Ok(());
+    };
+
+    let input = ListTagsInput {
+        account_id: state.account_id(),
+        card_number: Some(card_number),
+    };
+
+    if let (Some(message), Ok(output)) = (&query.message, state.list_tags.execute(input).await)
+        && let Some(card) = output.card
+    {
+        bot.edit_message_text(
+            message.chat().id,
+            message.id(),
+            format!(
+                "{}\n🏷 Tags: {}",
+                CardFormatter::format_card_heading(&card),
+                TagFormatter::format_titles(&card.tag_titles)
+            ),
+        )
+        .parse_mode(ParseMode::Html)
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Send the tag toggle keyboard for a card
+async fn send_toggle_keyboard(
+    bot: &Bot,
+    chat_id: ChatId,
+    state: &Arc<BotState>,
+    number: i64,
+) -> ResponseResult<()> {
+    let input = ListTagsInput {
+        account_id: state.account_id(),
+        card_number: Some(number),
+    };
+
+    match state.list_tags.execute(input).await {
+        Ok(output) if output.tags.is_empty() => {
+            bot.send_message(chat_id, "No tags yet. Add one with /tag <number> <tag>.")
+                .await?;
+        }
+        Ok(output) => {
+            let heading = output
+                .card
+                .as_ref()
+                .map(CardFormatter::format_card_heading)
+                .unwrap_or_default();
+            bot.send_message(chat_id, format!("{}\n🏷 Tap tags to toggle them:", heading))
+                .parse_mode(ParseMode::Html)
+                .reply_markup(tag_toggle_keyboard(number, &output.tags, &output.card_tag_ids))
+                .await?;
+        }
+        Err(e) => {
+            bot.send_message(chat_id, format!("Failed to load tags: {}", e))
+                .await?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,232 @@
+use std::sync::Arc;
+use teloxide::prelude::*;
+use teloxide::types::{InlineKeyboardMarkup, ParseMode};
+
+use crate::application::use_cases::{
+    BoardRef, CloseCardInput, GetCardDetailsInput, GetTriageCardInput, GetTriageCardOutput,
+    MoveCardInput, PostponeCardInput,
+};
+use crate::domain::value_objects::FizzyId;
+use crate::infrastructure::telegram::bot::BotState;
+use crate::infrastructure::telegram::formatters::CardFormatter;
+use crate::infrastructure::telegram::keyboards::triage_keyboard;
+
+/// What to do with the card currently shown in the triage flow
+pub enum TriageAction<'a> {
+    Move(&'a str),
+    NotNow,
+    Close,
+    Skip,
+}
+
+/// Handle /triage [board]: show the first card awaiting a column
+pub async fn handle(
+    bot: Bot,
+    msg: Message,
+    state: Arc<BotState>,
+    name: String,
+) -> ResponseResult<()> {
+    let Some(user_id) = state.user_for_message(&msg) else {
+        bot.send_message(
+            msg.chat.id,
+            "Sorry, you are not authorized to use this bot.",
+        )
+        .await?;
+        return Ok(());
+    };
+
+    let name = name.trim();
+    let board = if name.is_empty() {
+        BoardRef::Id(state.default_board_id())
+    } else {
+        BoardRef::Name(name.to_string())
+    };
+
+    let input = GetTriageCardInput {
+        account_id: state.account_id(),
+        user_id,
+        board,
+        skip: 0,
+    };
+
+    match state.get_triage_card.execute(input).await {
+        Ok(output) => {
+            let (text, keyboard) = render(&output, 0, state.base_url());
+            let request = bot.send_message(msg.chat.id, text).parse_mode(ParseMode::Html);
+            let sent = match keyboard {
+                Some(keyboard) => request.reply_markup(keyboard).await?,
+                None => request.await?,
+            };
+            if let Some(card) = &output.card {
+                state.remember_card_message(&sent, card.number).await;
+            }
+        }
+        Err(e) => {
+            tracing::error!("Error loading triage queue: {:?}", e);
+            bot.send_message(msg.chat.id, format!("Error: {}", e))
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle a triage button: apply the action, then show the next card in the same message
+pub async fn handle_action(
+    bot: &Bot,
+    query: &CallbackQuery,
+    state: &Arc<BotState>,
+    user_id: &FizzyId,
+    card_number_str: &str,
+    action: TriageAction<'_>,
+    skip_str: &str,
+) -> ResponseResult<()> {
+    let (Ok(card_number), Ok(skip)) = (card_number_str.parse::<i64>(), skip_str.parse::<usize>())
+    else {
+        bot.answer_callback_query(query.id.clone())
+            .text("Invalid triage action")
+            .show_alert(true)
+            .await?;
+        return Ok(());
+    };
+
+    let card = match state
+        .get_card_details
+        .execute(GetCardDetailsInput {
+            account_id: state.account_id(),
+            card_number,
+        })
+        .await
+    {
+        Ok(card) => card,
+        Err(e) => {
+            bot.answer_callback_query(query.id.clone())
+                .text(format!("Card not found: {}", e))
+                .show_alert(true)
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let result = match action {
+        TriageAction::Move(column_id) => state
+            .move_card
+            .execute(MoveCardInput {
+                account_id: state.account_id(),
+                user_id: user_id.clone(),
+                card_number,
+                column_id: FizzyId::new(column_id.to_string()),
+            })
+            .await
+            .map(|card| {
+                format!(
+                    "Moved to {}",
+                    card.column_name.as_deref().unwrap_or("column")
+                )
+            }),
+        TriageAction::NotNow => state
+            .postpone_card
+            .execute(PostponeCardInput {
+                account_id: state.account_id(),
+                user_id: user_id.clone(),
+                card_number,
+                postpone: true,
+            })
+            .await
+            .map(|_| "Moved to Not now".to_string()),
+        TriageAction::Close => state
+            .close_card
+            .execute(CloseCardInput {
+                account_id: state.account_id(),
+                user_id: user_id.clone(),
+                card_number,
+            })
+            .await
+            .map(|_| "Closed".to_string()),
+        TriageAction::Skip => Ok("Skipped".to_string()),
+    };
+
+    // A skipped card stays in the queue, so step past it; handled cards leave the queue
+    let next_skip = match result {
+        Ok(text) => {
+            bot.answer_callback_query(query.id.clone())
+                .text(format!("#{}: {}", card_number, text))
+                .await?;
+            if matches!(action, TriageAction::Skip) { skip + 1 } else { skip }
+        }
+        Err(e) => {
+            bot.answer_callback_query(query.id.clone())
+                .text(format!("Failed: {}", e))
+                .show_alert(true)
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let input = GetTriageCardInput {
+        account_id: state.account_id(),
+        user_id: user_id.clone(),
+        board: BoardRef::Id(card.board_id),
+        skip: next_skip,
+    };
+
+    let Some(message) = &query.message else {
+        return Ok(());
+    };
+
+    match state.get_triage_card.execute(input).await {
+        Ok(output) => {
+            let (text, keyboard) = render(&output, next_skip, state.base_url());
+            let request = bot
+                .edit_message_text(message.chat().id, message.id(), text)
+                .parse_mode(ParseMode::Html);
+            let edited = match keyboard {
+                Some(keyboard) => request.reply_markup(keyboard).await?,
+                None => request.await?,
+            };
+            if let Some(card) = &output.card {
+                state.remember_card_message(&edited, card.number).await;
+            }
+        }
+        Err(e) => {
+            tracing::error!("Error loading triage queue: {:?}", e);
+            bot.send_message(message.chat().id, format!("Error: {}", e))
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Render the current triage step, or a summary once the queue is exhausted
+fn render(
+    output: &GetTriageCardOutput,
+    skip: usize,
+    base_url: Option<&str>,
+) -> (String, Option<InlineKeyboardMarkup>) {
+    match &output.card {
+        Some(card) => (
+            format!(
+                "📥 <b>Triage · {}</b> ({} of {})\n\n{}",
+                output.board.name,
+                skip + 1,
+                output.total,
+                CardFormatter::format_card(card, base_url)
+            ),
+            Some(triage_keyboard(card.number, &output.columns, skip)),
+        ),
+        None if output.total == 0 => (
+            format!("✨ Nothing awaiting triage in <b>{}</b>.", output.board.name),
+            None,
+        ),
+        None => (
+            format!(
+                "📥 <b>Triage · {}</b>\n\nEnd of the queue. {} skipped card(s) still awaiting a column.",
+                output.board.name,
+                // Some skipped cards may have been triaged elsewhere meanwhile
+                skip.min(output.total)
+            ),
+            None,
+        ),
+    }
+}
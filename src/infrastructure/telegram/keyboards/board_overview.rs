@@ -0,0 +1,28 @@
+use crate::application::use_cases::{BoardSection, SectionOverview};
+use crate::infrastructure::telegram::formatters::BoardFormatter;
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+
+/// Create inline keyboard for the board overview: a button per collapsed
+/// section that lists its cards, and one listing every open card
+pub fn board_overview_keyboard(
+    sections: &[SectionOverview],
+    callback_data: impl Fn(BoardSection) -> String,
+) -> InlineKeyboardMarkup {
+    let mut buttons: Vec<Vec<InlineKeyboardButton>> = sections
+        .iter()
+        .filter(|section| section.is_collapsed())
+        .map(|section| {
+            vec![InlineKeyboardButton::callback(
+                format!("▸ {} ({})", BoardFormatter::section_label(section), section.count),
+                callback_data(section.section.clone()),
+            )]
+        })
+        .collect();
+
+    buttons.push(vec![InlineKeyboardButton::callback(
+        "📋 All open cards",
+        callback_data(BoardSection::Open),
+    )]);
+
+    InlineKeyboardMarkup::new(buttons)
+}
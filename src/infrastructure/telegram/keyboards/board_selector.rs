@@ -1,13 +1,24 @@
 use crate::domain::entities::Board;
+use crate::domain::value_objects::FizzyId;
 use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
 
-/// Create inline keyboard for board selection
-pub fn board_selector_keyboard(boards: &[Board], callback_prefix: &str) -> InlineKeyboardMarkup {
-    let buttons: Vec<Vec<InlineKeyboardButton>> = boards
+/// Create inline keyboard for board selection, listing the selected board first
+pub fn board_selector_keyboard(
+    boards: &[Board],
+    selected: Option<&FizzyId>,
+    callback_prefix: &str,
+) -> InlineKeyboardMarkup {
+    let (first, rest): (Vec<&Board>, Vec<&Board>) = boards
         .iter()
-        .map(|board| {
+        .partition(|board| Some(&board.id) == selected);
+
+    let buttons: Vec<Vec<InlineKeyboardButton>> = first
+        .iter()
+        .map(|board| (format!("✓ {}", board.name), board))
+        .chain(rest.iter().map(|board| (board.name.clone(), board)))
+        .map(|(label, board)| {
             vec![InlineKeyboardButton::callback(
-                &board.name,
+                label,
                 format!("{}:{}", callback_prefix, board.id),
             )]
         })
@@ -1,17 +1,56 @@
+use crate::domain::entities::{Attachment, Card};
 use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
 
-/// Create inline keyboard with card action buttons
-pub fn card_actions_keyboard(card_number: i64) -> InlineKeyboardMarkup {
-    let buttons = vec![
+/// Most attachments offered as buttons; the newest are kept
+const MAX_ATTACHMENT_BUTTONS: usize = 5;
+
+/// Create inline keyboard with card action buttons.
+/// `expandable` adds a button to show the full description, and each of the
+/// card's most recent attachments gets a button that sends the file.
+pub fn card_actions_keyboard(
+    card: &Card,
+    expandable: bool,
+    attachments: &[Attachment],
+) -> InlineKeyboardMarkup {
+    let card_number = card.number;
+    let golden_button = if card.is_golden {
+        InlineKeyboardButton::callback("☆ Ungild", format!("ungild:{}", card_number))
+    } else {
+        InlineKeyboardButton::callback("⭐ Gild", format!("gild:{}", card_number))
+    };
+
+    let mut buttons = vec![
         vec![
             InlineKeyboardButton::callback("✅ Close", format!("close:{}", card_number)),
             InlineKeyboardButton::callback("💬 Comment", format!("comment:{}", card_number)),
         ],
+        vec![
+            InlineKeyboardButton::callback("📁 Move", format!("move:{}", card_number)),
+            golden_button,
+        ],
         vec![InlineKeyboardButton::callback(
-            "📁 Move",
-            format!("move:{}", card_number),
+            "💬 Show comments",
+            format!("comments:{}", card_number),
         )],
     ];
 
+    let newest = attachments.len().saturating_sub(MAX_ATTACHMENT_BUTTONS);
+    for attachment in &attachments[newest..] {
+        buttons.push(vec![InlineKeyboardButton::callback(
+            format!("📎 {}", attachment.filename),
+            format!("att:{}:{}", card_number, attachment.id),
+        )]);
+    }
+
+    if expandable {
+        buttons.insert(
+            0,
+            vec![InlineKeyboardButton::callback(
+                "📖 Expand description",
+                format!("expand:{}", card_number),
+            )],
+        );
+    }
+
     InlineKeyboardMarkup::new(buttons)
 }
@@ -0,0 +1,67 @@
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+
+use crate::domain::entities::Comment;
+
+/// Create "✏️ Edit" / "🗑 Delete" buttons for each comment the viewer may change,
+/// labelled with the comment's position on the page, followed by
+/// "◀ Older" / "Newer ▶" buttons.
+/// Returns `None` when there is nothing to show.
+pub fn comments_keyboard(
+    card_number: i64,
+    page: usize,
+    has_older: bool,
+    manageable: &[(usize, &Comment)],
+) -> Option<InlineKeyboardMarkup> {
+    let mut rows: Vec<Vec<InlineKeyboardButton>> = manageable
+        .iter()
+        .map(|(index, comment)| {
+            vec![
+                InlineKeyboardButton::callback(
+                    format!("✏️ Edit {}", index),
+                    format!("edit_comment:{}:{}", card_number, comment.id),
+                ),
+                InlineKeyboardButton::callback(
+                    format!("🗑 Delete {}", index),
+                    format!("cmt_del:{}:{}", card_number, comment.id),
+                ),
+            ]
+        })
+        .collect();
+
+    let mut row = Vec::new();
+
+    if has_older {
+        row.push(InlineKeyboardButton::callback(
+            "◀ Older",
+            format!("comments:{}:{}", card_number, page + 1),
+        ));
+    }
+
+    if page > 0 {
+        row.push(InlineKeyboardButton::callback(
+            "Newer ▶",
+            format!("comments:{}:{}", card_number, page - 1),
+        ));
+    }
+
+    if !row.is_empty() {
+        rows.push(row);
+    }
+
+    if rows.is_empty() {
+        None
+    } else {
+        Some(InlineKeyboardMarkup::new(rows))
+    }
+}
+
+/// Create the confirmation keyboard shown before deleting a comment
+pub fn comment_delete_confirm_keyboard(card_number: i64, comment: &Comment) -> InlineKeyboardMarkup {
+    InlineKeyboardMarkup::new(vec![vec![
+        InlineKeyboardButton::callback(
+            "🗑 Yes, delete",
+            format!("cmt_del_yes:{}:{}", card_number, comment.id),
+        ),
+        InlineKeyboardButton::callback("Cancel", format!("comments:{}:0", card_number)),
+    ]])
+}
@@ -0,0 +1,24 @@
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+
+/// Create inline keyboard to choose which card field to edit
+pub fn edit_field_keyboard() -> InlineKeyboardMarkup {
+    let buttons = vec![
+        vec![
+            InlineKeyboardButton::callback("📝 Title", "edit_field:title"),
+            InlineKeyboardButton::callback("📄 Description", "edit_field:description"),
+        ],
+        vec![InlineKeyboardButton::callback("❌ Cancel", "edit_cancel")],
+    ];
+
+    InlineKeyboardMarkup::new(buttons)
+}
+
+/// Create inline keyboard to confirm or discard an edit preview
+pub fn edit_confirm_keyboard() -> InlineKeyboardMarkup {
+    let buttons = vec![vec![
+        InlineKeyboardButton::callback("✅ Save", "edit_save"),
+        InlineKeyboardButton::callback("❌ Cancel", "edit_cancel"),
+    ]];
+
+    InlineKeyboardMarkup::new(buttons)
+}
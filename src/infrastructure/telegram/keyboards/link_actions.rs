@@ -0,0 +1,9 @@
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+
+/// Create keyboard for an admin to approve or deny a link request
+pub fn link_approval_keyboard(code: &str) -> InlineKeyboardMarkup {
+    InlineKeyboardMarkup::new(vec![vec![
+        InlineKeyboardButton::callback("✅ Approve", format!("link_approve:{}", code)),
+        InlineKeyboardButton::callback("❌ Deny", format!("link_deny:{}", code)),
+    ]])
+}
@@ -1,7 +1,23 @@
+mod board_overview;
 mod board_selector;
 mod card_actions;
 mod column_selector;
+mod comment_actions;
+mod edit_actions;
+mod link_actions;
+mod pagination;
+mod tag_toggle;
+mod triage_actions;
+mod user_picker;
 
+pub use board_overview::board_overview_keyboard;
 pub use board_selector::board_selector_keyboard;
 pub use card_actions::card_actions_keyboard;
 pub use column_selector::column_selector_keyboard;
+pub use comment_actions::{comment_delete_confirm_keyboard, comments_keyboard};
+pub use edit_actions::{edit_confirm_keyboard, edit_field_keyboard};
+pub use link_actions::link_approval_keyboard;
+pub use pagination::{pagination_keyboard, prev_next_keyboard};
+pub use tag_toggle::tag_toggle_keyboard;
+pub use triage_actions::triage_keyboard;
+pub use user_picker::user_picker_keyboard;
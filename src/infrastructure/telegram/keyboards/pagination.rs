@@ -0,0 +1,38 @@
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+
+/// Create inline keyboard with "◀ Prev" / "Next ▶" buttons.
+/// Returns `None` when there is only a single page.
+pub fn pagination_keyboard(
+    page: usize,
+    has_next: bool,
+    callback_data: impl Fn(usize) -> String,
+) -> Option<InlineKeyboardMarkup> {
+    prev_next_keyboard(
+        (page > 0).then(|| callback_data(page - 1)),
+        has_next.then(|| callback_data(page + 1)),
+    )
+}
+
+/// Create inline keyboard with "◀ Prev" / "Next ▶" buttons carrying the given
+/// callback data, for lists paged by cursor rather than page number.
+/// Returns `None` when there is neither.
+pub fn prev_next_keyboard(
+    prev: Option<String>,
+    next: Option<String>,
+) -> Option<InlineKeyboardMarkup> {
+    let mut row = Vec::new();
+
+    if let Some(data) = prev {
+        row.push(InlineKeyboardButton::callback("◀ Prev", data));
+    }
+
+    if let Some(data) = next {
+        row.push(InlineKeyboardButton::callback("Next ▶", data));
+    }
+
+    if row.is_empty() {
+        None
+    } else {
+        Some(InlineKeyboardMarkup::new(vec![row]))
+    }
+}
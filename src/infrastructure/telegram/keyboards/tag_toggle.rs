@@ -0,0 +1,42 @@
+use crate::domain::entities::Tag;
+use crate::domain::value_objects::FizzyId;
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+
+/// Most tags offered on one keyboard
+const MAX_TAGS: usize = 30;
+
+/// Create multi-select keyboard for toggling tags on a card
+pub fn tag_toggle_keyboard(
+    card_number: i64,
+    tags: &[Tag],
+    selected: &[FizzyId],
+) -> InlineKeyboardMarkup {
+    let mut buttons: Vec<Vec<InlineKeyboardButton>> = tags
+        .iter()
+        .take(MAX_TAGS)
+        .collect::<Vec<_>>()
+        .chunks(2)
+        .map(|row| {
+            row.iter()
+                .map(|tag| {
+                    let label = if selected.contains(&tag.id) {
+                        format!("✅ #{}", tag.title)
+                    } else {
+                        format!("#{}", tag.title)
+                    };
+                    InlineKeyboardButton::callback(
+                        label,
+                        format!("tag_toggle:{}:{}", card_number, tag.id),
+                    )
+                })
+                .collect()
+        })
+        .collect();
+
+    buttons.push(vec![InlineKeyboardButton::callback(
+        "Done",
+        format!("tag_done:{}", card_number),
+    )]);
+
+    InlineKeyboardMarkup::new(buttons)
+}
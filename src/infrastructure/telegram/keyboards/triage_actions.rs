@@ -0,0 +1,28 @@
+use crate::domain::entities::Column;
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+
+/// Create keyboard for triaging a card: one button per column plus Not now / Close / Skip.
+/// `skip` is carried along so the next card can be found after this one is handled.
+pub fn triage_keyboard(card_number: i64, columns: &[Column], skip: usize) -> InlineKeyboardMarkup {
+    let mut buttons: Vec<Vec<InlineKeyboardButton>> = columns
+        .chunks(2)
+        .map(|row| {
+            row.iter()
+                .map(|column| {
+                    InlineKeyboardButton::callback(
+                        column.formatted_name(),
+                        format!("tri_col:{}:{}:{}", card_number, column.id, skip),
+                    )
+                })
+                .collect()
+        })
+        .collect();
+
+    buttons.push(vec![
+        InlineKeyboardButton::callback("⏸️ Not now", format!("tri_notnow:{}:{}", card_number, skip)),
+        InlineKeyboardButton::callback("✅ Close", format!("tri_close:{}:{}", card_number, skip)),
+        InlineKeyboardButton::callback("⏭️ Skip", format!("tri_skip:{}:{}", card_number, skip)),
+    ]);
+
+    InlineKeyboardMarkup::new(buttons)
+}
@@ -0,0 +1,21 @@
+use crate::domain::entities::User;
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+
+/// Create inline keyboard for picking a user, two per row
+pub fn user_picker_keyboard(users: &[User], callback_prefix: &str) -> InlineKeyboardMarkup {
+    let buttons: Vec<Vec<InlineKeyboardButton>> = users
+        .chunks(2)
+        .map(|row| {
+            row.iter()
+                .map(|user| {
+                    InlineKeyboardButton::callback(
+                        user.name.clone(),
+                        format!("{}:{}", callback_prefix, user.id),
+                    )
+                })
+                .collect()
+        })
+        .collect();
+
+    InlineKeyboardMarkup::new(buttons)
+}
@@ -2,3 +2,5 @@ pub mod bot;
 pub mod handlers;
 pub mod formatters;
 pub mod keyboards;
+pub mod rich_text;
+pub mod user_directory;
@@ -0,0 +1,551 @@
+//! Conversion between Telegram formatted text and the HTML Fizzy stores in
+//! `action_text_rich_texts.body`.
+//!
+//! Telegram sends formatting as entities: spans over the text measured in
+//! UTF-16 code units. Fizzy's editor (Trix) saves a `<div>` with `<br>`
+//! line breaks and a small set of inline tags, so that is what we produce.
+//! Going the other way, Telegram only accepts a handful of tags, so stored
+//! HTML is flattened to text and re-marked with those.
+
+use html2text::render::text_renderer::{RichAnnotation, TaggedLine, TextDecorator};
+use teloxide::types::{Message, MessageEntity, MessageEntityKind};
+use crate::infrastructure::text::{escape_attribute, escape_html};
+
+/// Line width used when flattening HTML; wide enough that html2text never wraps
+const RENDER_WIDTH: usize = 10_000;
+
+/// Convert text and its entities into ActionText HTML.
+/// Returns an empty string for blank text.
+pub fn to_action_text(text: &str, entities: &[MessageEntity]) -> String {
+    if text.trim().is_empty() {
+        return String::new();
+    }
+
+    // Pair every character with its UTF-16 offset, the unit entities are measured in
+    let mut chars = Vec::with_capacity(text.len());
+    let mut offset = 0;
+    for c in text.chars() {
+        chars.push((offset, c));
+        offset += c.len_utf16();
+    }
+    let text_end = offset;
+
+    let mut spans: Vec<Span> = entities
+        .iter()
+        .filter(|e| e.length > 0 && e.offset < text_end)
+        .filter_map(|e| {
+            let end = (e.offset + e.length).min(text_end);
+            let content: String = chars
+                .iter()
+                .filter(|(at, _)| *at >= e.offset && *at < end)
+                .map(|(_, c)| c)
+                .collect();
+            Tag::for_entity(&e.kind, &content).map(|tag| Span { start: e.offset, end, tag })
+        })
+        .collect();
+
+    // Outer spans first so they are opened before the spans nested inside them
+    spans.sort_by(|a, b| a.start.cmp(&b.start).then(b.end.cmp(&a.end)));
+
+    let mut html = String::from("<div>");
+    let mut open: Vec<&Span> = Vec::new();
+    let mut next = 0;
+
+    for &(at, c) in chars.iter().chain(std::iter::once(&(text_end, '\0'))) {
+        close_ended(&mut html, &mut open, at);
+
+        while next < spans.len() && spans[next].start <= at {
+            html.push_str(&spans[next].tag.open());
+            open.push(&spans[next]);
+            next += 1;
+        }
+
+        if at == text_end {
+            break;
+        }
+
+        let in_pre = open.iter().any(|span| span.tag == Tag::Pre);
+        match c {
+            '\n' if in_pre => html.push('\n'),
+            '\n' => html.push_str("<br>"),
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            c => html.push(c),
+        }
+    }
+
+    html.push_str("</div>");
+    html
+}
+
+/// Convert the end of a message's text (or caption), starting where `tail` does.
+///
+/// Commands such as `/comment 12 text` only store the part after their
+/// arguments, while the entities are measured from the start of the message.
+pub fn message_tail_to_action_text(msg: &Message, tail: &str) -> String {
+    let tail = tail.trim();
+    let (full, entities) = match (msg.text(), msg.caption()) {
+        (Some(text), _) => (text, msg.entities().unwrap_or_default()),
+        (None, Some(caption)) => (caption, msg.caption_entities().unwrap_or_default()),
+        (None, None) => return to_action_text(tail, &[]),
+    };
+
+    let Some(start) = full.rfind(tail) else {
+        return to_action_text(tail, &[]);
+    };
+
+    let shift: usize = full[..start].chars().map(char::len_utf16).sum();
+    let length: usize = tail.chars().map(char::len_utf16).sum();
+
+    // Re-measure the entities from the start of the tail, dropping those outside it
+    let entities: Vec<MessageEntity> = entities
+        .iter()
+        .filter(|e| e.offset + e.length > shift && e.offset < shift + length)
+        .map(|e| {
+            let offset = e.offset.max(shift);
+            let end = (e.offset + e.length).min(shift + length);
+            MessageEntity {
+                kind: e.kind.clone(),
+                offset: offset - shift,
+                length: end - offset,
+            }
+        })
+        .collect();
+
+    to_action_text(tail, &entities)
+}
+
+/// Convert stored ActionText HTML into HTML Telegram accepts, keeping at most
+/// `max_chars` characters of text. Returns the HTML and whether it was cut short.
+pub fn to_telegram_html(html: &str, max_chars: usize) -> (String, bool) {
+    let lines = html2text::config::with_decorator(TelegramDecorator)
+        .lines_from_read(html.as_bytes(), RENDER_WIDTH)
+        .unwrap_or_default();
+
+    // Flatten into runs of identically formatted text
+    let mut runs: Vec<(String, Vec<RichAnnotation>)> = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        if i > 0 {
+            push_run(&mut runs, "\n", &[]);
+        }
+        for tagged in line.tagged_strings() {
+            // html2text strikes text through with combining characters; <s> replaces those
+            push_run(&mut runs, &tagged.s.replace('\u{336}', ""), &tagged.tag);
+        }
+    }
+
+    // Drop the blank lines html2text leaves around blocks
+    while runs.last().is_some_and(|(text, _)| text.trim().is_empty()) {
+        runs.pop();
+    }
+    if let Some((text, _)) = runs.last_mut() {
+        let trimmed = text.trim_end().len();
+        text.truncate(trimmed);
+    }
+
+    let mut output = String::new();
+    let mut remaining = max_chars;
+    let mut truncated = false;
+
+    for (text, annotations) in &runs {
+        let count = text.chars().count();
+        let text = if count > remaining {
+            truncated = true;
+            text.chars().take(remaining).collect::<String>()
+        } else {
+            text.clone()
+        };
+        remaining -= text.chars().count();
+
+        let mut piece = escape_html(&text);
+        for annotation in annotations {
+            piece = match annotation {
+                RichAnnotation::Strong => format!("<b>{}</b>", piece),
+                RichAnnotation::Emphasis => format!("<i>{}</i>", piece),
+                RichAnnotation::Strikeout => format!("<s>{}</s>", piece),
+                RichAnnotation::Code | RichAnnotation::Preformat(_) => format!("<code>{}</code>", piece),
+                RichAnnotation::Link(url) => {
+                    format!("<a href=\"{}\">{}</a>", escape_attribute(url), piece)
+                }
+                _ => piece,
+            };
+        }
+        output.push_str(&piece);
+
+        if truncated {
+            break;
+        }
+    }
+
+    if truncated {
+        output = output.trim_end().to_string();
+        output.push('…');
+    }
+
+    (output, truncated)
+}
+
+/// Annotates text like html2text's `RichDecorator`, but without the `*` and
+/// `` ` `` markers it puts around bold text and code
+#[derive(Clone)]
+struct TelegramDecorator;
+
+impl TextDecorator for TelegramDecorator {
+    type Annotation = RichAnnotation;
+
+    fn decorate_link_start(&mut self, url: &str) -> (String, RichAnnotation) {
+        (String::new(), RichAnnotation::Link(url.to_string()))
+    }
+
+    fn decorate_link_end(&mut self) -> String {
+        String::new()
+    }
+
+    fn decorate_em_start(&self) -> (String, RichAnnotation) {
+        (String::new(), RichAnnotation::Emphasis)
+    }
+
+    fn decorate_em_end(&self) -> String {
+        String::new()
+    }
+
+    fn decorate_strong_start(&self) -> (String, RichAnnotation) {
+        (String::new(), RichAnnotation::Strong)
+    }
+
+    fn decorate_strong_end(&self) -> String {
+        String::new()
+    }
+
+    fn decorate_strikeout_start(&self) -> (String, RichAnnotation) {
+        (String::new(), RichAnnotation::Strikeout)
+    }
+
+    fn decorate_strikeout_end(&self) -> String {
+        String::new()
+    }
+
+    fn decorate_code_start(&self) -> (String, RichAnnotation) {
+        (String::new(), RichAnnotation::Code)
+    }
+
+    fn decorate_code_end(&self) -> String {
+        String::new()
+    }
+
+    fn decorate_preformat_first(&self) -> RichAnnotation {
+        RichAnnotation::Preformat(false)
+    }
+
+    fn decorate_preformat_cont(&self) -> RichAnnotation {
+        RichAnnotation::Preformat(true)
+    }
+
+    fn decorate_image(&mut self, src: &str, title: &str) -> (String, RichAnnotation) {
+        (title.to_string(), RichAnnotation::Image(src.to_string()))
+    }
+
+    fn header_prefix(&self, level: usize) -> String {
+        "#".repeat(level) + " "
+    }
+
+    fn quote_prefix(&self) -> String {
+        "> ".to_string()
+    }
+
+    fn unordered_item_prefix(&self) -> String {
+        "• ".to_string()
+    }
+
+    fn ordered_item_prefix(&self, i: i64) -> String {
+        format!("{}. ", i)
+    }
+
+    fn make_subblock_decorator(&self) -> Self {
+        TelegramDecorator
+    }
+
+    fn finalise(&mut self, _links: Vec<String>) -> Vec<TaggedLine<RichAnnotation>> {
+        Vec::new()
+    }
+}
+
+/// Append text to the last run if it has the same formatting, otherwise start a new run
+fn push_run(runs: &mut Vec<(String, Vec<RichAnnotation>)>, text: &str, annotations: &[RichAnnotation]) {
+    match runs.last_mut() {
+        Some((last, tags)) if tags.as_slice() == annotations => last.push_str(text),
+        _ => runs.push((text.to_string(), annotations.to_vec())),
+    }
+}
+
+struct Span {
+    start: usize,
+    end: usize,
+    tag: Tag,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Tag {
+    Strong,
+    Em,
+    Del,
+    Code,
+    Pre,
+    Blockquote,
+    Link(String),
+}
+
+impl Tag {
+    /// Map an entity onto a tag Trix understands. Formatting Fizzy has no
+    /// equivalent for (underline, spoilers, mentions...) is kept as plain text.
+    fn for_entity(kind: &MessageEntityKind, content: &str) -> Option<Self> {
+        match kind {
+            MessageEntityKind::Bold => Some(Tag::Strong),
+            MessageEntityKind::Italic => Some(Tag::Em),
+            MessageEntityKind::Strikethrough => Some(Tag::Del),
+            MessageEntityKind::Code => Some(Tag::Code),
+            MessageEntityKind::Pre { .. } => Some(Tag::Pre),
+            MessageEntityKind::Blockquote | MessageEntityKind::ExpandableBlockquote => {
+                Some(Tag::Blockquote)
+            }
+            MessageEntityKind::TextLink { url } => Some(Tag::Link(url.to_string())),
+            MessageEntityKind::Url if content.contains("://") => Some(Tag::Link(content.to_string())),
+            MessageEntityKind::Url => Some(Tag::Link(format!("https://{}", content))),
+            MessageEntityKind::Email => Some(Tag::Link(format!("mailto:{}", content))),
+            _ => None,
+        }
+    }
+
+    fn open(&self) -> String {
+        match self {
+            Tag::Strong => "<strong>".to_string(),
+            Tag::Em => "<em>".to_string(),
+            Tag::Del => "<del>".to_string(),
+            Tag::Code => "<code>".to_string(),
+            Tag::Pre => "<pre>".to_string(),
+            Tag::Blockquote => "<blockquote>".to_string(),
+            Tag::Link(href) => format!("<a href=\"{}\">", escape_attribute(href)),
+        }
+    }
+
+    fn close(&self) -> &'static str {
+        match self {
+            Tag::Strong => "</strong>",
+            Tag::Em => "</em>",
+            Tag::Del => "</del>",
+            Tag::Code => "</code>",
+            Tag::Pre => "</pre>",
+            Tag::Blockquote => "</blockquote>",
+            Tag::Link(_) => "</a>",
+        }
+    }
+}
+
+/// Close every open span that ends at `at`. Telegram lets spans overlap without
+/// nesting, so spans opened after one that ends are closed and reopened.
+fn close_ended(html: &mut String, open: &mut Vec<&Span>, at: usize) {
+    let Some(first_ended) = open.iter().position(|span| span.end <= at) else {
+        return;
+    };
+
+    let closed = open.split_off(first_ended);
+    for span in closed.iter().rev() {
+        html.push_str(span.tag.close());
+    }
+
+    for span in closed {
+        if span.end > at {
+            html.push_str(&span.tag.open());
+            open.push(span);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use html2text::render::text_renderer::TrivialDecorator;
+
+    fn entity(kind: MessageEntityKind, offset: usize, length: usize) -> MessageEntity {
+        MessageEntity { kind, offset, length }
+    }
+
+    fn text_link(url: &str, offset: usize, length: usize) -> MessageEntity {
+        serde_json::from_value(serde_json::json!({
+            "type": "text_link",
+            "url": url,
+            "offset": offset,
+            "length": length,
+        }))
+        .unwrap()
+    }
+
+    /// The text html2text reads back out of the HTML. It draws strikethrough
+    /// with combining characters, which are dropped here.
+    fn rendered(html: &str) -> String {
+        html2text::from_read_with_decorator(html.as_bytes(), 1000, TrivialDecorator::new())
+            .replace('\u{336}', "")
+            .trim_end()
+            .to_string()
+    }
+
+    #[test]
+    fn test_plain_text() {
+        assert_eq!(to_action_text("Fix the login", &[]), "<div>Fix the login</div>");
+        assert_eq!(to_action_text("a < b & c", &[]), "<div>a &lt; b &amp; c</div>");
+        assert_eq!(to_action_text("line one\nline two", &[]), "<div>line one<br>line two</div>");
+        assert_eq!(to_action_text("  ", &[]), "");
+    }
+
+    #[test]
+    fn test_entities() {
+        let entities = [
+            entity(MessageEntityKind::Bold, 0, 4),
+            entity(MessageEntityKind::Italic, 5, 6),
+            entity(MessageEntityKind::Code, 12, 4),
+        ];
+        assert_eq!(
+            to_action_text("Bold italic code", &entities),
+            "<div><strong>Bold</strong> <em>italic</em> <code>code</code></div>"
+        );
+
+        let entities = [
+            text_link("https://example.com/a?b=1&c=2", 4, 4),
+            entity(MessageEntityKind::Url, 13, 11),
+        ];
+        assert_eq!(
+            to_action_text("See docs and example.com", &entities),
+            "<div>See <a href=\"https://example.com/a?b=1&amp;c=2\">docs</a> and \
+             <a href=\"https://example.com\">example.com</a></div>"
+        );
+    }
+
+    #[test]
+    fn test_nested_and_overlapping_entities() {
+        // Bold around italic
+        let entities = [
+            entity(MessageEntityKind::Italic, 5, 3),
+            entity(MessageEntityKind::Bold, 0, 8),
+        ];
+        assert_eq!(
+            to_action_text("very bad", &entities),
+            "<div><strong>very <em>bad</em></strong></div>"
+        );
+
+        // Bold "ab", italic "bc": the italic is split to keep the tags nested
+        let entities = [
+            entity(MessageEntityKind::Bold, 0, 2),
+            entity(MessageEntityKind::Italic, 1, 2),
+        ];
+        assert_eq!(
+            to_action_text("abc", &entities),
+            "<div><strong>a<em>b</em></strong><em>c</em></div>"
+        );
+    }
+
+    #[test]
+    fn test_utf16_offsets() {
+        // The emoji takes two UTF-16 code units
+        let entities = [entity(MessageEntityKind::Bold, 3, 4)];
+        assert_eq!(to_action_text("🔥 hot!", &entities), "<div>🔥 <strong>hot!</strong></div>");
+    }
+
+    #[test]
+    fn test_pre_keeps_line_breaks() {
+        let text = "Run:\nfn main() {}\nok";
+        let entities = [entity(MessageEntityKind::Pre { language: None }, 5, 15)];
+        assert_eq!(
+            to_action_text(text, &entities),
+            "<div>Run:<br><pre>fn main() {}\nok</pre></div>"
+        );
+    }
+
+    #[test]
+    fn test_message_tail() {
+        let msg: Message = serde_json::from_value(serde_json::json!({
+            "message_id": 1,
+            "date": 0,
+            "chat": { "id": 1, "type": "private", "first_name": "A" },
+            "text": "/comment 12 🔥 Ship it today",
+            "entities": [
+                { "type": "bot_command", "offset": 0, "length": 8 },
+                { "type": "bold", "offset": 9, "length": 7 },
+                { "type": "italic", "offset": 20, "length": 5 }
+            ]
+        }))
+        .unwrap();
+
+        // The bold span starts inside the command arguments and is cut at the tail
+        assert_eq!(
+            message_tail_to_action_text(&msg, "🔥 Ship it today"),
+            "<div><strong>🔥 S</strong>hip <em>it to</em>day</div>"
+        );
+    }
+
+    #[test]
+    fn test_to_telegram_html() {
+        let html = "<div><strong>Steps</strong><br>1. open <a href=\"https://fizzy.do?a=1&amp;b=2\">the app</a><br>\
+                    2. type <code>&lt;x&gt;</code></div>";
+        assert_eq!(
+            to_telegram_html(html, 500),
+            (
+                "<b>Steps</b>\n1. open <a href=\"https://fizzy.do?a=1&amp;b=2\">the app</a>\n2. type <code>&lt;x&gt;</code>"
+                    .to_string(),
+                false
+            )
+        );
+
+        // Tags stay balanced when the text is cut inside them
+        assert_eq!(
+            to_telegram_html("<div>Hello <em>wonderful world</em></div>", 10),
+            ("Hello <i>wond</i>…".to_string(), true)
+        );
+    }
+
+    #[test]
+    fn test_telegram_html_round_trip() {
+        // What we store comes back with the same formatting
+        let entities = [
+            entity(MessageEntityKind::Bold, 0, 4),
+            entity(MessageEntityKind::Strikethrough, 5, 3),
+        ];
+        let stored = to_action_text("Bold old text\nline two", &entities);
+        assert_eq!(
+            to_telegram_html(&stored, 500).0,
+            "<b>Bold</b> <s>old</s> text\nline two"
+        );
+    }
+
+    #[test]
+    fn test_round_trip_through_html2text() {
+        let cases: Vec<(&str, Vec<MessageEntity>)> = vec![
+            ("Just some text", vec![]),
+            ("Steps:\n1. open the app\n2. tap login", vec![entity(MessageEntityKind::Bold, 0, 6)]),
+            (
+                "Breaks when <input> is empty & saved",
+                vec![entity(MessageEntityKind::Code, 12, 7)],
+            ),
+            (
+                "Compare with fizzy and 🚀 launch",
+                vec![
+                    text_link("https://fizzy.do", 13, 5),
+                    entity(MessageEntityKind::Italic, 23, 9),
+                ],
+            ),
+            (
+                "Old ~value~ new value",
+                vec![
+                    entity(MessageEntityKind::Strikethrough, 4, 7),
+                    entity(MessageEntityKind::Underline, 12, 3),
+                    entity(MessageEntityKind::Spoiler, 16, 5),
+                ],
+            ),
+        ];
+
+        for (text, entities) in cases {
+            assert_eq!(rendered(&to_action_text(text, &entities)), text);
+        }
+    }
+}
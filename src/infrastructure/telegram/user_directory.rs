@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use crate::domain::errors::DomainError;
+use crate::domain::ports::UserLinkRepository;
+use crate::domain::value_objects::FizzyId;
+use crate::infrastructure::config::AppConfig;
+
+/// Resolves which Fizzy user a Telegram account acts as.
+///
+/// Mappings come from two places: the admin-configured `TELEGRAM_USER_MAP`
+/// and links persisted in the bot database. Configured mappings win, so an
+/// admin can always override a self-service link.
+pub struct UserDirectory {
+    configured: HashMap<i64, FizzyId>,
+    linked: RwLock<HashMap<i64, FizzyId>>,
+    link_repository: Arc<dyn UserLinkRepository>,
+}
+
+impl UserDirectory {
+    /// Build the directory from configuration and persisted links
+    pub async fn load(
+        config: &AppConfig,
+        link_repository: Arc<dyn UserLinkRepository>,
+    ) -> Result<Self, DomainError> {
+        let configured = config
+            .telegram
+            .user_map
+            .iter()
+            .map(|(telegram_user_id, user_id)| (*telegram_user_id, FizzyId::new(user_id.clone())))
+            .collect();
+
+        let linked = link_repository
+            .list()
+            .await?
+            .into_iter()
+            .map(|link| (link.telegram_user_id, link.user_id))
+            .collect();
+
+        Ok(Self {
+            configured,
+            linked: RwLock::new(linked),
+            link_repository,
+        })
+    }
+
+    /// Get the Fizzy user a Telegram user acts as, if any
+    pub fn resolve(&self, telegram_user_id: i64) -> Option<FizzyId> {
+        if let Some(user_id) = self.configured.get(&telegram_user_id) {
+            return Some(user_id.clone());
+        }
+
+        self.linked
+            .read()
+            .ok()?
+            .get(&telegram_user_id)
+            .cloned()
+    }
+
+    /// Get every Telegram user acting as the given Fizzy user
+    pub fn telegram_user_ids(&self, user_id: &FizzyId) -> Vec<i64> {
+        self.mappings()
+            .into_iter()
+            .filter(|(_, mapped)| mapped == user_id)
+            .map(|(telegram_user_id, _)| telegram_user_id)
+            .collect()
+    }
+
+    /// Get all effective (Telegram user, Fizzy user) mappings
+    pub fn mappings(&self) -> Vec<(i64, FizzyId)> {
+        let mut mappings: HashMap<i64, FizzyId> = self
+            .linked
+            .read()
+            .map(|linked| linked.clone())
+            .unwrap_or_default();
+        mappings.extend(self.configured.clone());
+        mappings.into_iter().collect()
+    }
+
+    /// Persist a self-service link; takes effect immediately
+    pub async fn link(&self, telegram_user_id: i64, user_id: &FizzyId) -> Result<(), DomainError> {
+        self.link_repository.save(telegram_user_id, user_id).await?;
+
+        if let Ok(mut linked) = self.linked.write() {
+            linked.insert(telegram_user_id, user_id.clone());
+        }
+
+        Ok(())
+    }
+
+    /// Remove a self-service link; configured mappings are unaffected
+    pub async fn unlink(&self, telegram_user_id: i64) -> Result<(), DomainError> {
+        self.link_repository.delete(telegram_user_id).await?;
+
+        if let Ok(mut linked) = self.linked.write() {
+            linked.remove(&telegram_user_id);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+//! Text helpers shared by the formatters, handlers and rich text conversion.
+
+/// Escape HTML special characters for Telegram and ActionText
+pub fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Escape a value for use inside a double-quoted HTML attribute
+pub fn escape_attribute(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Shorten text to at most `max` characters, adding an ellipsis
+pub fn truncate(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+    let mut truncated: String = text.chars().take(max).collect();
+    truncated.push('…');
+    truncated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_counts_characters() {
+        assert_eq!(truncate("Zürich", 6), "Zürich");
+        assert_eq!(truncate("Zürich", 3), "Zür…");
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape_html("<b>\"R&D\"</b>"), "&lt;b&gt;\"R&amp;D\"&lt;/b&gt;");
+        assert_eq!(escape_attribute("a\"b<c"), "a&quot;b&lt;c");
+    }
+}
@@ -1,14 +1,25 @@
 use std::sync::Arc;
+use teloxide::dispatching::dialogue::InMemStorage;
 use teloxide::prelude::*;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 use sparkling::infrastructure::config::AppConfig;
+use sparkling::domain::ports::UserRepository;
+use sparkling::domain::value_objects::FizzyId;
 use sparkling::infrastructure::persistence::{
-    create_pool, SqliteBoardRepository, SqliteCardRepository, SqliteCommentRepository,
-    SqliteEventRepository,
+    create_pool, create_state_pool, GlobalIdSigner, SqliteAssignmentRepository,
+    SqliteAttachmentRepository, SqliteBoardRepository, SqliteCardMessageRepository,
+    SqliteCardRepository, SqliteCommentRepository,
+    SqliteDigestScheduleRepository, SqliteEventRepository, SqliteLinkRequestRepository,
+    SqliteReminderRepository, SqliteSearchRepository, SqliteSubscriptionRepository,
+    SqliteTagRepository, SqliteUserLinkRepository, SqliteUserRepository,
+    SqliteUserSettingsRepository,
 };
-use sparkling::infrastructure::telegram::bot::{create_bot, BotState, Command};
+use sparkling::infrastructure::scheduler::Scheduler;
+use sparkling::infrastructure::telegram::bot::{create_bot, BotState, Command, Repositories};
 use sparkling::infrastructure::telegram::handlers;
+use sparkling::infrastructure::telegram::handlers::edit::{EditDialogue, EditState};
+use sparkling::infrastructure::telegram::user_directory::UserDirectory;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -39,36 +50,124 @@ async fn main() -> anyhow::Result<()> {
     let board_repository = Arc::new(SqliteBoardRepository::new(pool.clone()));
     let comment_repository = Arc::new(SqliteCommentRepository::new(pool.clone()));
     let event_repository = Arc::new(SqliteEventRepository::new(pool.clone()));
+    let search_repository = Arc::new(SqliteSearchRepository::new(pool.clone()));
+    let user_repository = Arc::new(SqliteUserRepository::new(pool.clone()));
+    let assignment_repository = Arc::new(SqliteAssignmentRepository::new(pool.clone()));
+    let tag_repository = Arc::new(SqliteTagRepository::new(pool.clone()));
+    if config.fizzy.secret_key_base.is_none() {
+        tracing::warn!("FIZZY_SECRET_KEY_BASE not set: attached files are only mentioned by name in the web app");
+    }
+    let attachment_repository = Arc::new(SqliteAttachmentRepository::new(
+        pool.clone(),
+        config.database.storage_path.clone(),
+        config.database.storage_service.clone(),
+        config.fizzy.secret_key_base.as_deref().map(GlobalIdSigner::new),
+    ));
+
+    // Create the bot's own database and load Telegram -> Fizzy user mappings
+    let state_pool = create_state_pool(&config).await?;
+    let user_link_repository = Arc::new(SqliteUserLinkRepository::new(state_pool.clone()));
+    let link_request_repository = Arc::new(SqliteLinkRequestRepository::new(state_pool.clone()));
+    let user_settings_repository = Arc::new(SqliteUserSettingsRepository::new(state_pool.clone()));
+    let reminder_repository = Arc::new(SqliteReminderRepository::new(state_pool.clone()));
+    let subscription_repository = Arc::new(SqliteSubscriptionRepository::new(state_pool.clone()));
+    let digest_schedule_repository = Arc::new(SqliteDigestScheduleRepository::new(state_pool.clone()));
+    let card_message_repository = Arc::new(SqliteCardMessageRepository::new(state_pool.clone()));
+    let users = Arc::new(UserDirectory::load(&config, user_link_repository).await?);
+
+    let account_id = FizzyId::new(config.fizzy.account_id.clone());
+    for (telegram_user_id, user_id) in users.mappings() {
+        match user_repository.find_by_id(&account_id, &user_id).await {
+            Ok(Some(user)) if user.is_active() => {
+                tracing::info!("Telegram user {} acts as {}", telegram_user_id, user.name)
+            }
+            Ok(Some(user)) => {
+                tracing::warn!("Telegram user {} is mapped to inactive user {}", telegram_user_id, user.name)
+            }
+            Ok(None) => {
+                tracing::warn!("Telegram user {} is mapped to unknown user {}", telegram_user_id, user_id)
+            }
+            Err(e) => tracing::warn!("Failed to verify user mapping: {}", e),
+        }
+    }
 
     // Create bot state with use cases
     let state = Arc::new(BotState::new(
         config.clone(),
-        card_repository,
-        board_repository,
-        comment_repository,
-        event_repository,
+        users,
+        Repositories {
+            card: card_repository,
+            board: board_repository,
+            comment: comment_repository,
+            event: event_repository,
+            search: search_repository,
+            user: user_repository,
+            assignment: assignment_repository,
+            tag: tag_repository,
+            attachment: attachment_repository,
+            user_settings: user_settings_repository,
+            link_request: link_request_repository,
+            subscription: subscription_repository,
+            digest_schedule: digest_schedule_repository,
+            card_messages: card_message_repository,
+        },
     ));
 
     // Create bot
     let bot = create_bot(&config);
     tracing::info!("Bot initialized");
 
-    // Build handler with both commands and callbacks
+    // Start background jobs (due-date reminders, subscription notifications, digests)
+    Scheduler::new(bot.clone(), state.clone(), reminder_repository).spawn();
+    tracing::info!("Scheduler started");
+
+    // Build handler with commands, dialogue replies and callbacks
     let command_handler = Update::filter_message()
         .filter_command::<Command>()
+        .enter_dialogue::<Message, InMemStorage<EditState>, EditState>()
         .endpoint(handle_command);
 
+    // Messages from anyone but the user who started an edit fall through
+    let edit_value_handler = Update::filter_message()
+        .enter_dialogue::<Message, InMemStorage<EditState>, EditState>()
+        .filter(|msg: Message, edit_state: EditState| edit_state.awaits_message_from(&msg))
+        .endpoint(handlers::edit::receive_value);
+
+    // Photos and documents are attached to the card they name or reply to
+    let attachment_handler = Update::filter_message()
+        .filter(|msg: Message| msg.photo().is_some() || msg.document().is_some())
+        .endpoint(handlers::attachment::handle);
+
+    // Replies to the bot's card messages act on that card
+    let reply_handler = Update::filter_message()
+        .filter(|msg: Message| msg.reply_to_message().is_some())
+        .endpoint(handlers::reply::handle);
+
     let callback_handler = Update::filter_callback_query()
-        .endpoint(handlers::callbacks::handle_callback);
+        .branch(
+            dptree::filter(|query: CallbackQuery| {
+                query.data.as_deref().is_some_and(|data| data.starts_with("edit_"))
+            })
+            .enter_dialogue::<CallbackQuery, InMemStorage<EditState>, EditState>()
+            .endpoint(handlers::edit::handle_callback),
+        )
+        .branch(dptree::endpoint(handlers::callbacks::handle_callback));
+
+    // "@bot <query>" from any chat offers cards to share there
+    let inline_handler = Update::filter_inline_query().endpoint(handlers::inline::handle);
 
     let handler = dptree::entry()
         .branch(command_handler)
-        .branch(callback_handler);
+        .branch(edit_value_handler)
+        .branch(attachment_handler)
+        .branch(reply_handler)
+        .branch(callback_handler)
+        .branch(inline_handler);
 
     // Start bot
     tracing::info!("Bot is running! Press Ctrl+C to stop.");
     Dispatcher::builder(bot, handler)
-        .dependencies(dptree::deps![state])
+        .dependencies(dptree::deps![state, InMemStorage::<EditState>::new()])
         .enable_ctrlc_handler()
         .build()
         .dispatch()
@@ -82,21 +181,23 @@ async fn handle_command(
     msg: Message,
     cmd: Command,
     state: Arc<BotState>,
+    dialogue: EditDialogue,
 ) -> ResponseResult<()> {
     let user_id = msg.from.as_ref().map(|u| u.id.0 as i64).unwrap_or(0);
 
-    // Authorization check
-    if !state.is_authorized(user_id) {
+    // Authorization check; /link must work before the user is linked, and
+    // /start checks for itself so it can answer the inline "link" button
+    if !matches!(cmd, Command::Link { .. } | Command::Start { .. }) && !state.is_authorized(user_id) {
         bot.send_message(
             msg.chat.id,
-            "Sorry, you are not authorized to use this bot.",
+            "Sorry, you are not authorized to use this bot.\nUse /link <your Fizzy email> to link your account.",
         )
         .await?;
         return Ok(());
     }
 
     match cmd {
-        Command::Start => handlers::start::handle(bot, msg, state).await?,
+        Command::Start { payload } => handlers::start::handle(bot, msg, state, payload).await?,
         Command::Help => handlers::help::handle(bot, msg, state).await?,
         Command::Me | Command::MyCards => handlers::my_cards::handle(bot, msg, state).await?,
         Command::Boards => handlers::boards::handle(bot, msg, state).await?,
@@ -108,6 +209,51 @@ async fn handle_command(
         Command::Comment { number, text } => {
             handlers::comment::handle(bot, msg, state, number, text).await?
         }
+        Command::History { number } => handlers::history::handle(bot, msg, state, number).await?,
+        Command::Comments { number } => handlers::comments::handle(bot, msg, state, number).await?,
+        Command::Search { terms } => handlers::search::handle(bot, msg, state, terms).await?,
+        Command::Edit { number } => {
+            handlers::edit::handle(bot, msg, state, dialogue, number).await?
+        }
+        Command::Cancel => handlers::edit::cancel(bot, msg, dialogue).await?,
+        Command::Assign { number, name } => {
+            handlers::assign::handle_assign(bot, msg, state, number, name).await?
+        }
+        Command::Unassign { number, name } => {
+            handlers::assign::handle_unassign(bot, msg, state, number, name).await?
+        }
+        Command::Tag { number, tags } => {
+            handlers::tags::handle_tag(bot, msg, state, number, tags).await?
+        }
+        Command::Untag { number, tags } => {
+            handlers::tags::handle_untag(bot, msg, state, number, tags).await?
+        }
+        Command::Tags { args } => handlers::tags::handle_list(bot, msg, state, args).await?,
+        Command::Due { number, when } => handlers::due::handle(bot, msg, state, number, when).await?,
+        Command::Timezone { zone } => handlers::due::handle_timezone(bot, msg, state, zone).await?,
+        Command::Gild { number } => {
+            handlers::golden::handle_gild(bot, msg, state, number, true).await?
+        }
+        Command::Ungild { number } => {
+            handlers::golden::handle_gild(bot, msg, state, number, false).await?
+        }
+        Command::Golden => handlers::golden::handle_list(bot, msg, state).await?,
+        Command::NotNow { number } => {
+            handlers::postpone::handle(bot, msg, state, number, true).await?
+        }
+        Command::Resume { number } => {
+            handlers::postpone::handle(bot, msg, state, number, false).await?
+        }
+        Command::Postponed { name } => handlers::postpone::handle_list(bot, msg, state, name).await?,
+        Command::Triage { name } => handlers::triage::handle(bot, msg, state, name).await?,
+        Command::Subscribe { args } => {
+            handlers::subscribe::handle(bot, msg, state, args, true).await?
+        }
+        Command::Unsubscribe { args } => {
+            handlers::subscribe::handle(bot, msg, state, args, false).await?
+        }
+        Command::Digest { args } => handlers::digest::handle(bot, msg, state, args).await?,
+        Command::Link { args } => handlers::link::handle(bot, msg, state, args).await?,
     }
 
     Ok(())